@@ -93,7 +93,7 @@ where
         return Ok(id);
     }
 
-    anyhow::bail!("{}", config.not_found_msg)
+    Err(CliError::not_found(config.not_found_msg).into())
 }
 
 /// Helper to get nested array from JSON value
@@ -0,0 +1,341 @@
+use colored::{Color, Colorize};
+
+const TEXT_ROWS: usize = 10;
+const TEXT_WIDTH: usize = 60;
+const SVG_WIDTH: f64 = 640.0;
+const SVG_HEIGHT: f64 = 300.0;
+
+/// One line of a chart; `values[i]` is the value at x = i.
+pub struct Series {
+    pub label: String,
+    pub values: Vec<f64>,
+    pub glyph: char,
+    pub color: Color,
+    /// Drawn dashed in SVG (e.g. ideal lines)
+    pub dashed: bool,
+}
+
+/// A point of interest on the x axis, e.g. a scope change.
+pub struct Marker {
+    pub x: usize,
+    pub glyph: char,
+    pub label: String,
+}
+
+pub struct LineChart {
+    pub title: String,
+    /// Number of x positions
+    pub points: usize,
+    /// Labels for the first and last x position
+    pub x_labels: (String, String),
+    pub series: Vec<Series>,
+    pub markers: Vec<Marker>,
+}
+
+fn format_value(v: f64) -> String {
+    if (v - v.round()).abs() < 0.05 {
+        format!("{}", v.round() as i64)
+    } else {
+        format!("{:.1}", v)
+    }
+}
+
+impl LineChart {
+    fn y_max(&self) -> f64 {
+        self.series
+            .iter()
+            .flat_map(|s| s.values.iter().copied())
+            .fold(0.0_f64, f64::max)
+            .ceil()
+            .max(1.0)
+    }
+
+    /// Render for the terminal with Unicode glyphs. Later series are drawn on
+    /// top of earlier ones.
+    pub fn render_text(&self) -> String {
+        let points = self.points.max(1);
+        let col_width = (TEXT_WIDTH / points).clamp(1, 4);
+        let cols = points * col_width;
+        let y_max = self.y_max();
+
+        let mut grid: Vec<Vec<Option<(char, Color)>>> = vec![vec![None; cols]; TEXT_ROWS];
+        for series in &self.series {
+            for (x, v) in series.values.iter().enumerate().take(points) {
+                let level = (v / y_max * (TEXT_ROWS - 1) as f64).round() as usize;
+                let row = TEXT_ROWS - 1 - level.min(TEXT_ROWS - 1);
+                grid[row][x * col_width + col_width / 2] = Some((series.glyph, series.color));
+            }
+        }
+
+        let labels = [
+            (0, format_value(y_max)),
+            ((TEXT_ROWS - 1) / 2, format_value(y_max / 2.0)),
+            (TEXT_ROWS - 1, "0".to_string()),
+        ];
+        let label_width = labels.iter().map(|(_, l)| l.len()).max().unwrap_or(1);
+
+        let mut out = format!("{}\n", self.title.bold());
+        for (r, row) in grid.iter().enumerate() {
+            match labels.iter().find(|(lr, _)| *lr == r) {
+                Some((_, l)) => out.push_str(&format!("{:>w$} ┤", l, w = label_width)),
+                None => out.push_str(&format!("{:>w$} │", "", w = label_width)),
+            }
+            let cells: String = row
+                .iter()
+                .map(|cell| match cell {
+                    Some((glyph, color)) => glyph.to_string().color(*color).to_string(),
+                    None => " ".to_string(),
+                })
+                .collect();
+            out.push_str(cells.trim_end());
+            out.push('\n');
+        }
+        out.push_str(&format!(
+            "{:>w$} └{}\n",
+            "",
+            "─".repeat(cols),
+            w = label_width
+        ));
+
+        let indent = " ".repeat(label_width + 2);
+        if !self.markers.is_empty() {
+            let mut row = vec![' '; cols];
+            for m in self.markers.iter().filter(|m| m.x < points) {
+                row[m.x * col_width + col_width / 2] = m.glyph;
+            }
+            let row: String = row.into_iter().collect();
+            out.push_str(&format!("{}{}\n", indent, row.trim_end().yellow()));
+        }
+
+        let (first, last) = &self.x_labels;
+        let gap = cols.saturating_sub(first.chars().count() + last.chars().count());
+        out.push_str(&format!(
+            "{}{}{}{}\n",
+            indent,
+            first,
+            " ".repeat(gap.max(1)),
+            last
+        ));
+
+        let mut legend: Vec<String> = self
+            .series
+            .iter()
+            .map(|s| format!("{} {}", s.glyph.to_string().color(s.color), s.label))
+            .collect();
+        for m in &self.markers {
+            let entry = format!("{} {}", m.glyph.to_string().yellow(), m.label);
+            if !legend.contains(&entry) {
+                legend.push(entry);
+            }
+        }
+        out.push_str(&format!("{}{}\n", indent, legend.join("   ")));
+        out
+    }
+
+    fn svg_group(&self, top: f64) -> String {
+        let (left, right, head, foot) = (48.0, 16.0, 36.0, 48.0);
+        let plot_w = SVG_WIDTH - left - right;
+        let plot_h = SVG_HEIGHT - head - foot;
+        let y_max = self.y_max();
+        let step = plot_w / (self.points.max(2) - 1) as f64;
+        let x_at = |x: usize| left + x as f64 * step;
+        let y_at = |v: f64| head + plot_h - v / y_max * plot_h;
+
+        let mut g = format!("<g transform=\"translate(0,{})\">\n", top);
+        g.push_str(&format!(
+            "<text x=\"{}\" y=\"20\" font-size=\"14\" font-weight=\"bold\">{}</text>\n",
+            left,
+            escape_xml(&self.title)
+        ));
+        for v in [0.0, y_max / 2.0, y_max] {
+            let y = y_at(v);
+            g.push_str(&format!(
+                "<line x1=\"{}\" y1=\"{y:.1}\" x2=\"{}\" y2=\"{y:.1}\" stroke=\"#e5e7eb\"/>\n",
+                left,
+                left + plot_w
+            ));
+            g.push_str(&format!(
+                "<text x=\"{}\" y=\"{:.1}\" font-size=\"11\" text-anchor=\"end\" fill=\"#6b7280\">{}</text>\n",
+                left - 6.0,
+                y + 4.0,
+                format_value(v)
+            ));
+        }
+        for m in self.markers.iter().filter(|m| m.x < self.points) {
+            let x = x_at(m.x);
+            g.push_str(&format!(
+                "<line x1=\"{x:.1}\" y1=\"{}\" x2=\"{x:.1}\" y2=\"{}\" stroke=\"#ca8a04\" stroke-dasharray=\"2 3\"><title>{}</title></line>\n",
+                head,
+                head + plot_h,
+                escape_xml(&m.label)
+            ));
+            g.push_str(&format!(
+                "<text x=\"{x:.1}\" y=\"{}\" font-size=\"10\" text-anchor=\"middle\" fill=\"#ca8a04\">{}</text>\n",
+                head - 4.0,
+                m.glyph
+            ));
+        }
+        for s in &self.series {
+            let points: Vec<String> = s
+                .values
+                .iter()
+                .enumerate()
+                .map(|(x, v)| format!("{:.1},{:.1}", x_at(x), y_at(*v)))
+                .collect();
+            g.push_str(&format!(
+                "<polyline fill=\"none\" stroke=\"{}\" stroke-width=\"2\"{} points=\"{}\"/>\n",
+                svg_color(s.color),
+                if s.dashed {
+                    " stroke-dasharray=\"6 4\""
+                } else {
+                    ""
+                },
+                points.join(" ")
+            ));
+        }
+        let axis_y = head + plot_h;
+        g.push_str(&format!(
+            "<line x1=\"{}\" y1=\"{axis_y}\" x2=\"{}\" y2=\"{axis_y}\" stroke=\"#374151\"/>\n",
+            left,
+            left + plot_w
+        ));
+        g.push_str(&format!(
+            "<text x=\"{}\" y=\"{}\" font-size=\"11\" fill=\"#6b7280\">{}</text>\n",
+            left,
+            axis_y + 16.0,
+            escape_xml(&self.x_labels.0)
+        ));
+        g.push_str(&format!(
+            "<text x=\"{}\" y=\"{}\" font-size=\"11\" text-anchor=\"end\" fill=\"#6b7280\">{}</text>\n",
+            left + plot_w,
+            axis_y + 16.0,
+            escape_xml(&self.x_labels.1)
+        ));
+        let mut x = left;
+        for s in &self.series {
+            g.push_str(&format!(
+                "<line x1=\"{x}\" y1=\"{ly}\" x2=\"{}\" y2=\"{ly}\" stroke=\"{}\" stroke-width=\"2\"{}/>\n",
+                x + 16.0,
+                svg_color(s.color),
+                if s.dashed { " stroke-dasharray=\"4 3\"" } else { "" },
+                ly = axis_y + 34.0
+            ));
+            g.push_str(&format!(
+                "<text x=\"{}\" y=\"{}\" font-size=\"11\">{}</text>\n",
+                x + 20.0,
+                axis_y + 38.0,
+                escape_xml(&s.label)
+            ));
+            x += 28.0 + 7.0 * s.label.len() as f64;
+        }
+        g.push_str("</g>\n");
+        g
+    }
+}
+
+/// Render charts stacked vertically into one standalone SVG document.
+pub fn to_svg(charts: &[LineChart]) -> String {
+    let height = SVG_HEIGHT * charts.len().max(1) as f64;
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" font-family=\"sans-serif\">\n<rect width=\"100%\" height=\"100%\" fill=\"white\"/>\n",
+        w = SVG_WIDTH,
+        h = height
+    );
+    for (i, chart) in charts.iter().enumerate() {
+        svg.push_str(&chart.svg_group(i as f64 * SVG_HEIGHT));
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn svg_color(color: Color) -> &'static str {
+    match color {
+        Color::Cyan | Color::BrightCyan => "#0891b2",
+        Color::Green | Color::BrightGreen => "#16a34a",
+        Color::Red | Color::BrightRed => "#dc2626",
+        Color::Yellow | Color::BrightYellow => "#ca8a04",
+        Color::Blue | Color::BrightBlue => "#2563eb",
+        Color::Magenta | Color::BrightMagenta => "#9333ea",
+        Color::BrightBlack => "#9ca3af",
+        _ => "#374151",
+    }
+}
+
+fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> LineChart {
+        LineChart {
+            title: "Burndown <points>".to_string(),
+            points: 5,
+            x_labels: ("Jan 01".to_string(), "Jan 05".to_string()),
+            series: vec![Series {
+                label: "remaining".to_string(),
+                values: vec![8.0, 6.0, 6.0, 3.0, 0.0],
+                glyph: '●',
+                color: Color::Cyan,
+                dashed: false,
+            }],
+            markers: vec![Marker {
+                x: 2,
+                glyph: '▲',
+                label: "scope added".to_string(),
+            }],
+        }
+    }
+
+    /// Drop ANSI color codes so assertions don't depend on terminal detection
+    fn strip_ansi(text: &str) -> String {
+        let mut out = String::new();
+        let mut chars = text.chars();
+        while let Some(c) = chars.next() {
+            if c == '\x1b' {
+                chars.by_ref().find(|c| c.is_ascii_alphabetic());
+            } else {
+                out.push(c);
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn test_render_text_layout() {
+        let text = strip_ansi(&sample().render_text());
+        let lines: Vec<&str> = text.lines().collect();
+        // title + rows + axis + markers + x labels + legend
+        assert_eq!(lines.len(), 1 + TEXT_ROWS + 4);
+        assert!(lines[1].starts_with("8 ┤"));
+        assert!(lines[TEXT_ROWS].starts_with("0 ┤"));
+        assert!(lines[1].contains('●'));
+        assert!(lines[TEXT_ROWS + 2].contains('▲'));
+        assert!(lines[TEXT_ROWS + 3].contains("Jan 01") && lines[TEXT_ROWS + 3].contains("Jan 05"));
+        assert!(
+            lines[TEXT_ROWS + 4].contains("remaining")
+                && lines[TEXT_ROWS + 4].contains("scope added")
+        );
+    }
+
+    #[test]
+    fn test_to_svg() {
+        let svg = to_svg(&[sample(), sample()]);
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("height=\"600\""));
+        assert_eq!(svg.matches("<polyline").count(), 2);
+        assert!(svg.contains("Burndown &lt;points&gt;"));
+        assert!(svg.contains("scope added"));
+    }
+
+    #[test]
+    fn test_format_value() {
+        assert_eq!(format_value(3.0), "3");
+        assert_eq!(format_value(2.5), "2.5");
+    }
+}
@@ -0,0 +1,321 @@
+use anyhow::Result;
+use regex::Regex;
+use std::path::{Path, PathBuf};
+
+use crate::vcs::run_git_command;
+
+/// Where GitHub and GitLab look for CODEOWNERS, in their order of precedence.
+const LOCATIONS: &[&str] = &[
+    ".github/CODEOWNERS",
+    "CODEOWNERS",
+    "docs/CODEOWNERS",
+    ".gitlab/CODEOWNERS",
+];
+
+/// One `pattern @owner ...` line.
+#[derive(Debug)]
+pub struct Rule {
+    pub pattern: String,
+    pub owners: Vec<String>,
+    regex: Regex,
+}
+
+#[derive(Debug, Default)]
+pub struct CodeOwners {
+    pub path: Option<PathBuf>,
+    rules: Vec<Rule>,
+}
+
+/// Translate a gitignore-style pattern into a regex over repo-relative paths.
+/// Patterns with a leading or inner `/` are anchored at the root; others
+/// match at any depth. Matching a directory matches everything beneath it,
+/// except for a trailing `/*`, which only covers the directory's own files.
+pub fn pattern_regex(pattern: &str) -> Option<Regex> {
+    let trimmed = pattern.trim_end_matches('/');
+    if trimmed.is_empty() {
+        return Regex::new("^.*$").ok();
+    }
+    let anchored = trimmed.starts_with('/') || trimmed.contains('/');
+    let body = trimmed.trim_start_matches('/');
+
+    let mut re = String::from("^");
+    if !anchored {
+        re.push_str("(?:.*/)?");
+    }
+    let chars: Vec<char> = body.chars().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '*' if chars.get(i + 1) == Some(&'*') => {
+                if chars.get(i + 2) == Some(&'/') {
+                    re.push_str("(?:.*/)?");
+                    i += 3;
+                } else {
+                    re.push_str(".*");
+                    i += 2;
+                }
+                continue;
+            }
+            '*' => re.push_str("[^/]*"),
+            '?' => re.push_str("[^/]"),
+            '[' => match char_class(&chars[i + 1..]) {
+                Some((class, len)) => {
+                    re.push_str(&class);
+                    i += len + 1;
+                    continue;
+                }
+                None => re.push_str(r"\["),
+            },
+            c => re.push_str(&regex::escape(&c.to_string())),
+        }
+        i += 1;
+    }
+    let only_files = body.ends_with("/*");
+    if !only_files {
+        re.push_str("(?:/.*)?");
+    }
+    re.push('$');
+    Regex::new(&re).ok()
+}
+
+/// A `[...]` class (after the `[`) as a regex class and the number of
+/// characters it used, or None when it isn't closed. `!` or `^` negates; a
+/// class never matches `/`.
+fn char_class(chars: &[char]) -> Option<(String, usize)> {
+    let negated = matches!(chars.first(), Some('!') | Some('^'));
+    let start = usize::from(negated);
+    // A `]` right after the opening bracket is a literal
+    let close = start + 1 + chars.get(start + 1..)?.iter().position(|c| *c == ']')?;
+    let mut class = String::from(if negated { "[^/" } else { "[" });
+    for c in &chars[start..close] {
+        if matches!(c, '\\' | '[' | ']' | '&' | '~') {
+            class.push('\\');
+        }
+        class.push(*c);
+    }
+    class.push(']');
+    Some((class, close + 1))
+}
+
+pub fn is_glob(value: &str) -> bool {
+    value.contains(['*', '?', '['])
+}
+
+impl CodeOwners {
+    pub fn parse(content: &str) -> CodeOwners {
+        let rules = content
+            .lines()
+            .map(str::trim)
+            // Skip comments and GitLab `[Section]` headers
+            .filter(|l| {
+                !l.is_empty() && !l.starts_with('#') && !l.starts_with('[') && !l.starts_with("^[")
+            })
+            .filter_map(|line| {
+                let line = line.split(" #").next().unwrap_or(line);
+                let mut parts = line.split_whitespace();
+                let pattern = parts.next()?.replace("\\ ", " ");
+                let owners: Vec<String> = parts.map(String::from).collect();
+                Some(Rule {
+                    regex: pattern_regex(&pattern)?,
+                    pattern,
+                    owners,
+                })
+            })
+            .collect();
+        CodeOwners { path: None, rules }
+    }
+
+    /// Load CODEOWNERS from the repository root (empty when there is none).
+    pub fn load(root: &Path) -> Result<CodeOwners> {
+        for location in LOCATIONS {
+            let path = root.join(location);
+            if path.is_file() {
+                let mut owners = CodeOwners::parse(&std::fs::read_to_string(&path)?);
+                owners.path = Some(path);
+                return Ok(owners);
+            }
+        }
+        Ok(CodeOwners::default())
+    }
+
+    /// The rule that owns `path`: the last matching one, as on GitHub.
+    pub fn rule_for(&self, path: &str) -> Option<&Rule> {
+        let path = path.trim_start_matches("./").trim_start_matches('/');
+        self.rules.iter().rev().find(|r| r.regex.is_match(path))
+    }
+}
+
+/// Repository root and the current directory relative to it ("" at the root).
+pub fn repo_root_and_prefix() -> (PathBuf, String) {
+    match run_git_command(&["rev-parse", "--show-toplevel"]) {
+        Ok(root) => {
+            let prefix = run_git_command(&["rev-parse", "--show-prefix"]).unwrap_or_default();
+            (PathBuf::from(root), prefix)
+        }
+        Err(_) => (std::env::current_dir().unwrap_or_default(), String::new()),
+    }
+}
+
+/// Turn user-supplied paths (relative to the current directory, possibly
+/// globs like `src/foo/**`) into repo-relative paths. Globs are expanded
+/// against tracked files; a glob with no matches stands for its directory.
+pub fn expand_paths(values: &[String], prefix: &str) -> Vec<String> {
+    let mut tracked: Option<Vec<String>> = None;
+    let mut paths = Vec::new();
+    for value in values {
+        let relative = format!("{}{}", prefix, value.trim_start_matches("./"));
+        if !is_glob(&relative) {
+            paths.push(relative);
+            continue;
+        }
+        let files = tracked.get_or_insert_with(|| {
+            run_git_command(&["ls-files", "--full-name", ":/"])
+                .map(|out| out.lines().map(String::from).collect())
+                .unwrap_or_default()
+        });
+        let matched: Vec<String> = match pattern_regex(&format!("/{}", relative)) {
+            Some(re) => files.iter().filter(|f| re.is_match(f)).cloned().collect(),
+            None => Vec::new(),
+        };
+        if matched.is_empty() {
+            let dir = relative
+                .split(['*', '?', '['])
+                .next()
+                .unwrap_or("")
+                .to_string();
+            paths.push(dir);
+        } else {
+            paths.extend(matched);
+        }
+    }
+    paths
+}
+
+/// Owners ranked by how many of `paths` they own, ties in first-seen order.
+pub fn rank_owners(owners: &CodeOwners, paths: &[String]) -> Vec<(String, usize)> {
+    let mut ranked: Vec<(String, usize)> = Vec::new();
+    for path in paths {
+        for owner in owners
+            .rule_for(path)
+            .map(|r| &r.owners)
+            .into_iter()
+            .flatten()
+        {
+            match ranked
+                .iter_mut()
+                .find(|(o, _)| o.eq_ignore_ascii_case(owner))
+            {
+                Some((_, count)) => *count += 1,
+                None => ranked.push((owner.clone(), 1)),
+            }
+        }
+    }
+    // Stable sort keeps first-seen order among equals
+    ranked.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+    ranked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"
+# Default owners
+*                   @acme/core
+*.js                @frontend-lead
+/docs/              docs@acme.com
+src/payments/       @alice @bob
+src/**/migrations   @dba
+[Backend]
+/api/               @carol   # inline comment
+"#;
+
+    #[test]
+    fn test_last_matching_rule_wins() {
+        let owners = CodeOwners::parse(SAMPLE);
+        assert_eq!(
+            owners.rule_for("README.md").unwrap().owners,
+            vec!["@acme/core"]
+        );
+        assert_eq!(
+            owners.rule_for("web/app.js").unwrap().owners,
+            vec!["@frontend-lead"]
+        );
+        assert_eq!(
+            owners.rule_for("docs/guide/intro.md").unwrap().owners,
+            vec!["docs@acme.com"]
+        );
+        assert_eq!(
+            owners.rule_for("src/payments/stripe.rs").unwrap().owners,
+            vec!["@alice", "@bob"]
+        );
+        assert_eq!(
+            owners
+                .rule_for("src/users/db/migrations/001.sql")
+                .unwrap()
+                .owners,
+            vec!["@dba"]
+        );
+        assert_eq!(
+            owners.rule_for("api/server.rs").unwrap().owners,
+            vec!["@carol"]
+        );
+    }
+
+    #[test]
+    fn test_pattern_anchoring() {
+        let re = pattern_regex("/build").unwrap();
+        assert!(re.is_match("build/out.o"));
+        assert!(!re.is_match("src/build/out.o"));
+        let re = pattern_regex("build").unwrap();
+        assert!(re.is_match("src/build/out.o"));
+        let re = pattern_regex("docs/*.md").unwrap();
+        assert!(re.is_match("docs/a.md"));
+        assert!(!re.is_match("docs/sub/a.md"));
+        assert!(!re.is_match("other/docs/a.md"));
+    }
+
+    #[test]
+    fn test_trailing_star_stays_in_directory() {
+        let re = pattern_regex("docs/*").unwrap();
+        assert!(re.is_match("docs/getting-started.md"));
+        assert!(!re.is_match("docs/build-app/troubleshooting.md"));
+        let re = pattern_regex("/apps/").unwrap();
+        assert!(re.is_match("apps/web/index.ts"));
+    }
+
+    #[test]
+    fn test_character_classes() {
+        let re = pattern_regex("src/[ab]/**").unwrap();
+        assert!(re.is_match("src/a/lib.rs"));
+        assert!(re.is_match("src/b/deep/mod.rs"));
+        assert!(!re.is_match("src/c/lib.rs"));
+        let re = pattern_regex("v[!0-1].txt").unwrap();
+        assert!(re.is_match("v2.txt"));
+        assert!(!re.is_match("v1.txt"));
+        // Unclosed brackets are literal
+        assert!(pattern_regex("weird[name").unwrap().is_match("weird[name"));
+    }
+
+    #[test]
+    fn test_rank_owners() {
+        let owners = CodeOwners::parse(SAMPLE);
+        let paths = vec![
+            "src/payments/a.rs".to_string(),
+            "src/payments/b.rs".to_string(),
+            "api/c.rs".to_string(),
+        ];
+        let ranked = rank_owners(&owners, &paths);
+        assert_eq!(ranked[0], ("@alice".to_string(), 2));
+        assert_eq!(ranked[1], ("@bob".to_string(), 2));
+        assert_eq!(ranked[2], ("@carol".to_string(), 1));
+    }
+
+    #[test]
+    fn test_expand_paths_without_glob() {
+        assert_eq!(
+            expand_paths(&["./lib.rs".to_string()], "src/"),
+            vec!["src/lib.rs".to_string()]
+        );
+    }
+}
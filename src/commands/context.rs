@@ -0,0 +1,508 @@
+use anyhow::Result;
+use colored::Colorize;
+use serde_json::{json, Value};
+use std::path::{Path, PathBuf};
+
+use crate::api::LinearClient;
+use crate::output::{print_json_owned, OutputOptions};
+use crate::priority::priority_to_string;
+use crate::text::strip_markdown;
+use crate::vcs::{issue_id_from_branch, issue_id_from_branch_with, run_git_command};
+
+use super::git::{extract_linear_issue, get_vcs, run_jj_command, Vcs};
+
+/// Name of the file that pins the current issue for a directory tree.
+const ISSUE_FILE: &str = ".linear";
+/// How many recent commits (not on the default branch) to scan for
+/// `Linear-Issue:` trailers.
+const TRAILER_DEPTH: &str = "10";
+
+/// Where the current issue was found.
+#[derive(Debug, Clone, PartialEq)]
+struct Detected {
+    issue_id: String,
+    /// "file", "branch", "bookmark", "description" or "trailer"
+    source: &'static str,
+    branch: Option<String>,
+}
+
+/// First identifier in a `.linear` file: either a bare `LIN-123` or
+/// `issue = "LIN-123"`. `#` comments are ignored.
+fn parse_issue_file(content: &str) -> Option<String> {
+    content
+        .lines()
+        .map(|l| l.split('#').next().unwrap_or("").trim())
+        .filter(|l| !l.is_empty())
+        .find_map(|l| {
+            let value = match l.split_once('=') {
+                Some((key, value)) if key.trim() == "issue" => value,
+                Some(_) => return None,
+                None => l,
+            };
+            issue_id_from_branch(value.trim().trim_matches('"').trim_matches('\''))
+        })
+}
+
+/// Look for `.linear` from `start` upwards, stopping at `stop` (the repo root).
+fn find_issue_file(start: &Path, stop: Option<&Path>) -> Option<(PathBuf, String)> {
+    let mut dir = Some(start);
+    while let Some(d) = dir {
+        let path = d.join(ISSUE_FILE);
+        if path.is_file() {
+            if let Some(id) = std::fs::read_to_string(&path)
+                .ok()
+                .and_then(|c| parse_issue_file(&c))
+            {
+                return Some((path, id));
+            }
+        }
+        if Some(d) == stop {
+            break;
+        }
+        dir = d.parent();
+    }
+    None
+}
+
+/// Issue references in recent commit messages, newest first.
+fn issue_from_messages(messages: &str) -> Option<String> {
+    messages
+        .split('\x1e')
+        .map(str::trim)
+        .filter(|m| !m.is_empty())
+        .find_map(extract_linear_issue)
+}
+
+fn repo_root(vcs: Option<Vcs>) -> Option<PathBuf> {
+    match vcs? {
+        Vcs::Git => run_git_command(&["rev-parse", "--show-toplevel"]).ok(),
+        Vcs::Jj => run_jj_command(&["workspace", "root"]).ok(),
+    }
+    .map(PathBuf::from)
+}
+
+/// The default branch's name and a ref to it: the remote's HEAD when known,
+/// otherwise a local `main` or `master`.
+fn default_branch() -> Option<(String, String)> {
+    if let Ok(full) = run_git_command(&["symbolic-ref", "--short", "refs/remotes/origin/HEAD"]) {
+        let name = full.strip_prefix("origin/").unwrap_or(&full).to_string();
+        return Some((name, full));
+    }
+    ["main", "master"].into_iter().find_map(|name| {
+        run_git_command(&[
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            &format!("refs/heads/{}", name),
+        ])
+        .ok()
+        .map(|_| (name.to_string(), name.to_string()))
+    })
+}
+
+fn detect_git(template: Option<&str>) -> Option<Detected> {
+    let branch = run_git_command(&["symbolic-ref", "--short", "HEAD"]).ok();
+    if let Some(id) = branch
+        .as_deref()
+        .and_then(|b| issue_id_from_branch_with(b, template))
+    {
+        return Some(Detected {
+            issue_id: id,
+            source: "branch",
+            branch,
+        });
+    }
+    // Commits already on the default branch belong to other work, so only
+    // the branch's own commits are scanned (and none at all on the default)
+    let mut args = vec!["log", "-n", TRAILER_DEPTH, "--format=%B%x1e", "HEAD"];
+    let default = default_branch();
+    if let Some((name, reference)) = &default {
+        if branch.as_deref() == Some(name.as_str()) {
+            return None;
+        }
+        args.extend(["--not", reference.as_str()]);
+    }
+    let log = run_git_command(&args).ok()?;
+    issue_from_messages(&log).map(|id| Detected {
+        issue_id: id,
+        source: "trailer",
+        branch,
+    })
+}
+
+fn detect_jj(template: Option<&str>) -> Option<Detected> {
+    // Bookmarks on the working copy or its nearest bookmarked ancestor
+    let bookmarks = run_jj_command(&[
+        "log",
+        "-r",
+        "heads(::@ & bookmarks())",
+        "--no-graph",
+        "-T",
+        "bookmarks ++ \"\\n\"",
+    ])
+    .unwrap_or_default();
+    for bookmark in bookmarks.split_whitespace() {
+        let name = bookmark.trim_end_matches('*');
+        if let Some(id) = issue_id_from_branch_with(name, template) {
+            return Some(Detected {
+                issue_id: id,
+                source: "bookmark",
+                branch: Some(name.to_string()),
+            });
+        }
+    }
+    let descriptions = run_jj_command(&[
+        "log",
+        "-r",
+        &format!("ancestors(@, {}) ~ ::trunk()", TRAILER_DEPTH),
+        "--no-graph",
+        "-T",
+        "description ++ \"\\x1e\"",
+    ])
+    .ok()?;
+    issue_from_messages(&descriptions).map(|id| Detected {
+        issue_id: id,
+        source: "description",
+        branch: None,
+    })
+}
+
+/// Find the current issue: a `.linear` file wins, then the branch or jj
+/// bookmark, then references in recent commit messages.
+fn detect() -> Result<Detected> {
+    let vcs = get_vcs(None).ok();
+    let cwd = std::env::current_dir()?;
+    let root = repo_root(vcs);
+    if let Some((_, id)) = find_issue_file(&cwd, root.as_deref()) {
+        let branch = match vcs {
+            Some(Vcs::Git) => run_git_command(&["symbolic-ref", "--short", "HEAD"]).ok(),
+            _ => None,
+        };
+        return Ok(Detected {
+            issue_id: id,
+            source: "file",
+            branch,
+        });
+    }
+    // Custom branch templates get their own pattern so detection keeps working
+    let template = crate::config::git_config()
+        .ok()
+        .and_then(|g| g.branch.template);
+    let detected = match vcs {
+        Some(Vcs::Git) => detect_git(template.as_deref()),
+        Some(Vcs::Jj) => detect_jj(template.as_deref()),
+        None => anyhow::bail!(
+            "Not in a git or jj repository and no {} file found",
+            ISSUE_FILE
+        ),
+    };
+    detected.ok_or_else(|| {
+        anyhow::anyhow!(
+            "No Linear issue found in the branch, recent commits or a {} file",
+            ISSUE_FILE
+        )
+    })
+}
+
+const BASIC_FIELDS: &str = r#"
+    id
+    identifier
+    title
+    state { name }
+    assignee { name }
+    priority
+    url
+"#;
+
+const FULL_FIELDS: &str = r#"
+    id
+    identifier
+    title
+    description
+    url
+    priority
+    estimate
+    dueDate
+    branchName
+    state { name type }
+    assignee { name email }
+    team { key name }
+    project { name }
+    cycle { number name }
+    labels { nodes { name } }
+    parent { identifier title state { name } }
+    children { nodes { identifier title state { name type } } }
+    relations { nodes { type relatedIssue { identifier title state { name } } } }
+    inverseRelations { nodes { type issue { identifier title state { name } } } }
+    comments(first: 50) { nodes { createdAt body user { name } } }
+    attachments { nodes { title url sourceType } }
+"#;
+
+/// Attachments that look like pull/merge requests.
+fn is_pull_request(attachment: &Value) -> bool {
+    let source = attachment["sourceType"]
+        .as_str()
+        .unwrap_or("")
+        .to_lowercase();
+    let url = attachment["url"].as_str().unwrap_or("");
+    source.contains("github")
+        || source.contains("gitlab")
+        || url.contains("/pull/")
+        || url.contains("/merge_requests/")
+        || url.contains("/pulls/")
+}
+
+/// Flatten relations in both directions into `{type, issue}` pairs from this
+/// issue's point of view.
+fn flatten_relations(issue: &Value) -> Vec<Value> {
+    let outgoing = issue["relations"]["nodes"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|r| json!({ "type": r["type"], "issue": r["relatedIssue"] }));
+    let incoming = issue["inverseRelations"]["nodes"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|r| {
+            // "blocks" seen from the other side means this issue is blocked
+            let kind = match r["type"].as_str() {
+                Some("blocks") => json!("blocked_by"),
+                _ => r["type"].clone(),
+            };
+            json!({ "type": kind, "issue": r["issue"] })
+        });
+    outgoing.chain(incoming).collect()
+}
+
+/// Reshape the full query result into the context document agents consume.
+fn full_context(issue: &Value) -> Value {
+    let mut ctx = issue.clone();
+    if let Some(obj) = ctx.as_object_mut() {
+        obj.remove("relations");
+        obj.remove("inverseRelations");
+        obj.remove("attachments");
+        obj.insert("relations".into(), json!(flatten_relations(issue)));
+        let pull_requests: Vec<Value> = issue["attachments"]["nodes"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter(|a| is_pull_request(a))
+            .cloned()
+            .collect();
+        obj.insert("pull_requests".into(), json!(pull_requests));
+        obj.insert("sub_issues".into(), issue["children"]["nodes"].clone());
+        obj.remove("children");
+        obj.insert("comments".into(), issue["comments"]["nodes"].clone());
+        let labels: Vec<Value> = issue["labels"]["nodes"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|l| l["name"].clone())
+            .collect();
+        obj.insert("labels".into(), json!(labels));
+    }
+    ctx
+}
+
+pub async fn handle(full: bool, output: &OutputOptions, id_only: bool, retry: u32) -> Result<()> {
+    let detected = detect()?;
+    let issue_id = detected.issue_id.clone();
+    let wants_json = output.is_json() || output.has_template();
+
+    if id_only || (!wants_json && !full) {
+        if wants_json {
+            print_json_owned(json!(issue_id), output)?;
+        } else {
+            println!("{}", issue_id);
+        }
+        return Ok(());
+    }
+
+    let client = LinearClient::new_with_retry(retry)?;
+    let query = format!(
+        "query($id: String!) {{ issue(id: $id) {{ {} }} }}",
+        if full { FULL_FIELDS } else { BASIC_FIELDS }
+    );
+    let issue = client
+        .query(&query, Some(json!({ "id": issue_id })))
+        .await
+        .ok()
+        .map(|data| data["data"]["issue"].clone())
+        .filter(|i| !i.is_null());
+
+    if wants_json {
+        let mut doc = json!({
+            "branch": detected.branch,
+            "issue_id": issue_id,
+            "source": detected.source,
+            "found": issue.is_some(),
+        });
+        if let Some(issue) = &issue {
+            doc["issue"] = if full {
+                full_context(issue)
+            } else {
+                issue.clone()
+            };
+        }
+        print_json_owned(doc, output)?;
+        return Ok(());
+    }
+
+    let Some(issue) = issue else {
+        anyhow::bail!("Issue not found: {}", issue_id);
+    };
+    print_full(&full_context(&issue), detected.source);
+    Ok(())
+}
+
+fn print_full(ctx: &Value, source: &str) {
+    let s = |v: &Value| v.as_str().unwrap_or("-").to_string();
+    println!(
+        "{} {} {}",
+        s(&ctx["identifier"]).cyan().bold(),
+        s(&ctx["title"]).bold(),
+        format!("(from {})", source).dimmed()
+    );
+    println!("{}", "-".repeat(60));
+    println!("State:    {}", s(&ctx["state"]["name"]));
+    println!("Priority: {}", priority_to_string(ctx["priority"].as_i64()));
+    println!("Assignee: {}", s(&ctx["assignee"]["name"]));
+    if let Some(project) = ctx["project"]["name"].as_str() {
+        println!("Project:  {}", project);
+    }
+    if let Some(parent) = ctx["parent"]["identifier"].as_str() {
+        println!("Parent:   {} {}", parent, s(&ctx["parent"]["title"]));
+    }
+    println!("URL:      {}", s(&ctx["url"]).blue());
+
+    if let Some(desc) = ctx["description"].as_str().filter(|d| !d.is_empty()) {
+        println!("\n{}", strip_markdown(desc));
+    }
+
+    let section = |title: &str, items: Vec<String>| {
+        if !items.is_empty() {
+            println!("\n{}", title.bold());
+            for item in items {
+                println!("  {}", item);
+            }
+        }
+    };
+    let list = |v: &Value| v.as_array().cloned().unwrap_or_default();
+    section(
+        "Sub-issues",
+        list(&ctx["sub_issues"])
+            .iter()
+            .map(|c| {
+                format!(
+                    "{} {} [{}]",
+                    s(&c["identifier"]),
+                    s(&c["title"]),
+                    s(&c["state"]["name"])
+                )
+            })
+            .collect(),
+    );
+    section(
+        "Relations",
+        list(&ctx["relations"])
+            .iter()
+            .map(|r| {
+                format!(
+                    "{} {} {} [{}]",
+                    s(&r["type"]).replace('_', " "),
+                    s(&r["issue"]["identifier"]),
+                    s(&r["issue"]["title"]),
+                    s(&r["issue"]["state"]["name"])
+                )
+            })
+            .collect(),
+    );
+    section(
+        "Pull requests",
+        list(&ctx["pull_requests"])
+            .iter()
+            .map(|p| format!("{} {}", s(&p["title"]), s(&p["url"]).blue()))
+            .collect(),
+    );
+    section(
+        "Comments",
+        list(&ctx["comments"])
+            .iter()
+            .map(|c| {
+                format!(
+                    "{} {}: {}",
+                    s(&c["createdAt"])
+                        .chars()
+                        .take(10)
+                        .collect::<String>()
+                        .dimmed(),
+                    s(&c["user"]["name"]),
+                    strip_markdown(c["body"].as_str().unwrap_or("")).replace('\n', " ")
+                )
+            })
+            .collect(),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_issue_file() {
+        assert_eq!(parse_issue_file("LIN-123\n").as_deref(), Some("LIN-123"));
+        assert_eq!(
+            parse_issue_file("# pinned\nissue = \"eng-9\"\n").as_deref(),
+            Some("ENG-9")
+        );
+        assert_eq!(parse_issue_file("team = \"ENG\"\n"), None);
+        assert_eq!(parse_issue_file(""), None);
+    }
+
+    #[test]
+    fn test_find_issue_file_stops_at_root() {
+        let tmp = std::env::temp_dir().join(format!("linear-ctx-{}", std::process::id()));
+        let nested = tmp.join("repo/src/deep");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::write(tmp.join(ISSUE_FILE), "OUT-1").unwrap();
+        let repo = tmp.join("repo");
+        assert_eq!(find_issue_file(&nested, Some(&repo)), None);
+        std::fs::write(repo.join(ISSUE_FILE), "LIN-7").unwrap();
+        let (path, id) = find_issue_file(&nested, Some(&repo)).unwrap();
+        assert_eq!(id, "LIN-7");
+        assert_eq!(path, repo.join(ISSUE_FILE));
+        std::fs::remove_dir_all(&tmp).unwrap();
+    }
+
+    #[test]
+    fn test_issue_from_messages() {
+        let log = "Tidy up\n\x1eLIN-5: Fix thing\n\nLinear-Issue: LIN-5\n\x1e";
+        assert_eq!(issue_from_messages(log).as_deref(), Some("LIN-5"));
+        assert_eq!(issue_from_messages("Tidy up\x1e"), None);
+    }
+
+    #[test]
+    fn test_full_context_shapes_relations_and_prs() {
+        let issue = json!({
+            "identifier": "LIN-1",
+            "labels": { "nodes": [{ "name": "Bug" }] },
+            "children": { "nodes": [{ "identifier": "LIN-2" }] },
+            "comments": { "nodes": [] },
+            "relations": { "nodes": [{ "type": "blocks", "relatedIssue": { "identifier": "LIN-3" } }] },
+            "inverseRelations": { "nodes": [{ "type": "blocks", "issue": { "identifier": "LIN-4" } }] },
+            "attachments": { "nodes": [
+                { "title": "PR", "url": "https://github.com/a/b/pull/1", "sourceType": "github" },
+                { "title": "Doc", "url": "https://example.com/spec", "sourceType": "url" },
+            ]},
+        });
+        let ctx = full_context(&issue);
+        assert_eq!(ctx["labels"], json!(["Bug"]));
+        assert_eq!(ctx["sub_issues"][0]["identifier"], "LIN-2");
+        assert_eq!(ctx["relations"][0]["type"], "blocks");
+        assert_eq!(ctx["relations"][1]["type"], "blocked_by");
+        assert_eq!(ctx["relations"][1]["issue"]["identifier"], "LIN-4");
+        assert_eq!(ctx["pull_requests"].as_array().unwrap().len(), 1);
+        assert!(ctx.get("attachments").is_none());
+    }
+}
@@ -55,6 +55,94 @@ pub enum CycleCommands {
         #[arg(long)]
         ends_at: Option<String>,
     },
+    /// Close a cycle, moving unfinished issues into the next one
+    #[command(after_help = r#"EXAMPLES:
+    linear cycles close -t ENG                       # Active cycle -> next cycle
+    linear c close -t ENG --dry-run                  # Preview what would move
+    linear c close -t ENG --state "In Progress"      # Only in-progress issues
+    linear c close -t ENG --cycle 12 --interactive   # Pick issues from cycle 12
+    linear c close -t ENG --comment                  # Note the carry-over on each issue
+
+Prints a summary of the close; --comment also leaves a carry-over comment
+on each moved issue."#)]
+    Close {
+        /// Team key, name, or ID (defaults to team in .linear.toml)
+        #[arg(short, long)]
+        team: Option<String>,
+        /// Cycle number or ID (default: the active cycle)
+        #[arg(long)]
+        cycle: Option<String>,
+        /// Only move issues in these states (can be repeated)
+        #[arg(long = "state")]
+        states: Vec<String>,
+        /// Choose which issues to move
+        #[arg(short, long)]
+        interactive: bool,
+        /// Comment on each moved issue noting the carry-over
+        #[arg(long)]
+        comment: bool,
+        /// Preview without moving (dry run)
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Propose backlog issues for the next cycle from past velocity
+    #[command(after_help = r#"EXAMPLES:
+    linear cycles plan -t ENG                        # Capacity = average velocity
+    linear c plan -t ENG --capacity 30               # Fixed capacity in points
+    linear c plan -t ENG --capacity 30 --apply       # Move proposed issues in
+
+Unassigned-to-cycle backlog and todo issues are taken by priority (urgent
+first, then oldest) while their estimates fit the capacity left after what
+the cycle already contains. Unestimated issues are listed, not planned."#)]
+    Plan {
+        /// Team key, name, or ID (defaults to team in .linear.toml)
+        #[arg(short, long)]
+        team: Option<String>,
+        /// Cycle number or ID (default: the cycle after the active one)
+        #[arg(long)]
+        cycle: Option<String>,
+        /// Capacity in points (default: average velocity)
+        #[arg(long)]
+        capacity: Option<f64>,
+        /// Finished cycles to average velocity over
+        #[arg(long, default_value = "3")]
+        velocity_cycles: usize,
+        /// Move the proposed issues into the cycle
+        #[arg(long)]
+        apply: bool,
+        /// Preview without moving (dry run)
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Generate a retrospective report for a cycle
+    #[command(after_help = r#"EXAMPLES:
+    linear cycles report 12 -t ENG                   # Markdown to stdout
+    linear c report current -t ENG --html -f retro.html
+    linear c report CYCLE_ID --publish "Team Rituals"  # As a Linear document
+    linear c report 12 -t ENG --output json
+
+Covers committed vs completed scope, scope added/removed mid-cycle, carried
+over and reopened issues, per-assignee completion, bugs created vs closed
+and issues still blocked by open issues."#)]
+    Report {
+        /// Cycle number (with --team), "current", or cycle ID
+        cycle: String,
+        /// Team key, name, or ID (defaults to team in .linear.toml)
+        #[arg(short, long)]
+        team: Option<String>,
+        /// Render HTML instead of Markdown
+        #[arg(long)]
+        html: bool,
+        /// Write the report to a file (not with --output json)
+        #[arg(short, long)]
+        file: Option<String>,
+        /// Label that marks bugs
+        #[arg(long, default_value = "Bug")]
+        bug_label: String,
+        /// Publish as a Linear document in this project (not with --output json)
+        #[arg(long, value_name = "PROJECT")]
+        publish: Option<String>,
+    },
     /// Update an existing cycle
     Update {
         /// Cycle ID
@@ -107,6 +195,59 @@ pub async fn handle(cmd: CycleCommands, output: &OutputOptions) -> Result<()> {
             starts_at,
             ends_at,
         } => create_cycle(&team, name, description, starts_at, ends_at, output).await,
+        CycleCommands::Close {
+            team,
+            cycle,
+            states,
+            interactive,
+            comment,
+            dry_run,
+        } => {
+            let dry_run = dry_run || output.dry_run;
+            super::cycles_plan::close_cycle(
+                team,
+                cycle,
+                states,
+                interactive,
+                comment,
+                dry_run,
+                output,
+            )
+            .await
+        }
+        CycleCommands::Plan {
+            team,
+            cycle,
+            capacity,
+            velocity_cycles,
+            apply,
+            dry_run,
+        } => {
+            let dry_run = dry_run || output.dry_run;
+            super::cycles_plan::plan_cycle(
+                team,
+                cycle,
+                capacity,
+                velocity_cycles,
+                apply,
+                dry_run,
+                output,
+            )
+            .await
+        }
+        CycleCommands::Report {
+            cycle,
+            team,
+            html,
+            file,
+            bug_label,
+            publish,
+        } => {
+            super::cycles_report::cycle_report(
+                &cycle, team, html, file, &bug_label, publish, output,
+            )
+            .await
+        }
         CycleCommands::Update {
             id,
             name,
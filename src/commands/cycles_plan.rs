@@ -0,0 +1,678 @@
+use anyhow::Result;
+use chrono::Utc;
+use colored::Colorize;
+use serde_json::{json, Value};
+use tabled::{Table, Tabled};
+
+use crate::api::{resolve_team_id, LinearClient};
+use crate::display_options;
+use crate::output::{print_json_owned, OutputOptions};
+use crate::pagination::{paginate_nodes, PaginationOptions};
+use crate::priority::priority_to_string;
+use crate::text::truncate;
+
+/// Every cycle of a team, with the history needed for velocity.
+pub(super) async fn team_cycles(client: &LinearClient, team_id: &str) -> Result<Vec<Value>> {
+    let query = r#"
+        query($teamId: String!, $first: Int, $after: String, $last: Int, $before: String) {
+            team(id: $teamId) {
+                cycles(first: $first, after: $after, last: $last, before: $before) {
+                    nodes {
+                        id
+                        name
+                        number
+                        startsAt
+                        endsAt
+                        completedScopeHistory
+                    }
+                    pageInfo {
+                        hasNextPage
+                        endCursor
+                        hasPreviousPage
+                        startCursor
+                    }
+                }
+            }
+        }
+    "#;
+    let mut vars = serde_json::Map::new();
+    vars.insert("teamId".to_string(), json!(team_id));
+    let pagination = PaginationOptions {
+        all: true,
+        ..Default::default()
+    };
+    let mut cycles = paginate_nodes(
+        client,
+        query,
+        vars,
+        &["data", "team", "cycles", "nodes"],
+        &["data", "team", "cycles", "pageInfo"],
+        &pagination,
+        100,
+    )
+    .await?;
+    cycles.sort_by(|a, b| a["startsAt"].as_str().cmp(&b["startsAt"].as_str()));
+    Ok(cycles)
+}
+
+/// Now in Linear's timestamp format, so it compares as a string.
+pub(super) fn now_iso() -> String {
+    Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string()
+}
+
+/// "Sprint 4 (#12)", or "Cycle 12" for unnamed cycles.
+pub(super) fn cycle_label(cycle: &Value) -> String {
+    let number = cycle["number"].as_u64().unwrap_or(0);
+    match cycle["name"].as_str() {
+        Some(name) if !name.is_empty() => format!("{} (#{})", name, number),
+        _ => format!("Cycle {}", number),
+    }
+}
+
+/// The cycle given by number or ID, or the active one. `cycles` is sorted by start.
+pub(super) fn pick_cycle<'a>(
+    cycles: &'a [Value],
+    cycle: Option<&str>,
+    now: &str,
+) -> Option<&'a Value> {
+    match cycle {
+        Some(c) => cycles.iter().find(|cy| {
+            cy["id"].as_str() == Some(c)
+                || c.parse::<u64>()
+                    .ok()
+                    .is_some_and(|n| cy["number"].as_u64() == Some(n))
+        }),
+        None => cycles.iter().find(|cy| {
+            cy["startsAt"].as_str().is_some_and(|s| s <= now)
+                && cy["endsAt"].as_str().is_some_and(|e| e > now)
+        }),
+    }
+}
+
+/// The first cycle starting after `cycle`.
+fn next_cycle<'a>(cycles: &'a [Value], cycle: &Value) -> Option<&'a Value> {
+    let start = cycle["startsAt"].as_str()?;
+    cycles
+        .iter()
+        .find(|c| c["startsAt"].as_str().is_some_and(|s| s > start))
+}
+
+/// Average completed points over the last `count` finished cycles.
+fn average_velocity(cycles: &[Value], now: &str, count: usize) -> Option<f64> {
+    let finished: Vec<f64> = cycles
+        .iter()
+        .rev()
+        .filter(|c| c["endsAt"].as_str().is_some_and(|e| e <= now))
+        .take(count)
+        .filter_map(|c| c["completedScopeHistory"].as_array()?.last()?.as_f64())
+        .collect();
+    if finished.is_empty() {
+        None
+    } else {
+        Some(finished.iter().sum::<f64>() / finished.len() as f64)
+    }
+}
+
+fn is_unfinished(issue: &Value) -> bool {
+    !matches!(
+        issue["state"]["type"].as_str(),
+        Some("completed") | Some("canceled")
+    )
+}
+
+/// Unfinished issues, limited to the given state names when any are given.
+fn unfinished<'a>(issues: &'a [Value], states: &[String]) -> Vec<&'a Value> {
+    issues
+        .iter()
+        .filter(|i| is_unfinished(i))
+        .filter(|i| {
+            states.is_empty()
+                || states.iter().any(|s| {
+                    i["state"]["name"]
+                        .as_str()
+                        .is_some_and(|n| n.eq_ignore_ascii_case(s))
+                })
+        })
+        .collect()
+}
+
+/// Urgent first, "no priority" last.
+fn priority_rank(issue: &Value) -> i64 {
+    match issue["priority"].as_i64() {
+        Some(p) if p > 0 => p,
+        _ => 5,
+    }
+}
+
+/// Greedily fill `capacity` points with estimated candidates in priority
+/// order (oldest first within a priority). Returns the picks and the
+/// candidates skipped for having no estimate.
+fn plan(candidates: &[Value], capacity: f64) -> (Vec<&Value>, Vec<&Value>) {
+    let mut sorted: Vec<&Value> = candidates.iter().collect();
+    sorted.sort_by(|a, b| {
+        priority_rank(a)
+            .cmp(&priority_rank(b))
+            .then_with(|| a["createdAt"].as_str().cmp(&b["createdAt"].as_str()))
+    });
+    let mut left = capacity;
+    let mut picked = Vec::new();
+    let mut unestimated = Vec::new();
+    for issue in sorted {
+        match issue["estimate"].as_f64() {
+            None => unestimated.push(issue),
+            Some(e) if e <= left => {
+                left -= e;
+                picked.push(issue);
+            }
+            Some(_) => {}
+        }
+    }
+    (picked, unestimated)
+}
+
+fn points(issues: &[&Value]) -> f64 {
+    issues.iter().filter_map(|i| i["estimate"].as_f64()).sum()
+}
+
+async fn cycle_issues(client: &LinearClient, cycle_id: &str) -> Result<Vec<Value>> {
+    let query = r#"
+        query($id: String!) {
+            cycle(id: $id) {
+                issues(first: 250) {
+                    nodes {
+                        id
+                        identifier
+                        title
+                        estimate
+                        priority
+                        state { name type }
+                        assignee { name }
+                    }
+                }
+            }
+        }
+    "#;
+    let result = client.query(query, Some(json!({ "id": cycle_id }))).await?;
+    Ok(result["data"]["cycle"]["issues"]["nodes"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default())
+}
+
+/// Move an issue into a cycle, optionally leaving a comment.
+async fn move_to_cycle(
+    client: &LinearClient,
+    issue: &Value,
+    cycle_id: &str,
+    comment: Option<&str>,
+) -> Result<bool> {
+    let mutation = r#"
+        mutation($id: String!, $input: IssueUpdateInput!) {
+            issueUpdate(id: $id, input: $input) { success }
+        }
+    "#;
+    let id = issue["id"].as_str().unwrap_or("");
+    let result = client
+        .mutate(
+            mutation,
+            Some(json!({ "id": id, "input": { "cycleId": cycle_id } })),
+        )
+        .await?;
+    if result["data"]["issueUpdate"]["success"].as_bool() != Some(true) {
+        return Ok(false);
+    }
+    if let Some(body) = comment {
+        let mutation = r#"
+            mutation($input: CommentCreateInput!) {
+                commentCreate(input: $input) { success }
+            }
+        "#;
+        client
+            .mutate(
+                mutation,
+                Some(json!({ "input": { "issueId": id, "body": body } })),
+            )
+            .await?;
+    }
+    Ok(true)
+}
+
+#[derive(Tabled)]
+struct PlanRow {
+    #[tabled(rename = "ID")]
+    identifier: String,
+    #[tabled(rename = "Title")]
+    title: String,
+    #[tabled(rename = "Priority")]
+    priority: String,
+    #[tabled(rename = "Estimate")]
+    estimate: String,
+    #[tabled(rename = "State")]
+    state: String,
+}
+
+fn plan_row(issue: &Value) -> PlanRow {
+    PlanRow {
+        identifier: issue["identifier"].as_str().unwrap_or("").to_string(),
+        title: truncate(
+            issue["title"].as_str().unwrap_or(""),
+            display_options().max_width(50),
+        ),
+        priority: priority_to_string(issue["priority"].as_i64()),
+        estimate: issue["estimate"]
+            .as_f64()
+            .map(|e| e.to_string())
+            .unwrap_or_else(|| "-".to_string()),
+        state: issue["state"]["name"].as_str().unwrap_or("").to_string(),
+    }
+}
+
+fn issue_refs(issues: &[&Value]) -> Vec<Value> {
+    issues
+        .iter()
+        .map(|i| {
+            json!({
+                "id": i["id"],
+                "identifier": i["identifier"],
+                "title": i["title"],
+                "estimate": i["estimate"],
+                "state": i["state"]["name"],
+            })
+        })
+        .collect()
+}
+
+/// `--team`, or the team in `.linear.toml`.
+#[allow(clippy::too_many_arguments)]
+pub async fn close_cycle(
+    team: Option<String>,
+    cycle: Option<String>,
+    states: Vec<String>,
+    interactive: bool,
+    comment: bool,
+    dry_run: bool,
+    output: &OutputOptions,
+) -> Result<()> {
+    let team = &crate::config::team_or_repo_default(team)?;
+    let client = LinearClient::new()?;
+    let team_id = resolve_team_id(&client, team, &output.cache).await?;
+    let cycles = team_cycles(&client, &team_id).await?;
+    let now = now_iso();
+
+    let from = pick_cycle(&cycles, cycle.as_deref(), &now).ok_or_else(|| match cycle {
+        Some(ref c) => anyhow::anyhow!("Cycle {} not found for team {}", c, team),
+        None => anyhow::anyhow!("No active cycle for team {} (use --cycle)", team),
+    })?;
+    let to = next_cycle(&cycles, from).ok_or_else(|| {
+        anyhow::anyhow!(
+            "No cycle after {} - create one with `linear cycles create`",
+            cycle_label(from)
+        )
+    })?;
+    let (from_label, to_label) = (cycle_label(from), cycle_label(to));
+
+    let issues = cycle_issues(&client, from["id"].as_str().unwrap_or("")).await?;
+    let mut moving = unfinished(&issues, &states);
+
+    if interactive && !moving.is_empty() {
+        let items: Vec<String> = moving
+            .iter()
+            .map(|i| {
+                format!(
+                    "{} {} [{}]",
+                    i["identifier"].as_str().unwrap_or(""),
+                    truncate(i["title"].as_str().unwrap_or(""), Some(60)),
+                    i["state"]["name"].as_str().unwrap_or("")
+                )
+            })
+            .collect();
+        let defaults = vec![true; items.len()];
+        let selected = dialoguer::MultiSelect::new()
+            .with_prompt(format!("Move to {} (space to toggle)", to_label))
+            .items(&items)
+            .defaults(&defaults)
+            .interact()?;
+        moving = selected.into_iter().map(|i| moving[i]).collect();
+    }
+
+    if dry_run || moving.is_empty() {
+        if output.is_json() || output.has_template() {
+            print_json_owned(
+                json!({
+                    "dry_run": dry_run,
+                    "from": from_label,
+                    "to": to_label,
+                    "would_move": issue_refs(&moving),
+                }),
+                output,
+            )?;
+        } else if moving.is_empty() {
+            println!("No unfinished issues to move from {}.", from_label);
+        } else {
+            println!(
+                "{}",
+                format!(
+                    "[DRY RUN] Would move {} issues ({} points) from {} to {}:",
+                    moving.len(),
+                    points(&moving),
+                    from_label,
+                    to_label
+                )
+                .yellow()
+                .bold()
+            );
+            let rows: Vec<PlanRow> = moving.iter().map(|i| plan_row(i)).collect();
+            println!("{}", Table::new(rows));
+        }
+        return Ok(());
+    }
+
+    let body = format!(
+        "Carried over from {} to {}: unfinished when the cycle closed.",
+        from_label, to_label
+    );
+    let to_id = to["id"].as_str().unwrap_or("");
+    let mut moved = Vec::new();
+    let mut failed = Vec::new();
+    for issue in &moving {
+        let identifier = issue["identifier"].as_str().unwrap_or("");
+        if move_to_cycle(&client, issue, to_id, comment.then_some(body.as_str())).await? {
+            if !output.is_json() {
+                eprintln!("{} {} -> {}", "+".green(), identifier.cyan(), to_label);
+            }
+            moved.push(*issue);
+        } else {
+            eprintln!("{} Failed to move {}", "!".red(), identifier);
+            failed.push(*issue);
+        }
+    }
+
+    if output.is_json() || output.has_template() {
+        print_json_owned(
+            json!({
+                "from": from_label,
+                "to": to_label,
+                "moved": issue_refs(&moved),
+                "failed": issue_refs(&failed),
+            }),
+            output,
+        )?;
+    } else {
+        let done = issues.len() - unfinished(&issues, &[]).len();
+        println!(
+            "\n{} closed: {}/{} issues done, {} ({} points) moved to {}",
+            from_label,
+            done,
+            issues.len(),
+            moved.len(),
+            points(&moved),
+            to_label
+        );
+    }
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn plan_cycle(
+    team: Option<String>,
+    cycle: Option<String>,
+    capacity: Option<f64>,
+    velocity_cycles: usize,
+    apply: bool,
+    dry_run: bool,
+    output: &OutputOptions,
+) -> Result<()> {
+    let team = &crate::config::team_or_repo_default(team)?;
+    let client = LinearClient::new()?;
+    let team_id = resolve_team_id(&client, team, &output.cache).await?;
+    let cycles = team_cycles(&client, &team_id).await?;
+    let now = now_iso();
+
+    // Plan the given cycle, or the one after the active cycle
+    let target = match cycle {
+        Some(ref c) => pick_cycle(&cycles, Some(c), &now)
+            .ok_or_else(|| anyhow::anyhow!("Cycle {} not found for team {}", c, team))?,
+        None => match pick_cycle(&cycles, None, &now) {
+            Some(active) => next_cycle(&cycles, active),
+            None => cycles
+                .iter()
+                .find(|c| c["startsAt"].as_str().is_some_and(|s| s > now.as_str())),
+        }
+        .ok_or_else(|| anyhow::anyhow!("No upcoming cycle for team {} - create one first", team))?,
+    };
+    let target_label = cycle_label(target);
+
+    let velocity = average_velocity(&cycles, &now, velocity_cycles);
+    let capacity = match capacity.or(velocity) {
+        Some(c) => c,
+        None => anyhow::bail!("No finished cycles to derive velocity from; pass --capacity"),
+    };
+    let target_id = target["id"].as_str().unwrap_or("");
+    let committed_issues = cycle_issues(&client, target_id).await?;
+    let committed: f64 = committed_issues
+        .iter()
+        .filter_map(|i| i["estimate"].as_f64())
+        .sum();
+
+    let query = r#"
+        query($filter: IssueFilter, $first: Int, $after: String, $last: Int, $before: String) {
+            issues(first: $first, after: $after, last: $last, before: $before, filter: $filter) {
+                nodes {
+                    id
+                    identifier
+                    title
+                    estimate
+                    priority
+                    createdAt
+                    state { name type }
+                }
+                pageInfo {
+                    hasNextPage
+                    endCursor
+                    hasPreviousPage
+                    startCursor
+                }
+            }
+        }
+    "#;
+    let mut vars = serde_json::Map::new();
+    vars.insert(
+        "filter".to_string(),
+        json!({
+            "team": { "id": { "eq": team_id } },
+            "cycle": { "null": true },
+            "state": { "type": { "in": ["backlog", "unstarted"] } },
+        }),
+    );
+    // Every candidate, so priority ordering covers the whole backlog
+    let pagination = PaginationOptions {
+        all: true,
+        ..Default::default()
+    };
+    let candidates = paginate_nodes(
+        &client,
+        query,
+        vars,
+        &["data", "issues", "nodes"],
+        &["data", "issues", "pageInfo"],
+        &pagination,
+        250,
+    )
+    .await?;
+    let (picked, unestimated) = plan(&candidates, (capacity - committed).max(0.0));
+    let planned = points(&picked);
+
+    if apply && !dry_run {
+        for issue in &picked {
+            let identifier = issue["identifier"].as_str().unwrap_or("");
+            if move_to_cycle(&client, issue, target_id, None).await? {
+                if !output.is_json() {
+                    eprintln!("{} {} -> {}", "+".green(), identifier.cyan(), target_label);
+                }
+            } else {
+                eprintln!("{} Failed to move {}", "!".red(), identifier);
+            }
+        }
+    }
+
+    if output.is_json() || output.has_template() {
+        print_json_owned(
+            json!({
+                "cycle": target_label,
+                "capacity": capacity,
+                "velocity": velocity,
+                "committed": committed,
+                "planned": planned,
+                "applied": apply && !dry_run,
+                "issues": issue_refs(&picked),
+                "unestimated": issue_refs(&unestimated),
+            }),
+            output,
+        )?;
+        return Ok(());
+    }
+
+    println!("{} {}", "Plan for".bold(), target_label.bold());
+    match velocity {
+        Some(v) => println!(
+            "Velocity: {:.1} points/cycle (last {} cycles)",
+            v, velocity_cycles
+        ),
+        None => println!("Velocity: -"),
+    }
+    println!(
+        "Capacity: {} points, {} already in cycle, {} proposed ({} issues)",
+        capacity,
+        committed,
+        planned,
+        picked.len()
+    );
+    if picked.is_empty() {
+        println!("\nNo estimated backlog issues fit the remaining capacity.");
+    } else {
+        let rows: Vec<PlanRow> = picked.iter().map(|i| plan_row(i)).collect();
+        println!("\n{}", Table::new(rows));
+    }
+    if !unestimated.is_empty() {
+        println!(
+            "\n{} {} backlog issues need an estimate before they can be planned",
+            "!".yellow(),
+            unestimated.len()
+        );
+    }
+    if !picked.is_empty() {
+        if dry_run && apply {
+            println!(
+                "\n{}",
+                format!(
+                    "[DRY RUN] Would move {} issues to {}",
+                    picked.len(),
+                    target_label
+                )
+                .yellow()
+                .bold()
+            );
+        } else if !apply {
+            println!("\nRun with --apply to move these issues into the cycle.");
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cycle(number: u64, starts: &str, ends: &str, completed: &[f64]) -> Value {
+        json!({
+            "id": format!("c{}", number),
+            "number": number,
+            "name": null,
+            "startsAt": starts,
+            "endsAt": ends,
+            "completedScopeHistory": completed,
+        })
+    }
+
+    fn cycles() -> Vec<Value> {
+        vec![
+            cycle(
+                1,
+                "2026-01-05T00:00:00Z",
+                "2026-01-19T00:00:00Z",
+                &[0.0, 20.0],
+            ),
+            cycle(
+                2,
+                "2026-01-19T00:00:00Z",
+                "2026-02-02T00:00:00Z",
+                &[0.0, 30.0],
+            ),
+            cycle(
+                3,
+                "2026-02-02T00:00:00Z",
+                "2026-02-16T00:00:00Z",
+                &[0.0, 5.0],
+            ),
+            cycle(4, "2026-02-16T00:00:00Z", "2026-03-02T00:00:00Z", &[]),
+        ]
+    }
+
+    #[test]
+    fn test_pick_and_next_cycle() {
+        let cycles = cycles();
+        let now = "2026-02-10T00:00:00Z";
+        let active = pick_cycle(&cycles, None, now).unwrap();
+        assert_eq!(active["number"], 3);
+        assert_eq!(next_cycle(&cycles, active).unwrap()["number"], 4);
+        assert_eq!(pick_cycle(&cycles, Some("2"), now).unwrap()["id"], "c2");
+        assert_eq!(pick_cycle(&cycles, Some("c1"), now).unwrap()["number"], 1);
+        assert!(next_cycle(&cycles, &cycles[3]).is_none());
+        assert_eq!(cycle_label(active), "Cycle 3");
+    }
+
+    #[test]
+    fn test_average_velocity_uses_finished_cycles() {
+        let cycles = cycles();
+        assert_eq!(
+            average_velocity(&cycles, "2026-02-10T00:00:00Z", 2),
+            Some(25.0)
+        );
+        assert_eq!(average_velocity(&cycles, "2026-01-10T00:00:00Z", 3), None);
+    }
+
+    #[test]
+    fn test_unfinished_filters_by_state() {
+        let issues = vec![
+            json!({ "state": { "name": "Done", "type": "completed" } }),
+            json!({ "state": { "name": "In Progress", "type": "started" } }),
+            json!({ "state": { "name": "Todo", "type": "unstarted" } }),
+        ];
+        assert_eq!(unfinished(&issues, &[]).len(), 2);
+        let only = unfinished(&issues, &["in progress".to_string()]);
+        assert_eq!(only.len(), 1);
+        assert_eq!(only[0]["state"]["name"], "In Progress");
+    }
+
+    #[test]
+    fn test_plan_by_priority_within_capacity() {
+        let issue = |id: &str, priority: i64, estimate: Option<f64>, created: &str| json!({ "identifier": id, "priority": priority, "estimate": estimate, "createdAt": created });
+        let candidates = vec![
+            issue("A", 3, Some(5.0), "2026-01-01"),
+            issue("B", 1, Some(8.0), "2026-01-02"),
+            issue("C", 0, Some(1.0), "2026-01-01"),
+            issue("D", 2, Some(8.0), "2026-01-01"),
+            issue("E", 2, None, "2026-01-01"),
+            issue("F", 3, Some(2.0), "2026-01-02"),
+        ];
+        let (picked, unestimated) = plan(&candidates, 20.0);
+        let ids: Vec<&str> = picked
+            .iter()
+            .map(|i| i["identifier"].as_str().unwrap())
+            .collect();
+        // B(8) + D(8) = 16, A(5) doesn't fit, F(2) does, then C(1)
+        assert_eq!(ids, vec!["B", "D", "F", "C"]);
+        assert_eq!(unestimated.len(), 1);
+        assert_eq!(points(&picked), 19.0);
+    }
+}
@@ -0,0 +1,744 @@
+use anyhow::{Context, Result};
+use colored::Colorize;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+
+use super::cycles_plan::{cycle_label, now_iso, pick_cycle, team_cycles};
+use super::metrics::BurnData;
+use crate::api::{resolve_team_id, LinearClient};
+use crate::output::{print_json_owned, OutputOptions};
+use crate::pagination::{paginate_nodes, PaginationOptions};
+
+/// An issue mentioned in the report.
+#[derive(Debug, Clone)]
+struct Item {
+    identifier: String,
+    url: String,
+    text: String,
+}
+
+/// Report content, rendered as Markdown or HTML.
+enum Block {
+    Heading(u8, String),
+    Para(String),
+    Table(Vec<String>, Vec<Vec<String>>),
+    List(Vec<Item>),
+}
+
+fn to_markdown(blocks: &[Block]) -> String {
+    let mut out = String::new();
+    for block in blocks {
+        match block {
+            Block::Heading(level, text) => {
+                out.push_str(&format!("{} {}\n\n", "#".repeat(*level as usize), text))
+            }
+            Block::Para(text) => out.push_str(&format!("{}\n\n", text)),
+            Block::Table(headers, rows) => {
+                out.push_str(&format!("| {} |\n", headers.join(" | ")));
+                out.push_str(&format!("|{}\n", "---|".repeat(headers.len())));
+                for row in rows {
+                    out.push_str(&format!("| {} |\n", row.join(" | ")));
+                }
+                out.push('\n');
+            }
+            Block::List(items) => {
+                for item in items {
+                    let id = if item.url.is_empty() {
+                        item.identifier.clone()
+                    } else {
+                        format!("[{}]({})", item.identifier, item.url)
+                    };
+                    out.push_str(&format!("- {} {}\n", id, item.text));
+                }
+                out.push('\n');
+            }
+        }
+    }
+    out.trim_end().to_string() + "\n"
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn to_html(title: &str, blocks: &[Block]) -> String {
+    let mut out = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n\
+         <style>body{{font-family:sans-serif;max-width:48em;margin:2em auto}}\
+         table{{border-collapse:collapse}}td,th{{border:1px solid #ddd;padding:4px 8px;text-align:left}}</style>\n\
+         </head>\n<body>\n",
+        escape_html(title)
+    );
+    for block in blocks {
+        match block {
+            Block::Heading(level, text) => {
+                out.push_str(&format!("<h{l}>{}</h{l}>\n", escape_html(text), l = level))
+            }
+            Block::Para(text) => out.push_str(&format!("<p>{}</p>\n", escape_html(text))),
+            Block::Table(headers, rows) => {
+                out.push_str("<table>\n<tr>");
+                for h in headers {
+                    out.push_str(&format!("<th>{}</th>", escape_html(h)));
+                }
+                out.push_str("</tr>\n");
+                for row in rows {
+                    out.push_str("<tr>");
+                    for cell in row {
+                        out.push_str(&format!("<td>{}</td>", escape_html(cell)));
+                    }
+                    out.push_str("</tr>\n");
+                }
+                out.push_str("</table>\n");
+            }
+            Block::List(items) => {
+                out.push_str("<ul>\n");
+                for item in items {
+                    let id = if item.url.is_empty() {
+                        escape_html(&item.identifier)
+                    } else {
+                        format!(
+                            "<a href=\"{}\">{}</a>",
+                            escape_html(&item.url),
+                            escape_html(&item.identifier)
+                        )
+                    };
+                    out.push_str(&format!("<li>{} {}</li>\n", id, escape_html(&item.text)));
+                }
+                out.push_str("</ul>\n");
+            }
+        }
+    }
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+fn item(issue: &Value, suffix: &str) -> Item {
+    Item {
+        identifier: issue["identifier"].as_str().unwrap_or("").to_string(),
+        url: issue["url"].as_str().unwrap_or("").to_string(),
+        text: format!("{}{}", issue["title"].as_str().unwrap_or(""), suffix),
+    }
+}
+
+fn is_done(state_type: Option<&str>) -> bool {
+    matches!(state_type, Some("completed") | Some("canceled"))
+}
+
+/// Whether the issue went from completed back to an open state between
+/// `start` and `end`.
+fn was_reopened(issue: &Value, start: &str, end: &str) -> bool {
+    issue["history"]["nodes"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .any(|h| {
+            h["fromState"]["type"].as_str() == Some("completed")
+                && !is_done(h["toState"]["type"].as_str())
+                && h["createdAt"]
+                    .as_str()
+                    .is_some_and(|t| t >= start && t < end)
+        })
+}
+
+/// Whether the fetched history reaches back to `start`: either it is
+/// complete or its oldest event predates the window.
+fn history_covers(issue: &Value, start: &str) -> bool {
+    let history = &issue["history"];
+    history["pageInfo"]["hasNextPage"].as_bool() != Some(true)
+        || history["nodes"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|h| h["createdAt"].as_str())
+            .min()
+            .is_some_and(|oldest| oldest < start)
+}
+
+/// An issue's whole state history, newest first.
+async fn full_history(client: &LinearClient, issue_id: &str) -> Result<Vec<Value>> {
+    let query = r#"
+        query($id: String!, $first: Int, $after: String, $last: Int, $before: String) {
+            issue(id: $id) {
+                history(first: $first, after: $after, last: $last, before: $before, orderBy: createdAt) {
+                    nodes { createdAt fromState { type } toState { type } }
+                    pageInfo { hasNextPage endCursor hasPreviousPage startCursor }
+                }
+            }
+        }
+    "#;
+    let mut vars = serde_json::Map::new();
+    vars.insert("id".to_string(), json!(issue_id));
+    let pagination = PaginationOptions {
+        all: true,
+        ..Default::default()
+    };
+    paginate_nodes(
+        client,
+        query,
+        vars,
+        &["data", "issue", "history", "nodes"],
+        &["data", "issue", "history", "pageInfo"],
+        &pagination,
+        100,
+    )
+    .await
+}
+
+/// Open issues blocking this one.
+fn open_blockers(issue: &Value) -> Vec<String> {
+    issue["inverseRelations"]["nodes"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter(|r| r["type"].as_str() == Some("blocks"))
+        .filter(|r| !is_done(r["issue"]["state"]["type"].as_str()))
+        .filter_map(|r| r["issue"]["identifier"].as_str().map(String::from))
+        .collect()
+}
+
+struct AssigneeStats {
+    issues: usize,
+    completed: usize,
+    points: f64,
+}
+
+fn per_assignee(issues: &[Value]) -> BTreeMap<String, AssigneeStats> {
+    let mut map: BTreeMap<String, AssigneeStats> = BTreeMap::new();
+    for issue in issues {
+        let name = issue["assignee"]["name"]
+            .as_str()
+            .unwrap_or("Unassigned")
+            .to_string();
+        let entry = map.entry(name).or_insert(AssigneeStats {
+            issues: 0,
+            completed: 0,
+            points: 0.0,
+        });
+        entry.issues += 1;
+        if issue["state"]["type"].as_str() == Some("completed") {
+            entry.completed += 1;
+            entry.points += issue["estimate"].as_f64().unwrap_or(0.0);
+        }
+    }
+    map
+}
+
+/// Scope committed at the start, added and removed during the cycle, and
+/// the final and completed scope.
+struct Scope {
+    unit: &'static str,
+    committed: f64,
+    added: f64,
+    removed: f64,
+    final_scope: f64,
+    completed: f64,
+}
+
+impl Scope {
+    fn from_burn(burn: &BurnData) -> Scope {
+        let changes = burn.scope_changes();
+        Scope {
+            unit: burn.unit,
+            committed: burn.scope.first().copied().unwrap_or(0.0),
+            added: changes
+                .iter()
+                .filter(|(_, d)| *d > 0.0)
+                .map(|(_, d)| d)
+                .sum(),
+            removed: -changes
+                .iter()
+                .filter(|(_, d)| *d < 0.0)
+                .map(|(_, d)| d)
+                .sum::<f64>(),
+            final_scope: burn.scope.last().copied().unwrap_or(0.0),
+            completed: burn.completed.last().copied().unwrap_or(0.0),
+        }
+    }
+
+    fn completion(&self) -> f64 {
+        if self.final_scope > 0.0 {
+            (self.completed / self.final_scope * 100.0).round()
+        } else {
+            0.0
+        }
+    }
+}
+
+async fn count_issues(client: &LinearClient, filter: Value) -> Result<usize> {
+    let query = r#"
+        query($filter: IssueFilter, $first: Int, $after: String, $last: Int, $before: String) {
+            issues(first: $first, after: $after, last: $last, before: $before, filter: $filter) {
+                nodes { id }
+                pageInfo { hasNextPage endCursor hasPreviousPage startCursor }
+            }
+        }
+    "#;
+    let mut vars = serde_json::Map::new();
+    vars.insert("filter".to_string(), filter);
+    let pagination = PaginationOptions {
+        all: true,
+        ..Default::default()
+    };
+    Ok(paginate_nodes(
+        client,
+        query,
+        vars,
+        &["data", "issues", "nodes"],
+        &["data", "issues", "pageInfo"],
+        &pagination,
+        250,
+    )
+    .await?
+    .len())
+}
+
+/// The cycle's issues, with state history reaching back to the cycle start
+/// so reopen events inside the cycle are found.
+async fn fetch_cycle_issues(
+    client: &LinearClient,
+    cycle_id: &str,
+    start: &str,
+) -> Result<Vec<Value>> {
+    let query = r#"
+        query($filter: IssueFilter, $first: Int, $after: String, $last: Int, $before: String) {
+            issues(first: $first, after: $after, last: $last, before: $before, filter: $filter) {
+                nodes {
+                    id
+                    identifier
+                    title
+                    url
+                    estimate
+                    state { name type }
+                    assignee { name }
+                    history(first: 30, orderBy: createdAt) {
+                        nodes { createdAt fromState { type } toState { type } }
+                        pageInfo { hasNextPage }
+                    }
+                    inverseRelations {
+                        nodes { type issue { identifier state { type } } }
+                    }
+                }
+                pageInfo { hasNextPage endCursor hasPreviousPage startCursor }
+            }
+        }
+    "#;
+    let mut vars = serde_json::Map::new();
+    vars.insert(
+        "filter".to_string(),
+        json!({ "cycle": { "id": { "eq": cycle_id } } }),
+    );
+    let pagination = PaginationOptions {
+        all: true,
+        ..Default::default()
+    };
+    let mut issues = paginate_nodes(
+        client,
+        query,
+        vars,
+        &["data", "issues", "nodes"],
+        &["data", "issues", "pageInfo"],
+        &pagination,
+        50,
+    )
+    .await?;
+
+    // Busy issues have more events since the cycle than fit in one page
+    for issue in issues.iter_mut() {
+        if history_covers(issue, start) {
+            continue;
+        }
+        if let Some(id) = issue["id"].as_str().map(String::from) {
+            issue["history"]["nodes"] = Value::Array(full_history(client, &id).await?);
+        }
+    }
+    Ok(issues)
+}
+
+pub async fn cycle_report(
+    cycle: &str,
+    team: Option<String>,
+    html: bool,
+    file: Option<String>,
+    bug_label: &str,
+    publish: Option<String>,
+    output: &OutputOptions,
+) -> Result<()> {
+    if (output.is_json() || output.has_template()) && (file.is_some() || publish.is_some()) {
+        anyhow::bail!(
+            "--file and --publish write the rendered report; drop --output json to use them"
+        );
+    }
+    let client = LinearClient::new()?;
+
+    // Numbers and "current" need the team; anything else is a cycle ID
+    let by_team = cycle.parse::<u64>().is_ok() || cycle.eq_ignore_ascii_case("current");
+    let cycle_id = if by_team {
+        let team = crate::config::team_or_repo_default(team)?;
+        let team_id = resolve_team_id(&client, &team, &output.cache).await?;
+        let cycles = team_cycles(&client, &team_id).await?;
+        let wanted = (!cycle.eq_ignore_ascii_case("current")).then_some(cycle);
+        pick_cycle(&cycles, wanted, &now_iso())
+            .and_then(|c| c["id"].as_str().map(String::from))
+            .ok_or_else(|| anyhow::anyhow!("Cycle {} not found for team {}", cycle, team))?
+    } else {
+        cycle.to_string()
+    };
+
+    let query = r#"
+        query($id: String!) {
+            cycle(id: $id) {
+                id
+                number
+                name
+                startsAt
+                endsAt
+                completedAt
+                team { id name }
+                scopeHistory
+                completedScopeHistory
+                issueCountHistory
+                completedIssueCountHistory
+                uncompletedIssuesUponClose {
+                    nodes { identifier title url state { name } }
+                }
+            }
+        }
+    "#;
+    let result = client.query(query, Some(json!({ "id": cycle_id }))).await?;
+    let data = &result["data"]["cycle"];
+    if data.is_null() {
+        anyhow::bail!("Cycle not found: {}", cycle);
+    }
+    let label = cycle_label(data);
+    let start = data["startsAt"].as_str().unwrap_or("");
+    let end = data["endsAt"].as_str().unwrap_or("");
+    let issues = fetch_cycle_issues(&client, &cycle_id, start).await?;
+    let team_id = data["team"]["id"].as_str().unwrap_or("");
+    let scope = BurnData::from_cycle(data, false).map(|b| Scope::from_burn(&b));
+
+    // Carried over: Linear's record for closed cycles, else what's open now
+    let carried: Vec<Item> = if data["completedAt"].is_string() {
+        data["uncompletedIssuesUponClose"]["nodes"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|i| {
+                item(
+                    i,
+                    &format!(" ({})", i["state"]["name"].as_str().unwrap_or("")),
+                )
+            })
+            .collect()
+    } else {
+        issues
+            .iter()
+            .filter(|i| !is_done(i["state"]["type"].as_str()))
+            .map(|i| {
+                item(
+                    i,
+                    &format!(" ({})", i["state"]["name"].as_str().unwrap_or("")),
+                )
+            })
+            .collect()
+    };
+    let reopened: Vec<Item> = issues
+        .iter()
+        .filter(|i| was_reopened(i, start, end))
+        .map(|i| item(i, ""))
+        .collect();
+    let blocked: Vec<Item> = issues
+        .iter()
+        .filter(|i| !is_done(i["state"]["type"].as_str()))
+        .filter_map(|i| {
+            let blockers = open_blockers(i);
+            (!blockers.is_empty())
+                .then(|| item(i, &format!(" - blocked by {}", blockers.join(", "))))
+        })
+        .collect();
+    let assignees = per_assignee(&issues);
+    let completed_issues = issues
+        .iter()
+        .filter(|i| i["state"]["type"].as_str() == Some("completed"))
+        .count();
+
+    let bug_filter = |field: &str| {
+        let mut f = json!({
+            "team": { "id": { "eq": team_id } },
+            "labels": { "name": { "eqIgnoreCase": bug_label } },
+        });
+        f[field] = json!({ "gte": start, "lt": end });
+        f
+    };
+    let bugs_created = count_issues(&client, bug_filter("createdAt")).await?;
+    let bugs_closed = count_issues(&client, bug_filter("completedAt")).await?;
+
+    if output.is_json() || output.has_template() {
+        let items = |list: &[Item]| -> Vec<Value> {
+            list.iter()
+                .map(|i| json!({ "identifier": i.identifier, "url": i.url, "text": i.text }))
+                .collect()
+        };
+        print_json_owned(
+            json!({
+                "cycle": label,
+                "team": data["team"]["name"],
+                "starts_at": start,
+                "ends_at": end,
+                "scope": scope.as_ref().map(|s| json!({
+                    "unit": s.unit,
+                    "committed": s.committed,
+                    "added": s.added,
+                    "removed": s.removed,
+                    "final": s.final_scope,
+                    "completed": s.completed,
+                    "completion_pct": s.completion(),
+                })),
+                "issues": { "total": issues.len(), "completed": completed_issues },
+                "assignees": assignees.iter().map(|(name, a)| json!({
+                    "name": name,
+                    "issues": a.issues,
+                    "completed": a.completed,
+                    "points_completed": a.points,
+                })).collect::<Vec<_>>(),
+                "bugs": { "label": bug_label, "created": bugs_created, "closed": bugs_closed },
+                "carried_over": items(&carried),
+                "reopened": items(&reopened),
+                "blocked": items(&blocked),
+            }),
+            output,
+        )?;
+        return Ok(());
+    }
+
+    let title = format!("{} retrospective", label);
+    let mut blocks = vec![
+        Block::Heading(1, title.clone()),
+        Block::Para(format!(
+            "Team: {} · Period: {} to {}",
+            data["team"]["name"].as_str().unwrap_or("-"),
+            start.get(..10).unwrap_or(start),
+            end.get(..10).unwrap_or(end)
+        )),
+        Block::Heading(2, "Scope".to_string()),
+    ];
+    if let Some(ref s) = scope {
+        blocks.push(Block::Table(
+            vec!["".to_string(), capitalize(s.unit)],
+            vec![
+                vec!["Committed at start".to_string(), fmt(s.committed)],
+                vec!["Added mid-cycle".to_string(), format!("+{}", fmt(s.added))],
+                vec![
+                    "Removed mid-cycle".to_string(),
+                    format!("-{}", fmt(s.removed)),
+                ],
+                vec!["Final scope".to_string(), fmt(s.final_scope)],
+                vec![
+                    "Completed".to_string(),
+                    format!("{} ({}%)", fmt(s.completed), s.completion()),
+                ],
+            ],
+        ));
+    }
+    blocks.push(Block::Para(format!(
+        "Issues completed: {}/{}",
+        completed_issues,
+        issues.len()
+    )));
+    blocks.push(Block::Heading(2, "By assignee".to_string()));
+    blocks.push(Block::Table(
+        vec![
+            "Assignee".to_string(),
+            "Completed".to_string(),
+            "Points done".to_string(),
+        ],
+        assignees
+            .iter()
+            .map(|(name, a)| {
+                vec![
+                    name.clone(),
+                    format!("{}/{}", a.completed, a.issues),
+                    fmt(a.points),
+                ]
+            })
+            .collect(),
+    ));
+    blocks.push(Block::Heading(2, "Bugs".to_string()));
+    blocks.push(Block::Para(format!(
+        "Created: {} · Closed: {} (label \"{}\")",
+        bugs_created, bugs_closed, bug_label
+    )));
+    for (heading, list) in [
+        ("Carried over", &carried),
+        ("Reopened", &reopened),
+        ("Blocked", &blocked),
+    ] {
+        blocks.push(Block::Heading(2, format!("{} ({})", heading, list.len())));
+        if list.is_empty() {
+            blocks.push(Block::Para("None.".to_string()));
+        } else {
+            blocks.push(Block::List(list.clone()));
+        }
+    }
+
+    let markdown = to_markdown(&blocks);
+    let rendered = if html {
+        to_html(&title, &blocks)
+    } else {
+        markdown.clone()
+    };
+    match file {
+        Some(ref path) => {
+            std::fs::write(path, &rendered).with_context(|| format!("Failed to write {}", path))?;
+            eprintln!("{} Wrote {}", "+".green(), path);
+        }
+        None if publish.is_none() => print!("{}", rendered),
+        None => {}
+    }
+
+    // Linear documents are Markdown regardless of --html
+    if let Some(project) = publish {
+        super::documents::create_document(&title, &project, Some(markdown), None, None, output)
+            .await?;
+    }
+    Ok(())
+}
+
+fn fmt(v: f64) -> String {
+    if v.fract() == 0.0 {
+        format!("{}", v as i64)
+    } else {
+        format!("{:.1}", v)
+    }
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) => c.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_was_reopened_within_cycle() {
+        let issue = json!({ "history": { "nodes": [
+            { "createdAt": "2026-03-05T10:00:00.000Z",
+              "fromState": { "type": "completed" }, "toState": { "type": "started" } },
+        ]}});
+        assert!(was_reopened(
+            &issue,
+            "2026-03-02T00:00:00.000Z",
+            "2026-03-16T00:00:00.000Z"
+        ));
+        assert!(!was_reopened(
+            &issue,
+            "2026-03-16T00:00:00.000Z",
+            "2026-03-30T00:00:00.000Z"
+        ));
+        let canceled = json!({ "history": { "nodes": [
+            { "createdAt": "2026-03-05T10:00:00.000Z",
+              "fromState": { "type": "completed" }, "toState": { "type": "canceled" } },
+        ]}});
+        assert!(!was_reopened(
+            &canceled,
+            "2026-03-02T00:00:00.000Z",
+            "2026-03-16T00:00:00.000Z"
+        ));
+    }
+
+    #[test]
+    fn test_history_covers_cycle_start() {
+        let issue = |has_next: bool, oldest: &str| {
+            json!({ "history": {
+                "nodes": [{ "createdAt": "2026-03-20T00:00:00.000Z" }, { "createdAt": oldest }],
+                "pageInfo": { "hasNextPage": has_next },
+            }})
+        };
+        let start = "2026-03-02T00:00:00.000Z";
+        assert!(history_covers(
+            &issue(false, "2026-03-10T00:00:00.000Z"),
+            start
+        ));
+        assert!(history_covers(
+            &issue(true, "2026-02-27T00:00:00.000Z"),
+            start
+        ));
+        assert!(!history_covers(
+            &issue(true, "2026-03-10T00:00:00.000Z"),
+            start
+        ));
+    }
+
+    #[test]
+    fn test_open_blockers() {
+        let issue = json!({ "inverseRelations": { "nodes": [
+            { "type": "blocks", "issue": { "identifier": "ENG-1", "state": { "type": "started" } } },
+            { "type": "blocks", "issue": { "identifier": "ENG-2", "state": { "type": "completed" } } },
+            { "type": "related", "issue": { "identifier": "ENG-3", "state": { "type": "started" } } },
+        ]}});
+        assert_eq!(open_blockers(&issue), vec!["ENG-1"]);
+    }
+
+    #[test]
+    fn test_scope_from_burn() {
+        let cycle = json!({
+            "startsAt": "2026-03-02T00:00:00.000Z",
+            "endsAt": "2026-03-16T00:00:00.000Z",
+            "scopeHistory": [20, 25, 23, 26],
+            "completedScopeHistory": [0, 5, 10, 13],
+        });
+        let scope = Scope::from_burn(&BurnData::from_cycle(&cycle, false).unwrap());
+        assert_eq!(scope.committed, 20.0);
+        assert_eq!(scope.added, 8.0);
+        assert_eq!(scope.removed, 2.0);
+        assert_eq!(scope.final_scope, 26.0);
+        assert_eq!(scope.completion(), 50.0);
+    }
+
+    #[test]
+    fn test_per_assignee() {
+        let issues = vec![
+            json!({ "assignee": { "name": "Ann" }, "estimate": 3, "state": { "type": "completed" } }),
+            json!({ "assignee": { "name": "Ann" }, "estimate": 5, "state": { "type": "started" } }),
+            json!({ "assignee": null, "state": { "type": "completed" } }),
+        ];
+        let stats = per_assignee(&issues);
+        assert_eq!(stats["Ann"].issues, 2);
+        assert_eq!(stats["Ann"].completed, 1);
+        assert_eq!(stats["Ann"].points, 3.0);
+        assert_eq!(stats["Unassigned"].completed, 1);
+    }
+
+    #[test]
+    fn test_render_markdown_and_html() {
+        let blocks = vec![
+            Block::Heading(1, "Cycle 3 retrospective".to_string()),
+            Block::Table(
+                vec!["A".to_string(), "B".to_string()],
+                vec![vec!["1".to_string(), "<2>".to_string()]],
+            ),
+            Block::List(vec![Item {
+                identifier: "ENG-1".to_string(),
+                url: "https://linear.app/x/issue/ENG-1".to_string(),
+                text: "Fix & ship".to_string(),
+            }]),
+        ];
+        let md = to_markdown(&blocks);
+        assert!(md.starts_with("# Cycle 3 retrospective\n"));
+        assert!(md.contains("| A | B |\n|---|---|\n| 1 | <2> |"));
+        assert!(md.contains("- [ENG-1](https://linear.app/x/issue/ENG-1) Fix & ship"));
+        let html = to_html("Cycle 3", &blocks);
+        assert!(html.contains("<h1>Cycle 3 retrospective</h1>"));
+        assert!(html.contains("<td>&lt;2&gt;</td>"));
+        assert!(
+            html.contains("<a href=\"https://linear.app/x/issue/ENG-1\">ENG-1</a> Fix &amp; ship")
+        );
+    }
+}
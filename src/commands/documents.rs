@@ -353,7 +353,7 @@ async fn get_documents(ids: &[String], output: &OutputOptions) -> Result<()> {
     Ok(())
 }
 
-async fn create_document(
+pub(super) async fn create_document(
     title: &str,
     project: &str,
     content: Option<String>,
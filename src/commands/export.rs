@@ -1,10 +1,13 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::Subcommand;
 use csv::Writer;
-use serde_json::json;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
 use std::io::Write;
+use std::path::Path;
 
 use crate::api::LinearClient;
+use crate::dates::is_later;
 use crate::output::OutputOptions;
 use crate::pagination::{paginate_nodes, stream_nodes, PaginationOptions};
 
@@ -43,9 +46,62 @@ pub enum ExportCommands {
         #[arg(long)]
         all: bool,
     },
+    /// Export issues as NDJSON upsert/tombstone records
+    #[command(after_help = r#"EXAMPLES:
+    linear export ndjson --team ENG                          # Full export
+    linear exp ndjson -t ENG --since-last-run state.json     # Only issues updated since last run
+    linear exp ndjson --since-last-run state.json -f out.ndjson
+
+Each line is {"op":"upsert","id":...,"updatedAt":...,"issue":{...}} or, for
+archived issues, {"op":"delete","id":...,"archivedAt":...}. The state file is
+only advanced after the export completes (and never with --dry-run), and is
+tied to the --team it was written for."#)]
+    Ndjson {
+        /// Team key to export
+        #[arg(short, long)]
+        team: Option<String>,
+        /// Output file (default: stdout)
+        #[arg(short, long)]
+        file: Option<String>,
+        /// Checkpoint file: export only issues updated after the recorded updatedAt, then advance it
+        #[arg(long, value_name = "STATEFILE")]
+        since_last_run: Option<String>,
+    },
+    /// Export issues into a normalized SQLite database (requires the `sqlite` feature)
+    #[command(after_help = r#"EXAMPLES:
+    linear export sqlite team.db --team ENG    # Create or update team.db
+    linear exp sqlite team.db -t ENG --full    # Re-fetch everything
+
+Tables: issues, states, users, labels, issue_labels, cycles, projects,
+relations, history_events (latest 50 events per issue). Rows are upserted, so
+re-running only fetches issues updated since the previous export. With
+--dry-run the issues are fetched and counted but the database isn't touched."#)]
+    Sqlite {
+        /// Path to the SQLite database (created if missing)
+        database: String,
+        /// Team key to export
+        #[arg(short, long)]
+        team: Option<String>,
+        /// Ignore the stored checkpoint and re-export all issues
+        #[arg(long)]
+        full: bool,
+    },
+}
+
+/// Checkpoint persisted between incremental NDJSON exports
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ExportCheckpoint {
+    /// Team key the export was scoped to (None for the whole workspace)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    team: Option<String>,
+    /// Highest issue `updatedAt` seen by the last completed export
+    updated_at: String,
+    /// When the last export completed
+    exported_at: String,
 }
 
-pub async fn handle(cmd: ExportCommands, _output: &OutputOptions) -> Result<()> {
+pub async fn handle(cmd: ExportCommands, output: &OutputOptions) -> Result<()> {
     match cmd {
         ExportCommands::Csv {
             team,
@@ -60,9 +116,41 @@ pub async fn handle(cmd: ExportCommands, _output: &OutputOptions) -> Result<()>
             limit,
             all,
         } => export_markdown(team, file, limit, all).await,
+        ExportCommands::Ndjson {
+            team,
+            file,
+            since_last_run,
+        } => export_ndjson(team, file, since_last_run, output).await,
+        ExportCommands::Sqlite {
+            database,
+            team,
+            full,
+        } => export_sqlite(&database, team, full, output.dry_run).await,
     }
 }
 
+#[cfg(feature = "sqlite")]
+async fn export_sqlite(
+    database: &str,
+    team: Option<String>,
+    full: bool,
+    dry_run: bool,
+) -> Result<()> {
+    super::export_sqlite::export_sqlite(database, team, full, dry_run).await
+}
+
+#[cfg(not(feature = "sqlite"))]
+async fn export_sqlite(
+    _database: &str,
+    _team: Option<String>,
+    _full: bool,
+    _dry_run: bool,
+) -> Result<()> {
+    anyhow::bail!(
+        "SQLite export requires the 'sqlite' feature. Rebuild with: cargo build --features sqlite"
+    )
+}
+
 async fn export_csv(
     team: Option<String>,
     file: Option<String>,
@@ -332,3 +420,289 @@ async fn export_markdown(
 
     Ok(())
 }
+
+async fn export_ndjson(
+    team: Option<String>,
+    file: Option<String>,
+    since_last_run: Option<String>,
+    output: &OutputOptions,
+) -> Result<()> {
+    let client = LinearClient::new()?;
+
+    let query = r#"
+        query($filter: IssueFilter, $first: Int, $after: String) {
+            issues(first: $first, after: $after, filter: $filter, includeArchived: true, orderBy: updatedAt) {
+                nodes {
+                    id
+                    identifier
+                    title
+                    description
+                    priority
+                    estimate
+                    dueDate
+                    createdAt
+                    updatedAt
+                    startedAt
+                    completedAt
+                    canceledAt
+                    archivedAt
+                    url
+                    state { id name type }
+                    assignee { id name email }
+                    creator { id name email }
+                    team { id key name }
+                    labels { nodes { id name } }
+                    project { id name }
+                    cycle { id number name }
+                    parent { id identifier }
+                }
+                pageInfo {
+                    hasNextPage
+                    endCursor
+                }
+            }
+        }
+    "#;
+
+    let checkpoint = match since_last_run.as_deref() {
+        Some(path) => load_checkpoint(Path::new(path))?,
+        None => None,
+    };
+    if let (Some(path), Some(cp)) = (since_last_run.as_deref(), checkpoint.as_ref()) {
+        check_scope(cp, team.as_deref(), path)?;
+    }
+
+    let mut filter = json!({});
+    if let Some(ref t) = team {
+        filter["team"] = json!({ "key": { "eq": t } });
+    }
+    if let Some(ref cp) = checkpoint {
+        filter["updatedAt"] = json!({ "gt": cp.updated_at });
+    }
+
+    let mut vars = serde_json::Map::new();
+    vars.insert("filter".to_string(), filter);
+
+    let pagination = PaginationOptions {
+        all: true,
+        page_size: Some(250),
+        ..Default::default()
+    };
+
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let writer: Box<dyn Write> = if let Some(ref path) = file {
+        Box::new(std::io::BufWriter::new(std::fs::File::create(path)?))
+    } else {
+        Box::new(std::io::BufWriter::new(std::io::stdout()))
+    };
+    let writer = Rc::new(RefCell::new(writer));
+    let max_updated: Rc<RefCell<Option<String>>> = Rc::new(RefCell::new(None));
+    let tombstones = Rc::new(RefCell::new(0usize));
+
+    let total = stream_nodes(
+        &client,
+        query,
+        vars,
+        &["data", "issues", "nodes"],
+        &["data", "issues", "pageInfo"],
+        &pagination,
+        250,
+        |batch| {
+            let writer = Rc::clone(&writer);
+            let max_updated = Rc::clone(&max_updated);
+            let tombstones = Rc::clone(&tombstones);
+            async move {
+                let mut out = writer.borrow_mut();
+                let mut max = max_updated.borrow_mut();
+                for issue in &batch {
+                    let record = export_record(issue);
+                    if record["op"] == "delete" {
+                        *tombstones.borrow_mut() += 1;
+                    }
+                    writeln!(out, "{}", serde_json::to_string(&record)?)?;
+                    if let Some(updated) = issue["updatedAt"].as_str() {
+                        if is_later(updated, max.as_deref()) {
+                            *max = Some(updated.to_string());
+                        }
+                    }
+                }
+                Ok(())
+            }
+        },
+    )
+    .await?;
+
+    writer.borrow_mut().flush()?;
+
+    let new_max = max_updated.borrow_mut().take();
+    if let (Some(path), Some(updated_at)) = (since_last_run.as_deref(), new_max) {
+        if output.dry_run {
+            eprintln!("Dry run: state file {} not updated", path);
+        } else {
+            save_checkpoint(
+                Path::new(path),
+                &ExportCheckpoint {
+                    team: team.clone(),
+                    updated_at,
+                    exported_at: chrono::Utc::now().to_rfc3339(),
+                },
+            )?;
+        }
+    }
+
+    if file.is_some() || since_last_run.is_some() {
+        let since = checkpoint
+            .as_ref()
+            .map(|cp| format!(" updated since {}", cp.updated_at))
+            .unwrap_or_default();
+        let destination = file
+            .as_deref()
+            .map(|p| format!(" to {}", p))
+            .unwrap_or_default();
+        eprintln!(
+            "Exported {} issues ({} archived){}{}",
+            total,
+            tombstones.borrow(),
+            since,
+            destination
+        );
+    }
+
+    Ok(())
+}
+
+/// Build the NDJSON record for an issue: archived issues become tombstones.
+fn export_record(issue: &Value) -> Value {
+    if issue["archivedAt"].is_string() {
+        json!({
+            "op": "delete",
+            "id": issue["id"],
+            "identifier": issue["identifier"],
+            "updatedAt": issue["updatedAt"],
+            "archivedAt": issue["archivedAt"],
+        })
+    } else {
+        json!({
+            "op": "upsert",
+            "id": issue["id"],
+            "identifier": issue["identifier"],
+            "updatedAt": issue["updatedAt"],
+            "issue": issue,
+        })
+    }
+}
+
+/// A checkpoint only covers the scope it was written for; reusing it for
+/// another team would skip that team's issues updated before the checkpoint.
+fn check_scope(checkpoint: &ExportCheckpoint, team: Option<&str>, path: &str) -> Result<()> {
+    let same = match (checkpoint.team.as_deref(), team) {
+        (Some(a), Some(b)) => a.eq_ignore_ascii_case(b),
+        (a, b) => a == b,
+    };
+    if !same {
+        let scope = |t: Option<&str>| match t {
+            Some(t) => format!("team {}", t),
+            None => "all teams".to_string(),
+        };
+        anyhow::bail!(
+            "State file {} was written for {}, not {}. Use a separate state file per scope, or delete it to re-export everything.",
+            path,
+            scope(checkpoint.team.as_deref()),
+            scope(team)
+        );
+    }
+    Ok(())
+}
+
+fn load_checkpoint(path: &Path) -> Result<Option<ExportCheckpoint>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read state file {}", path.display()))?;
+    let checkpoint = serde_json::from_str(&content)
+        .with_context(|| format!("Invalid state file {}", path.display()))?;
+    Ok(Some(checkpoint))
+}
+
+fn save_checkpoint(path: &Path, checkpoint: &ExportCheckpoint) -> Result<()> {
+    let content = serde_json::to_string_pretty(checkpoint)?;
+    // Write to a temp file then rename so an interrupted run never corrupts the checkpoint
+    let temp_path = path.with_extension("tmp");
+    std::fs::write(&temp_path, content)?;
+    std::fs::rename(&temp_path, path)
+        .with_context(|| format!("Failed to update state file {}", path.display()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_record_upsert() {
+        let issue = json!({
+            "id": "abc",
+            "identifier": "ENG-1",
+            "updatedAt": "2026-10-01T10:00:00.000Z",
+            "archivedAt": null,
+        });
+        let record = export_record(&issue);
+        assert_eq!(record["op"], "upsert");
+        assert_eq!(record["issue"]["identifier"], "ENG-1");
+    }
+
+    #[test]
+    fn test_export_record_tombstone() {
+        let issue = json!({
+            "id": "abc",
+            "identifier": "ENG-1",
+            "updatedAt": "2026-10-01T10:00:00.000Z",
+            "archivedAt": "2026-10-01T10:00:00.000Z",
+        });
+        let record = export_record(&issue);
+        assert_eq!(record["op"], "delete");
+        assert_eq!(record["archivedAt"], "2026-10-01T10:00:00.000Z");
+        assert!(record.get("issue").is_none());
+    }
+
+    #[test]
+    fn test_checkpoint_roundtrip() {
+        let dir = std::env::temp_dir().join(format!("linear-export-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("state.json");
+        assert!(load_checkpoint(&path).unwrap().is_none());
+
+        save_checkpoint(
+            &path,
+            &ExportCheckpoint {
+                team: Some("ENG".to_string()),
+                updated_at: "2026-10-01T10:00:00.000Z".to_string(),
+                exported_at: "2026-10-01T11:00:00+00:00".to_string(),
+            },
+        )
+        .unwrap();
+        let loaded = load_checkpoint(&path).unwrap().unwrap();
+        assert_eq!(loaded.updated_at, "2026-10-01T10:00:00.000Z");
+        assert_eq!(loaded.team.as_deref(), Some("ENG"));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_checkpoint_scope_mismatch() {
+        let cp = ExportCheckpoint {
+            team: Some("ENG".to_string()),
+            updated_at: "2026-10-01T10:00:00.000Z".to_string(),
+            exported_at: "2026-10-01T11:00:00+00:00".to_string(),
+        };
+        assert!(check_scope(&cp, Some("eng"), "state.json").is_ok());
+        assert!(check_scope(&cp, Some("OPS"), "state.json").is_err());
+        assert!(check_scope(&cp, None, "state.json").is_err());
+        let all = ExportCheckpoint { team: None, ..cp };
+        assert!(check_scope(&all, None, "state.json").is_ok());
+        assert!(check_scope(&all, Some("ENG"), "state.json").is_err());
+    }
+}
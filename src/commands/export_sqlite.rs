@@ -0,0 +1,524 @@
+use anyhow::{Context, Result};
+use rusqlite::{params, Connection, OpenFlags, OptionalExtension};
+use serde_json::{json, Value};
+use std::path::Path;
+
+use crate::api::LinearClient;
+use crate::dates::is_later;
+use crate::pagination::{stream_nodes, PaginationOptions};
+
+const SCHEMA: &str = r#"
+    CREATE TABLE IF NOT EXISTS issues (
+        id TEXT PRIMARY KEY,
+        identifier TEXT NOT NULL,
+        number INTEGER,
+        title TEXT,
+        description TEXT,
+        priority INTEGER,
+        estimate REAL,
+        due_date TEXT,
+        created_at TEXT,
+        updated_at TEXT,
+        started_at TEXT,
+        completed_at TEXT,
+        canceled_at TEXT,
+        archived_at TEXT,
+        url TEXT,
+        team_key TEXT,
+        state_id TEXT REFERENCES states(id),
+        assignee_id TEXT REFERENCES users(id),
+        creator_id TEXT REFERENCES users(id),
+        project_id TEXT REFERENCES projects(id),
+        cycle_id TEXT REFERENCES cycles(id),
+        parent_id TEXT
+    );
+    CREATE INDEX IF NOT EXISTS issues_identifier ON issues(identifier);
+    CREATE TABLE IF NOT EXISTS states (
+        id TEXT PRIMARY KEY,
+        name TEXT,
+        type TEXT
+    );
+    CREATE TABLE IF NOT EXISTS users (
+        id TEXT PRIMARY KEY,
+        name TEXT,
+        email TEXT
+    );
+    CREATE TABLE IF NOT EXISTS labels (
+        id TEXT PRIMARY KEY,
+        name TEXT,
+        color TEXT
+    );
+    CREATE TABLE IF NOT EXISTS issue_labels (
+        issue_id TEXT NOT NULL,
+        label_id TEXT NOT NULL,
+        PRIMARY KEY (issue_id, label_id)
+    );
+    CREATE TABLE IF NOT EXISTS cycles (
+        id TEXT PRIMARY KEY,
+        number INTEGER,
+        name TEXT,
+        starts_at TEXT,
+        ends_at TEXT
+    );
+    CREATE TABLE IF NOT EXISTS projects (
+        id TEXT PRIMARY KEY,
+        name TEXT,
+        state TEXT,
+        target_date TEXT
+    );
+    CREATE TABLE IF NOT EXISTS relations (
+        id TEXT PRIMARY KEY,
+        issue_id TEXT NOT NULL,
+        related_issue_id TEXT NOT NULL,
+        type TEXT
+    );
+    CREATE TABLE IF NOT EXISTS history_events (
+        id TEXT PRIMARY KEY,
+        issue_id TEXT NOT NULL,
+        created_at TEXT,
+        actor_id TEXT,
+        from_state_id TEXT,
+        to_state_id TEXT,
+        from_assignee_id TEXT,
+        to_assignee_id TEXT,
+        from_priority INTEGER,
+        to_priority INTEGER,
+        from_estimate REAL,
+        to_estimate REAL
+    );
+    CREATE INDEX IF NOT EXISTS history_events_issue ON history_events(issue_id, created_at);
+    CREATE TABLE IF NOT EXISTS export_meta (
+        scope TEXT PRIMARY KEY,
+        updated_at TEXT NOT NULL,
+        exported_at TEXT NOT NULL
+    );
+"#;
+
+/// Export issues (and the entities they reference) into a normalized SQLite database.
+///
+/// Re-running against the same database only fetches issues updated since the
+/// previous export for the same team scope, unless `full` is set. A dry run
+/// reads the checkpoint but writes into an in-memory copy of the schema.
+pub async fn export_sqlite(
+    database: &str,
+    team: Option<String>,
+    full: bool,
+    dry_run: bool,
+) -> Result<()> {
+    let client = LinearClient::new()?;
+
+    let conn = if dry_run {
+        Connection::open_in_memory()?
+    } else {
+        Connection::open(database)
+            .with_context(|| format!("Failed to open SQLite database {}", database))?
+    };
+    conn.execute_batch(SCHEMA)?;
+
+    let scope = team.clone().unwrap_or_else(|| "*".to_string());
+    let checkpoint: Option<String> = if full {
+        None
+    } else if dry_run {
+        if Path::new(database).exists() {
+            let existing = Connection::open_with_flags(database, OpenFlags::SQLITE_OPEN_READ_ONLY)
+                .with_context(|| format!("Failed to open SQLite database {}", database))?;
+            stored_checkpoint(&existing, &scope)?
+        } else {
+            None
+        }
+    } else {
+        stored_checkpoint(&conn, &scope)?
+    };
+
+    // History is nested per issue, so keep pages small to stay under query complexity limits
+    let query = r#"
+        query($filter: IssueFilter, $first: Int, $after: String) {
+            issues(first: $first, after: $after, filter: $filter, includeArchived: true, orderBy: updatedAt) {
+                nodes {
+                    id
+                    identifier
+                    number
+                    title
+                    description
+                    priority
+                    estimate
+                    dueDate
+                    createdAt
+                    updatedAt
+                    startedAt
+                    completedAt
+                    canceledAt
+                    archivedAt
+                    url
+                    team { key }
+                    state { id name type }
+                    assignee { id name email }
+                    creator { id name email }
+                    project { id name state targetDate }
+                    cycle { id number name startsAt endsAt }
+                    parent { id }
+                    labels { nodes { id name color } }
+                    relations { nodes { id type relatedIssue { id } } }
+                    history(first: 50, orderBy: createdAt) {
+                        nodes {
+                            id
+                            createdAt
+                            actor { id name email }
+                            fromState { id name type }
+                            toState { id name type }
+                            fromAssignee { id name email }
+                            toAssignee { id name email }
+                            fromPriority
+                            toPriority
+                            fromEstimate
+                            toEstimate
+                        }
+                    }
+                }
+                pageInfo {
+                    hasNextPage
+                    endCursor
+                }
+            }
+        }
+    "#;
+
+    let mut filter = json!({});
+    if let Some(ref t) = team {
+        filter["team"] = json!({ "key": { "eq": t } });
+    }
+    if let Some(ref updated_at) = checkpoint {
+        filter["updatedAt"] = json!({ "gt": updated_at });
+    }
+
+    let mut vars = serde_json::Map::new();
+    vars.insert("filter".to_string(), filter);
+
+    let pagination = PaginationOptions {
+        all: true,
+        page_size: Some(50),
+        ..Default::default()
+    };
+
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let conn = Rc::new(RefCell::new(conn));
+    let max_updated: Rc<RefCell<Option<String>>> = Rc::new(RefCell::new(checkpoint.clone()));
+
+    let total = stream_nodes(
+        &client,
+        query,
+        vars,
+        &["data", "issues", "nodes"],
+        &["data", "issues", "pageInfo"],
+        &pagination,
+        50,
+        |batch| {
+            let conn = Rc::clone(&conn);
+            let max_updated = Rc::clone(&max_updated);
+            async move {
+                let mut conn = conn.borrow_mut();
+                let tx = conn.transaction()?;
+                let mut max = max_updated.borrow_mut();
+                for issue in &batch {
+                    upsert_issue(&tx, issue)?;
+                    if let Some(updated) = issue["updatedAt"].as_str() {
+                        if is_later(updated, max.as_deref()) {
+                            *max = Some(updated.to_string());
+                        }
+                    }
+                }
+                tx.commit()?;
+                Ok(())
+            }
+        },
+    )
+    .await?;
+
+    if let Some(updated_at) = max_updated.borrow().as_deref() {
+        conn.borrow().execute(
+            "INSERT OR REPLACE INTO export_meta (scope, updated_at, exported_at) VALUES (?1, ?2, ?3)",
+            params![scope, updated_at, chrono::Utc::now().to_rfc3339()],
+        )?;
+    }
+
+    let since = checkpoint
+        .map(|c| format!(" updated since {}", c))
+        .unwrap_or_default();
+    if dry_run {
+        eprintln!(
+            "Dry run: would export {} issues{} to {} (database not modified)",
+            total, since, database
+        );
+    } else {
+        eprintln!("Exported {} issues{} to {}", total, since, database);
+    }
+
+    Ok(())
+}
+
+fn stored_checkpoint(conn: &Connection, scope: &str) -> Result<Option<String>> {
+    Ok(conn
+        .query_row(
+            "SELECT updated_at FROM export_meta WHERE scope = ?1",
+            params![scope],
+            |row| row.get(0),
+        )
+        .optional()?)
+}
+
+fn upsert_issue(conn: &Connection, issue: &Value) -> Result<()> {
+    let issue_id = str_field(issue, "id").context("Issue without id")?;
+
+    if let Some(state) = object(&issue["state"]) {
+        upsert_state(conn, state)?;
+    }
+    for key in ["assignee", "creator"] {
+        if let Some(user) = object(&issue[key]) {
+            upsert_user(conn, user)?;
+        }
+    }
+    if let Some(project) = object(&issue["project"]) {
+        conn.execute(
+            "INSERT OR REPLACE INTO projects (id, name, state, target_date) VALUES (?1, ?2, ?3, ?4)",
+            params![
+                str_field(project, "id"),
+                str_field(project, "name"),
+                str_field(project, "state"),
+                str_field(project, "targetDate"),
+            ],
+        )?;
+    }
+    if let Some(cycle) = object(&issue["cycle"]) {
+        conn.execute(
+            "INSERT OR REPLACE INTO cycles (id, number, name, starts_at, ends_at) VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                str_field(cycle, "id"),
+                cycle["number"].as_i64(),
+                str_field(cycle, "name"),
+                str_field(cycle, "startsAt"),
+                str_field(cycle, "endsAt"),
+            ],
+        )?;
+    }
+
+    conn.execute(
+        "INSERT OR REPLACE INTO issues (
+            id, identifier, number, title, description, priority, estimate, due_date,
+            created_at, updated_at, started_at, completed_at, canceled_at, archived_at, url,
+            team_key, state_id, assignee_id, creator_id, project_id, cycle_id, parent_id
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22)",
+        params![
+            issue_id,
+            str_field(issue, "identifier"),
+            issue["number"].as_i64(),
+            str_field(issue, "title"),
+            str_field(issue, "description"),
+            issue["priority"].as_i64(),
+            issue["estimate"].as_f64(),
+            str_field(issue, "dueDate"),
+            str_field(issue, "createdAt"),
+            str_field(issue, "updatedAt"),
+            str_field(issue, "startedAt"),
+            str_field(issue, "completedAt"),
+            str_field(issue, "canceledAt"),
+            str_field(issue, "archivedAt"),
+            str_field(issue, "url"),
+            issue["team"]["key"].as_str(),
+            issue["state"]["id"].as_str(),
+            issue["assignee"]["id"].as_str(),
+            issue["creator"]["id"].as_str(),
+            issue["project"]["id"].as_str(),
+            issue["cycle"]["id"].as_str(),
+            issue["parent"]["id"].as_str(),
+        ],
+    )?;
+
+    // Labels and relations are replaced wholesale so removals propagate
+    conn.execute(
+        "DELETE FROM issue_labels WHERE issue_id = ?1",
+        params![issue_id],
+    )?;
+    for label in nodes(&issue["labels"]) {
+        conn.execute(
+            "INSERT OR REPLACE INTO labels (id, name, color) VALUES (?1, ?2, ?3)",
+            params![
+                str_field(label, "id"),
+                str_field(label, "name"),
+                str_field(label, "color"),
+            ],
+        )?;
+        conn.execute(
+            "INSERT OR IGNORE INTO issue_labels (issue_id, label_id) VALUES (?1, ?2)",
+            params![issue_id, str_field(label, "id")],
+        )?;
+    }
+
+    conn.execute(
+        "DELETE FROM relations WHERE issue_id = ?1",
+        params![issue_id],
+    )?;
+    for relation in nodes(&issue["relations"]) {
+        let Some(related) = relation["relatedIssue"]["id"].as_str() else {
+            continue;
+        };
+        conn.execute(
+            "INSERT OR REPLACE INTO relations (id, issue_id, related_issue_id, type) VALUES (?1, ?2, ?3, ?4)",
+            params![
+                str_field(relation, "id"),
+                issue_id,
+                related,
+                str_field(relation, "type"),
+            ],
+        )?;
+    }
+
+    for event in nodes(&issue["history"]) {
+        for key in ["fromState", "toState"] {
+            if let Some(state) = object(&event[key]) {
+                upsert_state(conn, state)?;
+            }
+        }
+        for key in ["actor", "fromAssignee", "toAssignee"] {
+            if let Some(user) = object(&event[key]) {
+                upsert_user(conn, user)?;
+            }
+        }
+        conn.execute(
+            "INSERT OR REPLACE INTO history_events (
+                id, issue_id, created_at, actor_id, from_state_id, to_state_id,
+                from_assignee_id, to_assignee_id, from_priority, to_priority, from_estimate, to_estimate
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+            params![
+                str_field(event, "id"),
+                issue_id,
+                str_field(event, "createdAt"),
+                event["actor"]["id"].as_str(),
+                event["fromState"]["id"].as_str(),
+                event["toState"]["id"].as_str(),
+                event["fromAssignee"]["id"].as_str(),
+                event["toAssignee"]["id"].as_str(),
+                event["fromPriority"].as_i64(),
+                event["toPriority"].as_i64(),
+                event["fromEstimate"].as_f64(),
+                event["toEstimate"].as_f64(),
+            ],
+        )?;
+    }
+
+    Ok(())
+}
+
+fn upsert_state(conn: &Connection, state: &Value) -> Result<()> {
+    conn.execute(
+        "INSERT OR REPLACE INTO states (id, name, type) VALUES (?1, ?2, ?3)",
+        params![
+            str_field(state, "id"),
+            str_field(state, "name"),
+            str_field(state, "type"),
+        ],
+    )?;
+    Ok(())
+}
+
+fn upsert_user(conn: &Connection, user: &Value) -> Result<()> {
+    conn.execute(
+        "INSERT OR REPLACE INTO users (id, name, email) VALUES (?1, ?2, ?3)",
+        params![
+            str_field(user, "id"),
+            str_field(user, "name"),
+            str_field(user, "email"),
+        ],
+    )?;
+    Ok(())
+}
+
+fn str_field<'a>(value: &'a Value, key: &str) -> Option<&'a str> {
+    value[key].as_str()
+}
+
+/// Return the value if it is an object with an id (skips null relations).
+fn object(value: &Value) -> Option<&Value> {
+    value["id"].as_str().map(|_| value)
+}
+
+fn nodes(value: &Value) -> impl Iterator<Item = &Value> {
+    value["nodes"].as_array().into_iter().flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_issue(labels: Value) -> Value {
+        json!({
+            "id": "issue-1",
+            "identifier": "ENG-1",
+            "number": 1,
+            "title": "Fix login",
+            "priority": 2,
+            "estimate": 3.0,
+            "updatedAt": "2026-10-01T10:00:00.000Z",
+            "team": { "key": "ENG" },
+            "state": { "id": "state-1", "name": "In Progress", "type": "started" },
+            "assignee": { "id": "user-1", "name": "Alex", "email": "alex@example.com" },
+            "creator": null,
+            "project": null,
+            "cycle": { "id": "cycle-1", "number": 7, "name": null },
+            "parent": null,
+            "labels": { "nodes": labels },
+            "relations": { "nodes": [
+                { "id": "rel-1", "type": "blocks", "relatedIssue": { "id": "issue-2" } }
+            ] },
+            "history": { "nodes": [
+                {
+                    "id": "hist-1",
+                    "createdAt": "2026-10-01T09:00:00.000Z",
+                    "actor": { "id": "user-1", "name": "Alex", "email": "alex@example.com" },
+                    "fromState": { "id": "state-0", "name": "Todo", "type": "unstarted" },
+                    "toState": { "id": "state-1", "name": "In Progress", "type": "started" }
+                }
+            ] }
+        })
+    }
+
+    fn count(conn: &Connection, table: &str) -> i64 {
+        conn.query_row(&format!("SELECT COUNT(*) FROM {}", table), [], |r| r.get(0))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_upsert_issue_normalizes_entities() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(SCHEMA).unwrap();
+        let issue = sample_issue(json!([{ "id": "label-1", "name": "bug", "color": "#f00" }]));
+        upsert_issue(&conn, &issue).unwrap();
+
+        assert_eq!(count(&conn, "issues"), 1);
+        assert_eq!(count(&conn, "states"), 2);
+        assert_eq!(count(&conn, "users"), 1);
+        assert_eq!(count(&conn, "labels"), 1);
+        assert_eq!(count(&conn, "issue_labels"), 1);
+        assert_eq!(count(&conn, "cycles"), 1);
+        assert_eq!(count(&conn, "projects"), 0);
+        assert_eq!(count(&conn, "relations"), 1);
+        assert_eq!(count(&conn, "history_events"), 1);
+    }
+
+    #[test]
+    fn test_upsert_issue_replaces_label_links() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(SCHEMA).unwrap();
+        upsert_issue(
+            &conn,
+            &sample_issue(json!([{ "id": "label-1", "name": "bug", "color": "#f00" }])),
+        )
+        .unwrap();
+        upsert_issue(&conn, &sample_issue(json!([]))).unwrap();
+
+        assert_eq!(count(&conn, "issues"), 1);
+        assert_eq!(count(&conn, "issue_labels"), 0);
+        assert_eq!(count(&conn, "history_events"), 1);
+    }
+}
@@ -7,8 +7,10 @@ use std::process::Command;
 
 use crate::api::LinearClient;
 use crate::display_options;
+use crate::output::OutputOptions;
+use crate::pagination::{paginate_nodes, PaginationOptions};
 use crate::text::truncate;
-use crate::vcs::{generate_branch_name, run_git_command};
+use crate::vcs::{generate_branch_name, issue_branch_name, run_git_command, BranchInfo};
 
 /// Version control system type
 #[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
@@ -34,7 +36,17 @@ pub enum GitCommands {
     #[command(after_help = r#"EXAMPLES:
     linear git checkout LIN-123                # Checkout issue branch
     linear g checkout LIN-123 -b feature/fix   # Use custom branch name
-    linear g checkout LIN-123 --vcs jj         # Use Jujutsu VCS"#)]
+    linear g checkout LIN-123 --vcs jj         # Use Jujutsu VCS
+
+BRANCH NAMES:
+    Linear's suggested branch name is used unless [git.branch] is set in
+    config.toml (or branch_template in .linear.toml):
+
+    [git.branch]
+    template = "{{user}}/{{identifier}}-{{slug}}"  # also {{IDENTIFIER}}, {{prefix}}
+    slug_length = 30
+    case = "lower"                                  # or "preserve"
+    label_prefixes = { bug = "fix/", feature = "feat/" }"#)]
     Checkout {
         /// Issue identifier (e.g., "LIN-123") or ID
         issue: String,
@@ -82,24 +94,188 @@ pub enum GitCommands {
         #[arg(long, value_enum)]
         vcs: Option<Vcs>,
     },
-    /// Create a GitHub PR from a Linear issue
+    /// Create a PR (GitHub, GitLab, Gitea or Forgejo) from a Linear issue
     #[command(after_help = r#"EXAMPLES:
     linear git pr LIN-123                      # Create PR for issue
     linear g pr LIN-123 --draft                # Create draft PR
     linear g pr LIN-123 -B develop             # Merge into develop
-    linear g pr LIN-123 --web                  # Open PR in browser"#)]
+    linear g pr LIN-123 --web                  # Open PR in browser
+    linear g pr LIN-123 --body-template @.github/linear_pr.md
+    linear g pr LIN-123 --forge gitlab         # Use glab for self-hosted GitLab
+
+TEMPLATES:
+    Placeholders: {{identifier}} {{title}} {{url}} {{description}}
+    {{acceptance_criteria}} {{sub_issues}} {{state}} {{team}} {{project}}
+    {{assignee}} {{labels}} {{priority}} {{branch}} {{base}}
+    Defaults can be set in config.toml under [git] as pr_title_template
+    and pr_body_template.
+
+FORGES:
+    Detected from the remote URL: gh (GitHub), glab (GitLab), tea (Gitea),
+    fj (Forgejo/Codeberg); other hosts use gh. Map self-hosted hosts
+    under [git.forges]."#)]
     Pr {
         /// Issue identifier (e.g., "LIN-123") or ID
         issue: String,
-        /// Base branch to merge into (default: main)
-        #[arg(short = 'B', long, default_value = "main")]
-        base: String,
+        /// Base branch to merge into (default: the remote's default branch)
+        #[arg(short = 'B', long)]
+        base: Option<String>,
         /// Create a draft PR
         #[arg(short, long)]
         draft: bool,
         /// Open the PR in the browser after creation
         #[arg(short, long)]
         web: bool,
+        /// Forge CLI to use (default: detected from the remote URL)
+        #[arg(long, value_enum)]
+        forge: Option<super::git_pr::Forge>,
+        /// Remote used for forge and default-branch detection
+        #[arg(long, default_value = "origin")]
+        remote: String,
+        /// PR title template (inline, or @file)
+        #[arg(long)]
+        title_template: Option<String>,
+        /// PR body template (inline, or @file)
+        #[arg(long)]
+        body_template: Option<String>,
+    },
+    /// Manage git hooks that reference Linear issues in commits
+    #[command(after_help = r#"EXAMPLES:
+    linear git hooks install                   # Install commit hooks
+    linear g hooks install --require-reference # Enforce issue references
+    linear g hooks uninstall                   # Remove them"#)]
+    Hooks {
+        #[command(subcommand)]
+        action: super::git_hooks::HookCommands,
+    },
+    /// Create stacked branches for a parent issue's sub-issues
+    #[command(args_conflicts_with_subcommands = true)]
+    #[command(after_help = r#"EXAMPLES:
+    linear git stack LIN-101                   # parent <- child1 <- child2 ...
+    linear g stack LIN-101 --flat              # every child on the parent branch
+    linear g stack LIN-101 --base develop      # start the stack from develop
+    linear g stack status                      # ahead/behind + issue state
+    linear g stack forget LIN-101              # stop tracking (keeps branches)
+
+Stacks are recorded in .git/linear-stacks.json."#)]
+    Stack {
+        #[command(subcommand)]
+        action: Option<super::git_stack::StackCommands>,
+        /// Parent issue identifier (e.g., "LIN-101")
+        issue: Option<String>,
+        /// Branch to start the stack from (default: current branch)
+        #[arg(short = 'B', long)]
+        base: Option<String>,
+        /// Branch every sub-issue from the parent branch instead of chaining
+        #[arg(long)]
+        flat: bool,
+    },
+    /// Check out an issue branch in its own worktree (or jj workspace)
+    #[command(args_conflicts_with_subcommands = true)]
+    #[command(after_help = r#"EXAMPLES:
+    linear git worktree LIN-123                # ../<repo>-worktrees/lin-123-title
+    linear g worktree LIN-123 -B origin/main   # New branch from origin/main
+    linear g worktree LIN-123 --dir ~/wt       # Somewhere else
+    linear g worktree list                     # Worktrees with issue state
+    linear g worktree prune                    # Remove finished issues' worktrees
+
+The directory defaults to ../{{repo}}-worktrees; set it in config.toml:
+
+    [git]
+    worktree_dir = "~/worktrees/{{repo}}""#)]
+    Worktree {
+        #[command(subcommand)]
+        action: Option<super::git_worktree::WorktreeCommands>,
+        /// Issue identifier (e.g., "LIN-123") or ID
+        issue: Option<String>,
+        /// Custom branch name (optional, uses issue's branch name by default)
+        #[arg(short, long)]
+        branch: Option<String>,
+        /// Directory to create worktrees in (overrides git.worktree_dir)
+        #[arg(long)]
+        dir: Option<std::path::PathBuf>,
+        /// Start point for a new branch (default: HEAD)
+        #[arg(short = 'B', long)]
+        base: Option<String>,
+        /// Version control system to use (auto-detected by default)
+        #[arg(long, value_enum)]
+        vcs: Option<Vcs>,
+    },
+    /// Suggest an assignee for paths from CODEOWNERS
+    #[command(after_help = r#"EXAMPLES:
+    linear git suggest-assignee src/payments/stripe.rs
+    linear g suggest-assignee 'src/foo/**' --output json
+    git diff --name-only main | linear g suggest-assignee -
+
+CODEOWNERS owners map to Linear users by email, by @handle as a name, or
+explicitly in config.toml (or .linear.toml):
+
+    [git.owners]
+    "@alice" = "alice@example.com"
+    "@acme/payments" = "bob@example.com""#)]
+    SuggestAssignee {
+        /// File paths or globs (relative to the current directory); "-" reads stdin
+        #[arg(required = true)]
+        paths: Vec<String>,
+    },
+    /// Move issues referenced by magic words ("fixes LIN-123") in a commit range
+    #[command(after_help = r#"EXAMPLES:
+    linear git sync-states --range origin/main@{1}..origin/main
+    linear g sync-states --range $OLDREV..$NEWREV --dry-run
+    linear g sync-states --range v1.2.0..HEAD --map fixes="Ready for QA"
+
+Built-in words: fix(es|ed), close(s|d), resolve(s|d), complete(s) -> first
+"completed" state; ref(s), references -> first "started" state. Targets may
+be state names or state types. Override them in config.toml:
+
+    [git.magic_words]
+    fixes = "Done"
+    refs = "In Progress"
+
+    [git.team_magic_words.ENG]
+    fixes = "Deployed"
+
+Issues are never moved backwards, and issues already in the target state
+are left alone, so re-running a range is safe."#)]
+    SyncStates {
+        /// Commit range to scan (A..B)
+        #[arg(long)]
+        range: String,
+        /// Override a mapping, e.g. fixes=Done (repeatable)
+        #[arg(long, value_name = "WORD=STATE")]
+        map: Vec<String>,
+        /// Include merge commits
+        #[arg(long)]
+        merges: bool,
+    },
+    /// Build release notes from the Linear issues referenced in a commit range
+    #[command(after_help = r#"EXAMPLES:
+    linear git changelog v1.2.0..HEAD          # Markdown grouped by label
+    linear g changelog v1.2.0 --group-by project
+    linear g changelog v1.2.0..v1.3.0 --output json
+    linear g changelog v1.2.0..HEAD --version v1.3.0 --mark-released Released --comment
+    linear g changelog v1.2.0..HEAD -f CHANGELOG-1.3.0.md"#)]
+    Changelog {
+        /// Commit range (A..B), or a single ref meaning REF..HEAD
+        range: String,
+        /// Group issues by: label, project, or none
+        #[arg(short, long, default_value = "label")]
+        group_by: String,
+        /// Version for the heading and comments (default: end of the range, or git describe)
+        #[arg(long)]
+        version: Option<String>,
+        /// Move referenced issues to this workflow state
+        #[arg(long, value_name = "STATE")]
+        mark_released: Option<String>,
+        /// Comment "Released in VERSION" on each referenced issue (skipped if already there)
+        #[arg(long)]
+        comment: bool,
+        /// Include merge commits
+        #[arg(long)]
+        merges: bool,
+        /// Write the notes to a file instead of stdout
+        #[arg(short, long)]
+        file: Option<String>,
     },
 }
 
@@ -133,14 +309,14 @@ fn detect_vcs() -> Result<Vcs> {
 }
 
 /// Get the VCS to use, either from the flag or auto-detected
-fn get_vcs(vcs_flag: Option<Vcs>) -> Result<Vcs> {
+pub(super) fn get_vcs(vcs_flag: Option<Vcs>) -> Result<Vcs> {
     match vcs_flag {
         Some(vcs) => Ok(vcs),
         None => detect_vcs(),
     }
 }
 
-pub async fn handle(cmd: GitCommands) -> Result<()> {
+pub async fn handle(cmd: GitCommands, output: &OutputOptions) -> Result<()> {
     match cmd {
         GitCommands::Checkout { issue, branch, vcs } => {
             let vcs = get_vcs(vcs)?;
@@ -163,11 +339,80 @@ pub async fn handle(cmd: GitCommands) -> Result<()> {
             base,
             draft,
             web,
-        } => create_pr(&issue, &base, draft, web).await,
+            forge,
+            remote,
+            title_template,
+            body_template,
+        } => {
+            super::git_pr::create_pr(
+                &issue,
+                base,
+                draft,
+                web,
+                forge,
+                &remote,
+                title_template,
+                body_template,
+                output,
+            )
+            .await
+        }
+        GitCommands::Hooks { action } => super::git_hooks::handle(action).await,
+        GitCommands::Stack {
+            action,
+            issue,
+            base,
+            flat,
+        } => match (action, issue) {
+            (Some(action), _) => super::git_stack::handle(action, output).await,
+            (None, Some(issue)) => super::git_stack::create_stack(&issue, base, flat, output).await,
+            (None, None) => anyhow::bail!("Specify a parent issue or a subcommand. See: linear git stack --help"),
+        },
+        GitCommands::SuggestAssignee { paths } => {
+            super::git_owners::suggest_assignee(paths, output).await
+        }
+        GitCommands::Worktree {
+            action,
+            issue,
+            branch,
+            dir,
+            base,
+            vcs,
+        } => match (action, issue) {
+            (Some(action), _) => super::git_worktree::handle(action, output).await,
+            (None, Some(issue)) => {
+                super::git_worktree::create_worktree(&issue, branch, dir, base, vcs, output).await
+            }
+            (None, None) => anyhow::bail!("Specify an issue or a subcommand. See: linear git worktree --help"),
+        },
+        GitCommands::SyncStates { range, map, merges } => {
+            super::git_sync_states::sync_states(&range, map, merges, output).await
+        }
+        GitCommands::Changelog {
+            range,
+            group_by,
+            version,
+            mark_released,
+            comment,
+            merges,
+            file,
+        } => {
+            super::git_changelog::changelog(
+                &range,
+                &group_by,
+                version,
+                mark_released,
+                comment,
+                merges,
+                file,
+                output,
+            )
+            .await
+        }
     }
 }
 
-async fn get_issue_info(issue_id: &str) -> Result<(String, String, String, String)> {
+pub(super) async fn get_issue_info(issue_id: &str) -> Result<(String, String, String, String)> {
     let client = LinearClient::new()?;
 
     let query = r#"
@@ -197,8 +442,83 @@ async fn get_issue_info(issue_id: &str) -> Result<(String, String, String, Strin
     Ok((identifier, title, branch_name, url))
 }
 
+/// An issue plus the branch it should live on.
+pub(super) struct IssueBranch {
+    pub identifier: String,
+    pub title: String,
+    pub url: String,
+    /// Linear's suggested branch name (may be empty)
+    pub linear_branch: String,
+    /// Branch from `[git.branch]`, or Linear's, or the generated default
+    pub branch: String,
+    /// Whether a `[git.branch]` template/rule produced `branch`
+    pub templated: bool,
+}
+
+/// Fetch an issue and name its branch according to `[git.branch]`.
+pub(super) async fn get_issue_branch(issue_id: &str) -> Result<IssueBranch> {
+    let config = crate::config::git_config()?.branch;
+    let client = LinearClient::new()?;
+
+    let needs_user = config
+        .template
+        .as_deref()
+        .is_some_and(|t| t.contains("{{user}}"));
+    let query = format!(
+        r#"
+        query($id: String!) {{
+            issue(id: $id) {{
+                identifier
+                title
+                branchName
+                url
+                labels {{ nodes {{ name }} }}
+            }}
+            {}
+        }}
+    "#,
+        if needs_user { "viewer { displayName }" } else { "" }
+    );
+
+    let result = client.query(&query, Some(json!({ "id": issue_id }))).await?;
+    let issue = &result["data"]["issue"];
+    if issue.is_null() {
+        anyhow::bail!("Issue not found: {}", issue_id);
+    }
+
+    let labels: Vec<String> = issue["labels"]["nodes"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|l| l["name"].as_str().map(String::from))
+        .collect();
+    let str_of = |v: &serde_json::Value| v.as_str().unwrap_or("").to_string();
+    let identifier = str_of(&issue["identifier"]);
+    let title = str_of(&issue["title"]);
+    let linear_branch = str_of(&issue["branchName"]);
+    let branch = issue_branch_name(
+        &config,
+        &BranchInfo {
+            identifier: &identifier,
+            title: &title,
+            linear_branch: &linear_branch,
+            user: result["data"]["viewer"]["displayName"].as_str(),
+            labels: &labels,
+        },
+    );
+
+    Ok(IssueBranch {
+        url: str_of(&issue["url"]),
+        identifier,
+        title,
+        linear_branch,
+        branch,
+        templated: !config.is_empty(),
+    })
+}
+
 /// Extract Linear issue ID from commit message
-fn extract_linear_issue(message: &str) -> Option<String> {
+pub(super) fn extract_linear_issue(message: &str) -> Option<String> {
     // Try Linear-Issue: trailer first
     if let Some(line) = message.lines().find(|l| l.starts_with("Linear-Issue:")) {
         return line
@@ -222,10 +542,79 @@ fn extract_linear_issue(message: &str) -> Option<String> {
         }
     }
 
+    // Try "XXX-123: subject" prefix (as written by the commit hooks)
+    let re_prefix = regex::Regex::new(r"^([A-Z]+-\d+):\s").ok()?;
+    if let Some(caps) = re_prefix.captures(message) {
+        return caps.get(1).map(|m| m.as_str().to_string());
+    }
+
     None
 }
 
-fn run_jj_command(args: &[&str]) -> Result<String> {
+/// Batch-fetch issues by identifier (e.g. "LIN-123"), grouped into one
+/// query per page using team key + number filters. Unknown IDs are omitted.
+pub(super) async fn fetch_issues_by_identifier(
+    client: &LinearClient,
+    identifiers: &[String],
+) -> Result<Vec<serde_json::Value>> {
+    let mut by_team: std::collections::BTreeMap<String, Vec<i64>> =
+        std::collections::BTreeMap::new();
+    for id in identifiers {
+        if let Some((key, number)) = id.rsplit_once('-') {
+            if let Ok(n) = number.parse::<i64>() {
+                by_team.entry(key.to_uppercase()).or_default().push(n);
+            }
+        }
+    }
+    if by_team.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let clauses: Vec<serde_json::Value> = by_team
+        .iter()
+        .map(|(key, numbers)| {
+            json!({
+                "team": { "key": { "eq": key } },
+                "number": { "in": numbers },
+            })
+        })
+        .collect();
+
+    let query = r#"
+        query($filter: IssueFilter, $first: Int, $after: String, $last: Int, $before: String) {
+            issues(first: $first, after: $after, last: $last, before: $before, filter: $filter, includeArchived: true) {
+                nodes {
+                    id identifier title url priority
+                    state { id name type }
+                    team { id key }
+                    project { id name }
+                    labels { nodes { name } }
+                }
+                pageInfo { hasNextPage endCursor hasPreviousPage startCursor }
+            }
+        }
+    "#;
+
+    let mut vars = serde_json::Map::new();
+    vars.insert("filter".to_string(), json!({ "or": clauses }));
+
+    let pagination = PaginationOptions {
+        all: true,
+        ..Default::default()
+    };
+    paginate_nodes(
+        client,
+        query,
+        vars,
+        &["data", "issues", "nodes"],
+        &["data", "issues", "pageInfo"],
+        &pagination,
+        100,
+    )
+    .await
+}
+
+pub(super) fn run_jj_command(args: &[&str]) -> Result<String> {
     let output = Command::new("jj").args(args).output()?;
 
     if output.status.success() {
@@ -258,7 +647,7 @@ fn get_current_branch(vcs: Vcs) -> Result<String> {
 }
 
 /// Generate the commit description with Linear issue trailer
-fn generate_jj_description(identifier: &str, title: &str, url: &str) -> String {
+pub(super) fn generate_jj_description(identifier: &str, title: &str, url: &str) -> String {
     format!(
         "{}: {}\n\nLinear-Issue: {}\nLinear-URL: {}",
         identifier, title, identifier, url
@@ -266,16 +655,16 @@ fn generate_jj_description(identifier: &str, title: &str, url: &str) -> String {
 }
 
 async fn checkout_issue(issue_id: &str, custom_branch: Option<String>, vcs: Vcs) -> Result<()> {
-    let (identifier, title, linear_branch, url) = get_issue_info(issue_id).await?;
+    let IssueBranch {
+        identifier,
+        title,
+        url,
+        branch,
+        ..
+    } = get_issue_branch(issue_id).await?;
     let title_width = display_options().max_width(50);
 
-    let branch_name = custom_branch
-        .or(if linear_branch.is_empty() {
-            None
-        } else {
-            Some(linear_branch)
-        })
-        .unwrap_or_else(|| generate_branch_name(&identifier, &title));
+    let branch_name = custom_branch.unwrap_or(branch);
 
     println!(
         "{} {} {}",
@@ -326,7 +715,14 @@ async fn checkout_issue(issue_id: &str, custom_branch: Option<String>, vcs: Vcs)
 }
 
 async fn show_branch(issue_id: &str, vcs: Vcs) -> Result<()> {
-    let (identifier, title, linear_branch, url) = get_issue_info(issue_id).await?;
+    let IssueBranch {
+        identifier,
+        title,
+        url,
+        linear_branch,
+        branch,
+        templated,
+    } = get_issue_branch(issue_id).await?;
     let title_width = display_options().max_width(50);
 
     println!(
@@ -342,13 +738,19 @@ async fn show_branch(issue_id: &str, vcs: Vcs) -> Result<()> {
     }
 
     let generated = generate_branch_name(&identifier, &title);
-    println!("Generated:     {}", generated.yellow());
+    if templated {
+        println!("Template:      {}", branch.green().bold());
+    } else {
+        println!("Generated:     {}", generated.yellow());
+    }
     println!("Issue URL:     {}", url.blue());
 
     match vcs {
         Vcs::Git => {
             // Check if either branch exists locally
-            if branch_exists(&linear_branch, vcs) {
+            if templated && branch_exists(&branch, vcs) {
+                println!("\n{} Template branch exists locally", "+".green());
+            } else if branch_exists(&linear_branch, vcs) {
                 println!("\n{} Linear branch exists locally", "+".green());
             } else if branch_exists(&generated, vcs) {
                 println!("\n{} Generated branch exists locally", "+".green());
@@ -358,7 +760,9 @@ async fn show_branch(issue_id: &str, vcs: Vcs) -> Result<()> {
         }
         Vcs::Jj => {
             // Check if bookmark exists
-            if branch_exists(&linear_branch, vcs) {
+            if templated && branch_exists(&branch, vcs) {
+                println!("\n{} Template bookmark exists", "+".green());
+            } else if branch_exists(&linear_branch, vcs) {
                 println!("\n{} Linear bookmark exists", "+".green());
             } else if branch_exists(&generated, vcs) {
                 println!("\n{} Generated bookmark exists", "+".green());
@@ -372,16 +776,16 @@ async fn show_branch(issue_id: &str, vcs: Vcs) -> Result<()> {
 }
 
 async fn create_branch(issue_id: &str, custom_branch: Option<String>, vcs: Vcs) -> Result<()> {
-    let (identifier, title, linear_branch, url) = get_issue_info(issue_id).await?;
+    let IssueBranch {
+        identifier,
+        title,
+        url,
+        branch,
+        ..
+    } = get_issue_branch(issue_id).await?;
     let title_width = display_options().max_width(50);
 
-    let branch_name = custom_branch
-        .or(if linear_branch.is_empty() {
-            None
-        } else {
-            Some(linear_branch)
-        })
-        .unwrap_or_else(|| generate_branch_name(&identifier, &title));
+    let branch_name = custom_branch.unwrap_or(branch);
 
     println!(
         "{} {} {}",
@@ -543,60 +947,25 @@ async fn show_commits(limit: usize, vcs: Vcs) -> Result<()> {
     }
 }
 
-fn run_gh_command(args: &[&str]) -> Result<String> {
-    let output = Command::new("gh").args(args).output()?;
-
-    if output.status.success() {
-        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
-    } else {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        anyhow::bail!("gh command failed: {}", stderr.trim());
-    }
-}
-
-async fn create_pr(issue_id: &str, base: &str, draft: bool, web: bool) -> Result<()> {
-    let (identifier, title, _branch_name, url) = get_issue_info(issue_id).await?;
-    let title_width = display_options().max_width(60);
-
-    let pr_title = format!("[{}] {}", identifier, title);
-    let pr_body = format!("Linear: {}", url);
-
-    println!(
-        "{} {}",
-        identifier.cyan(),
-        truncate(&title, title_width).dimmed()
-    );
-    println!(
-        "Creating PR with title: {}",
-        truncate(&pr_title, title_width).green()
-    );
-
-    let mut args = vec![
-        "pr", "create", "--title", &pr_title, "--body", &pr_body, "--base", base,
-    ];
-
-    if draft {
-        args.push("--draft");
-    }
-
-    if web {
-        args.push("--web");
-    }
-
-    let result = run_gh_command(&args)?;
-
-    if !result.is_empty() {
-        println!("{} PR created: {}", "+".green(), result);
-    } else {
-        println!("{} PR created successfully!", "+".green());
-    }
-
-    Ok(())
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::BranchConfig;
+
+    #[test]
+    fn test_extract_linear_issue_forms() {
+        assert_eq!(
+            extract_linear_issue("Fix it\n\nLinear-Issue: LIN-1\n").as_deref(),
+            Some("LIN-1")
+        );
+        assert_eq!(extract_linear_issue("[ENG-42] Add thing").as_deref(), Some("ENG-42"));
+        assert_eq!(
+            extract_linear_issue("See https://linear.app/acme/issue/OPS-7/x").as_deref(),
+            Some("OPS-7")
+        );
+        assert_eq!(extract_linear_issue("LIN-9: Fix login").as_deref(), Some("LIN-9"));
+        assert_eq!(extract_linear_issue("Bump to UTF-8 handling"), None);
+    }
 
     #[test]
     fn test_generate_branch_name_simple() {
@@ -646,4 +1015,82 @@ mod tests {
             "dev-1/fix-bug"
         );
     }
+
+    fn branch_info<'a>(labels: &'a [String], user: Option<&'a str>) -> BranchInfo<'a> {
+        BranchInfo {
+            identifier: "ENG-42",
+            title: "Fix Login Timeout on Safari",
+            linear_branch: "jane/eng-42-fix-login-timeout-on-safari",
+            user,
+            labels,
+        }
+    }
+
+    #[test]
+    fn test_issue_branch_name_defaults_to_linear() {
+        let config = BranchConfig::default();
+        assert_eq!(
+            issue_branch_name(&config, &branch_info(&[], None)),
+            "jane/eng-42-fix-login-timeout-on-safari"
+        );
+    }
+
+    #[test]
+    fn test_issue_branch_name_templates() {
+        let mut config = BranchConfig {
+            template: Some("{{user}}/{{identifier}}-{{slug}}".to_string()),
+            slug_length: Some(10),
+            ..Default::default()
+        };
+        assert_eq!(
+            issue_branch_name(&config, &branch_info(&[], Some("Jane Doe"))),
+            "jane-doe/eng-42-fix-login"
+        );
+        // Missing user collapses the empty segment
+        assert_eq!(
+            issue_branch_name(&config, &branch_info(&[], None)),
+            "eng-42-fix-login"
+        );
+
+        config.template = Some("feature/{{IDENTIFIER}}".to_string());
+        assert_eq!(
+            issue_branch_name(&config, &branch_info(&[], None)),
+            "feature/ENG-42"
+        );
+
+        config.template = Some("{{identifier}}/{{slug}}".to_string());
+        config.case = Some("preserve".to_string());
+        config.slug_length = None;
+        config.label_prefixes.insert("bug".into(), "fix/".into());
+        let labels = vec!["Bug".to_string()];
+        assert_eq!(
+            issue_branch_name(&config, &branch_info(&labels, None)),
+            "fix/eng-42/Fix-Login-Timeout-on-Safari"
+        );
+    }
+
+    #[test]
+    fn test_issue_id_from_branch_with_template() {
+        let template = Some("{{slug}}-{{identifier}}");
+        // The generic pattern would pick up "utf-8"
+        assert_eq!(
+            crate::vcs::issue_id_from_branch("fix-utf-8-names-eng-12").as_deref(),
+            Some("UTF-8")
+        );
+        assert_eq!(
+            crate::vcs::issue_id_from_branch_with("fix-utf-8-names-eng-12", template).as_deref(),
+            Some("ENG-12")
+        );
+        let user_template = Some("{{user}}/{{identifier}}-{{slug}}");
+        assert_eq!(
+            crate::vcs::issue_id_from_branch_with("fix/dev-2/eng-7-thing", user_template)
+                .as_deref(),
+            Some("ENG-7")
+        );
+        // Branches that don't follow the template still work
+        assert_eq!(
+            crate::vcs::issue_id_from_branch_with("lin-5/old-style", template).as_deref(),
+            Some("LIN-5")
+        );
+    }
 }
@@ -0,0 +1,525 @@
+use anyhow::Result;
+use colored::Colorize;
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap};
+
+use crate::api::{resolve_state_id, LinearClient};
+use crate::output::{print_json_owned, OutputOptions};
+use crate::vcs::run_git_command;
+
+use super::git::{extract_linear_issue, fetch_issues_by_identifier};
+
+/// Label names that map to well-known release note headings, in display order.
+const LABEL_HEADINGS: [(&str, &str); 6] = [
+    ("feature", "Features"),
+    ("improvement", "Improvements"),
+    ("enhancement", "Improvements"),
+    ("bug", "Bug Fixes"),
+    ("performance", "Performance"),
+    ("documentation", "Documentation"),
+];
+
+const OTHER_HEADING: &str = "Other";
+
+struct Commit {
+    sha: String,
+    subject: String,
+    issue: Option<String>,
+}
+
+/// Parse `git log --format=%H%x1f%s%x1f%B%x1e` output.
+fn parse_log(log: &str) -> Vec<Commit> {
+    log.split('\x1e')
+        .filter_map(|record| {
+            let mut parts = record.trim_start_matches('\n').splitn(3, '\x1f');
+            let sha = parts.next()?.trim();
+            if sha.is_empty() {
+                return None;
+            }
+            let subject = parts.next().unwrap_or("").to_string();
+            let body = parts.next().unwrap_or("");
+            Some(Commit {
+                sha: sha.to_string(),
+                issue: extract_linear_issue(body).map(|id| id.to_uppercase()),
+                subject,
+            })
+        })
+        .collect()
+}
+
+/// Expand "A" into "A..HEAD"; returns (range, end ref).
+fn normalize_range(range: &str) -> (String, String) {
+    match range.split_once("..") {
+        Some((_, end)) => {
+            let end = end.trim_start_matches('.');
+            let end = if end.is_empty() { "HEAD" } else { end };
+            (range.to_string(), end.to_string())
+        }
+        None => (format!("{}..HEAD", range), "HEAD".to_string()),
+    }
+}
+
+fn label_heading(issue: &Value) -> String {
+    let labels: Vec<String> = issue["labels"]["nodes"]
+        .as_array()
+        .map(|nodes| {
+            nodes
+                .iter()
+                .filter_map(|l| l["name"].as_str().map(|s| s.to_lowercase()))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    for (label, heading) in LABEL_HEADINGS {
+        if labels.iter().any(|l| l == label) {
+            return heading.to_string();
+        }
+    }
+    let mut labels = labels;
+    labels.sort();
+    match labels.first() {
+        Some(label) => {
+            let mut chars = label.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => OTHER_HEADING.to_string(),
+            }
+        }
+        None => OTHER_HEADING.to_string(),
+    }
+}
+
+fn group_name(issue: &Value, group_by: &str) -> String {
+    match group_by {
+        "project" => issue["project"]["name"]
+            .as_str()
+            .unwrap_or("No Project")
+            .to_string(),
+        "none" => "Changes".to_string(),
+        _ => label_heading(issue),
+    }
+}
+
+/// Order groups: well-known headings first, then alphabetical, catch-alls last.
+fn group_rank(name: &str) -> (usize, String) {
+    let known = LABEL_HEADINGS.iter().position(|(_, h)| *h == name);
+    let rank = match (known, name) {
+        (Some(i), _) => i,
+        (None, OTHER_HEADING) | (None, "No Project") => usize::MAX,
+        _ => LABEL_HEADINGS.len(),
+    };
+    (rank, name.to_string())
+}
+
+/// Build the changelog document (the JSON shape; Markdown is rendered from it).
+fn build_changelog(
+    version: &str,
+    range: &str,
+    commits: &[Commit],
+    issues: &[Value],
+    group_by: &str,
+) -> Value {
+    let by_id: HashMap<String, &Value> = issues
+        .iter()
+        .filter_map(|i| Some((i["identifier"].as_str()?.to_uppercase(), i)))
+        .collect();
+
+    // Commits per issue, in log order
+    let mut issue_commits: Vec<(String, Vec<&Commit>)> = Vec::new();
+    let mut index: HashMap<String, usize> = HashMap::new();
+    let mut unreferenced = Vec::new();
+    let mut missing = Vec::new();
+    for commit in commits {
+        match &commit.issue {
+            Some(id) if by_id.contains_key(id) => {
+                let idx = *index.entry(id.clone()).or_insert_with(|| {
+                    issue_commits.push((id.clone(), Vec::new()));
+                    issue_commits.len() - 1
+                });
+                issue_commits[idx].1.push(commit);
+            }
+            Some(id) => {
+                if !missing.contains(id) {
+                    missing.push(id.clone());
+                }
+                unreferenced
+                    .push(json!({ "sha": commit.sha, "subject": commit.subject, "issue": id }));
+            }
+            None => unreferenced.push(json!({ "sha": commit.sha, "subject": commit.subject })),
+        }
+    }
+
+    let mut groups: BTreeMap<(usize, String), Vec<Value>> = BTreeMap::new();
+    for (id, commits) in &issue_commits {
+        let issue = by_id[id];
+        let labels: Vec<&str> = issue["labels"]["nodes"]
+            .as_array()
+            .map(|n| n.iter().filter_map(|l| l["name"].as_str()).collect())
+            .unwrap_or_default();
+        groups
+            .entry(group_rank(&group_name(issue, group_by)))
+            .or_default()
+            .push(json!({
+                "id": issue["id"],
+                "identifier": issue["identifier"],
+                "title": issue["title"],
+                "url": issue["url"],
+                "state": issue["state"]["name"],
+                "project": issue["project"]["name"],
+                "labels": labels,
+                "commits": commits.iter().map(|c| c.sha.clone()).collect::<Vec<_>>(),
+            }));
+    }
+
+    json!({
+        "version": version,
+        "range": range,
+        "groups": groups
+            .into_iter()
+            .map(|((_, name), issues)| json!({ "name": name, "issues": issues }))
+            .collect::<Vec<_>>(),
+        "unreferenced": unreferenced,
+        "missing": missing,
+    })
+}
+
+fn render_markdown(changelog: &Value) -> String {
+    let mut out = format!("# {}\n", changelog["version"].as_str().unwrap_or(""));
+
+    for group in changelog["groups"].as_array().into_iter().flatten() {
+        out.push_str(&format!(
+            "\n## {}\n\n",
+            group["name"].as_str().unwrap_or("")
+        ));
+        for issue in group["issues"].as_array().into_iter().flatten() {
+            let identifier = issue["identifier"].as_str().unwrap_or("");
+            let reference = match issue["url"].as_str() {
+                Some(url) => format!("[{}]({})", identifier, url),
+                None => identifier.to_string(),
+            };
+            out.push_str(&format!(
+                "- {} ({})\n",
+                issue["title"].as_str().unwrap_or(""),
+                reference
+            ));
+        }
+    }
+
+    let unreferenced = changelog["unreferenced"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default();
+    if !unreferenced.is_empty() {
+        out.push_str("\n## Other Commits\n\n");
+        for commit in &unreferenced {
+            let sha = commit["sha"].as_str().unwrap_or("");
+            out.push_str(&format!(
+                "- {} {}\n",
+                &sha[..sha.len().min(8)],
+                commit["subject"].as_str().unwrap_or("")
+            ));
+        }
+    }
+
+    out
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn changelog(
+    range: &str,
+    group_by: &str,
+    version: Option<String>,
+    mark_released: Option<String>,
+    comment: bool,
+    merges: bool,
+    file: Option<String>,
+    output: &OutputOptions,
+) -> Result<()> {
+    if !matches!(group_by, "label" | "project" | "none") {
+        anyhow::bail!(
+            "Unknown --group-by value: '{}'. Use label, project, or none.",
+            group_by
+        );
+    }
+
+    let (range, end) = normalize_range(range);
+    let mut args = vec!["log", "--format=%H%x1f%s%x1f%B%x1e"];
+    if !merges {
+        args.push("--no-merges");
+    }
+    args.push(&range);
+    let log = run_git_command(&args)?;
+    let commits = parse_log(&log);
+
+    let version = match version {
+        Some(v) => v,
+        None if end != "HEAD" => end.clone(),
+        None => run_git_command(&["describe", "--tags", "--always", "HEAD"])
+            .unwrap_or_else(|_| "Unreleased".to_string()),
+    };
+
+    let mut identifiers: Vec<String> = commits.iter().filter_map(|c| c.issue.clone()).collect();
+    identifiers.sort();
+    identifiers.dedup();
+
+    // Commits without issue references don't need the API at all
+    let issues = if identifiers.is_empty() {
+        Vec::new()
+    } else {
+        fetch_issues_by_identifier(&LinearClient::new()?, &identifiers).await?
+    };
+
+    let notes = build_changelog(&version, &range, &commits, &issues, group_by);
+
+    if let Some(ref path) = file {
+        let content = if output.is_json() {
+            serde_json::to_string_pretty(&notes)?
+        } else {
+            render_markdown(&notes)
+        };
+        std::fs::write(path, content)?;
+        eprintln!("Wrote release notes to {}", path);
+    } else if output.is_json() || output.has_template() {
+        print_json_owned(notes.clone(), output)?;
+    } else {
+        print!("{}", render_markdown(&notes));
+    }
+
+    if (mark_released.is_none() && !comment) || issues.is_empty() {
+        return Ok(());
+    }
+
+    let released: Vec<&Value> = notes["groups"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .flat_map(|g| g["issues"].as_array().into_iter().flatten())
+        .collect();
+    let by_identifier: HashMap<&str, &Value> = issues
+        .iter()
+        .filter_map(|i| Some((i["identifier"].as_str()?, i)))
+        .collect();
+
+    if output.dry_run {
+        eprintln!(
+            "{}",
+            "[DRY RUN] Would update released issues:".yellow().bold()
+        );
+        for issue in &released {
+            let identifier = issue["identifier"].as_str().unwrap_or("");
+            if let Some(ref state) = mark_released {
+                eprintln!("  {} -> {}", identifier, state);
+            }
+            if comment {
+                eprintln!(
+                    "  {} comment: Released in {} (unless already posted)",
+                    identifier, version
+                );
+            }
+        }
+        return Ok(());
+    }
+
+    let client = LinearClient::new()?;
+    let mut state_ids: HashMap<String, String> = HashMap::new();
+    for issue in &released {
+        let identifier = issue["identifier"].as_str().unwrap_or("");
+        let Some(full) = by_identifier.get(identifier) else {
+            continue;
+        };
+        let issue_id = full["id"].as_str().unwrap_or("");
+
+        if let Some(ref state) = mark_released {
+            let team_id = full["team"]["id"].as_str().unwrap_or("").to_string();
+            if !full["state"]["name"]
+                .as_str()
+                .is_some_and(|s| s.eq_ignore_ascii_case(state))
+            {
+                let state_id = match state_ids.get(&team_id) {
+                    Some(id) => id.clone(),
+                    None => {
+                        let id = resolve_state_id(&client, &team_id, state).await?;
+                        state_ids.insert(team_id.clone(), id.clone());
+                        id
+                    }
+                };
+                let mutation = r#"
+                    mutation($id: String!, $input: IssueUpdateInput!) {
+                        issueUpdate(id: $id, input: $input) { success }
+                    }
+                "#;
+                let result = client
+                    .mutate(
+                        mutation,
+                        Some(json!({ "id": issue_id, "input": { "stateId": state_id } })),
+                    )
+                    .await?;
+                if result["data"]["issueUpdate"]["success"].as_bool() == Some(true) {
+                    eprintln!("{} {} -> {}", "+".green(), identifier.cyan(), state);
+                } else {
+                    eprintln!("{} Failed to update {}", "!".red(), identifier);
+                }
+            }
+        }
+
+        if comment {
+            let body = format!("Released in {}", version);
+            // Re-running for the same release must not repeat the comment
+            if has_comment(&client, issue_id, &body).await? {
+                eprintln!("  {} already has \"{}\"", identifier.cyan(), body);
+                continue;
+            }
+            let mutation = r#"
+                mutation($input: CommentCreateInput!) {
+                    commentCreate(input: $input) { success }
+                }
+            "#;
+            let result = client
+                .mutate(
+                    mutation,
+                    Some(json!({ "input": { "issueId": issue_id, "body": body } })),
+                )
+                .await?;
+            if result["data"]["commentCreate"]["success"].as_bool() == Some(true) {
+                eprintln!("{} Commented on {}", "+".green(), identifier.cyan());
+            } else {
+                eprintln!("{} Failed to comment on {}", "!".red(), identifier);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether the issue already has a comment with exactly this body.
+async fn has_comment(client: &LinearClient, issue_id: &str, body: &str) -> Result<bool> {
+    let query = r#"
+        query($id: String!, $body: String!) {
+            issue(id: $id) {
+                comments(first: 1, filter: { body: { eq: $body } }) { nodes { id } }
+            }
+        }
+    "#;
+    let result = client
+        .query(query, Some(json!({ "id": issue_id, "body": body })))
+        .await?;
+    Ok(result["data"]["issue"]["comments"]["nodes"]
+        .as_array()
+        .is_some_and(|n| !n.is_empty()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(identifier: &str, title: &str, labels: &[&str], project: Option<&str>) -> Value {
+        json!({
+            "id": format!("uuid-{}", identifier),
+            "identifier": identifier,
+            "title": title,
+            "url": format!("https://linear.app/acme/issue/{}", identifier),
+            "state": { "name": "Done" },
+            "project": project.map(|p| json!({ "name": p })).unwrap_or(Value::Null),
+            "labels": { "nodes": labels.iter().map(|l| json!({ "name": l })).collect::<Vec<_>>() },
+        })
+    }
+
+    #[test]
+    fn test_parse_log_extracts_issues() {
+        let log = "aaa\x1fLIN-1: Add login\x1fLIN-1: Add login\n\nLinear-Issue: LIN-1\n\x1e\nbbb\x1fFix typo\x1fFix typo\n\x1e\n";
+        let commits = parse_log(log);
+        assert_eq!(commits.len(), 2);
+        assert_eq!(commits[0].issue.as_deref(), Some("LIN-1"));
+        assert_eq!(commits[1].sha, "bbb");
+        assert!(commits[1].issue.is_none());
+    }
+
+    #[test]
+    fn test_normalize_range() {
+        assert_eq!(
+            normalize_range("v1.2.0"),
+            ("v1.2.0..HEAD".to_string(), "HEAD".to_string())
+        );
+        assert_eq!(
+            normalize_range("v1.2.0..v1.3.0"),
+            ("v1.2.0..v1.3.0".to_string(), "v1.3.0".to_string())
+        );
+        assert_eq!(
+            normalize_range("v1.2.0.."),
+            ("v1.2.0..".to_string(), "HEAD".to_string())
+        );
+    }
+
+    #[test]
+    fn test_build_changelog_groups_by_label() {
+        let commits = vec![
+            Commit {
+                sha: "a1".into(),
+                subject: "x".into(),
+                issue: Some("LIN-1".into()),
+            },
+            Commit {
+                sha: "a2".into(),
+                subject: "y".into(),
+                issue: Some("LIN-2".into()),
+            },
+            Commit {
+                sha: "a3".into(),
+                subject: "z".into(),
+                issue: Some("LIN-1".into()),
+            },
+            Commit {
+                sha: "a4".into(),
+                subject: "Bump deps".into(),
+                issue: None,
+            },
+            Commit {
+                sha: "a5".into(),
+                subject: "Gone".into(),
+                issue: Some("LIN-9".into()),
+            },
+        ];
+        let issues = vec![
+            issue("LIN-1", "Fix crash", &["Bug"], None),
+            issue("LIN-2", "Add SSO", &["Feature", "Bug"], Some("Auth")),
+        ];
+        let notes = build_changelog("v1.3.0", "v1.2.0..HEAD", &commits, &issues, "label");
+        let groups = notes["groups"].as_array().unwrap();
+        assert_eq!(groups[0]["name"], "Features");
+        assert_eq!(groups[1]["name"], "Bug Fixes");
+        assert_eq!(groups[1]["issues"][0]["commits"], json!(["a1", "a3"]));
+        assert_eq!(notes["unreferenced"].as_array().unwrap().len(), 2);
+        assert_eq!(notes["missing"], json!(["LIN-9"]));
+
+        let by_project = build_changelog("v1.3.0", "r", &commits, &issues, "project");
+        let names: Vec<&str> = by_project["groups"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|g| g["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, vec!["Auth", "No Project"]);
+    }
+
+    #[test]
+    fn test_render_markdown() {
+        let commits = vec![
+            Commit {
+                sha: "abcdef123456".into(),
+                subject: "Bump deps".into(),
+                issue: None,
+            },
+            Commit {
+                sha: "a1".into(),
+                subject: "x".into(),
+                issue: Some("LIN-1".into()),
+            },
+        ];
+        let issues = vec![issue("LIN-1", "Fix crash", &["bug"], None)];
+        let md = render_markdown(&build_changelog("v1.3.0", "r", &commits, &issues, "label"));
+        assert_eq!(
+            md,
+            "# v1.3.0\n\n## Bug Fixes\n\n- Fix crash ([LIN-1](https://linear.app/acme/issue/LIN-1))\n\n## Other Commits\n\n- abcdef12 Bump deps\n"
+        );
+    }
+}
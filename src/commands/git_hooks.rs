@@ -0,0 +1,428 @@
+use anyhow::{Context, Result};
+use clap::Subcommand;
+use colored::Colorize;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::vcs::{issue_id_from_branch_with, run_git_command};
+
+use super::git::{extract_linear_issue, get_issue_info};
+
+/// Marker written into hooks we install, so we never overwrite or remove foreign hooks.
+const HOOK_MARKER: &str = "# Installed by linear-cli";
+
+const HOOKS: [&str; 2] = ["prepare-commit-msg", "commit-msg"];
+
+/// How long the prepare-commit-msg hook waits for the issue URL before giving up.
+const URL_LOOKUP_TIMEOUT: Duration = Duration::from_secs(3);
+
+#[derive(Subcommand)]
+pub enum HookCommands {
+    /// Install prepare-commit-msg and commit-msg hooks in the current repo
+    #[command(after_help = r#"EXAMPLES:
+    linear git hooks install                   # Prefix commits and add trailers
+    linear g hooks install --require-reference # Reject commits without an issue
+    linear g hooks install --force             # Replace existing hooks (backed up)
+
+On a branch like lin-123/fix-login, commit messages become:
+
+    LIN-123: Fix login redirect
+
+    Linear-Issue: LIN-123
+    Linear-URL: https://linear.app/acme/issue/LIN-123
+
+Hooks run for git only; jj does not execute git hooks."#)]
+    Install {
+        /// Reject commits on issue branches that don't reference an issue
+        #[arg(long)]
+        require_reference: bool,
+        /// Overwrite existing hooks (the originals are renamed to *.bak)
+        #[arg(long)]
+        force: bool,
+    },
+    /// Remove hooks installed by linear-cli
+    Uninstall,
+    /// Run a hook (called by the installed hook scripts)
+    #[command(hide = true)]
+    Run {
+        /// Hook name: prepare-commit-msg or commit-msg
+        hook: String,
+        /// Reject commits that don't reference an issue (commit-msg only)
+        #[arg(long)]
+        require_reference: bool,
+        /// Arguments passed by git
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+}
+
+pub async fn handle(cmd: HookCommands) -> Result<()> {
+    match cmd {
+        HookCommands::Install {
+            require_reference,
+            force,
+        } => install_hooks(require_reference, force),
+        HookCommands::Uninstall => uninstall_hooks(),
+        HookCommands::Run {
+            hook,
+            require_reference,
+            args,
+        } => match hook.as_str() {
+            "prepare-commit-msg" => prepare_commit_msg(&args).await,
+            "commit-msg" => commit_msg(&args, require_reference),
+            other => anyhow::bail!("Unknown hook: {}", other),
+        },
+    }
+}
+
+fn hooks_dir() -> Result<PathBuf> {
+    // --git-path honours core.hooksPath and linked worktrees
+    let path = run_git_command(&["rev-parse", "--git-path", "hooks"])
+        .context("Not in a git repository")?;
+    Ok(PathBuf::from(path))
+}
+
+/// The hook finds the binary on PATH at commit time, so upgrades that move
+/// it keep working; the install-time path is only a fallback. With neither,
+/// the hook steps aside rather than blocking the commit.
+fn hook_script(hook: &str, require_reference: bool, exe: &Path) -> String {
+    let name = exe
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| "linear-cli".to_string());
+    let quote = |s: &str| format!("'{}'", s.replace('\'', r"'\''"));
+    let flag = if require_reference && hook == "commit-msg" {
+        " --require-reference"
+    } else {
+        ""
+    };
+    format!(
+        "#!/bin/sh\n{} (linear git hooks install)\n\
+         linear=$(command -v {} 2>/dev/null)\n\
+         [ -n \"$linear\" ] || [ ! -x {} ] || linear={}\n\
+         [ -n \"$linear\" ] || exit 0\n\
+         exec \"$linear\" git hooks run {}{} \"$@\"\n",
+        HOOK_MARKER,
+        quote(&name),
+        quote(&exe.to_string_lossy()),
+        quote(&exe.to_string_lossy()),
+        hook,
+        flag
+    )
+}
+
+fn install_hooks(require_reference: bool, force: bool) -> Result<()> {
+    let dir = hooks_dir()?;
+    std::fs::create_dir_all(&dir)?;
+    let exe = std::env::current_exe().unwrap_or_else(|_| PathBuf::from("linear-cli"));
+
+    for hook in HOOKS {
+        let path = dir.join(hook);
+        if path.exists() {
+            let existing = std::fs::read_to_string(&path).unwrap_or_default();
+            if !existing.contains(HOOK_MARKER) {
+                if !force {
+                    anyhow::bail!(
+                        "{} already exists and wasn't installed by linear-cli. Use --force to replace it.",
+                        path.display()
+                    );
+                }
+                let backup = dir.join(format!("{}.bak", hook));
+                std::fs::rename(&path, &backup)?;
+                println!("  Backed up {} to {}", hook, backup.display());
+            }
+        }
+
+        std::fs::write(&path, hook_script(hook, require_reference, &exe))?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755))?;
+        }
+
+        println!("{} Installed {}", "+".green(), path.display());
+    }
+
+    if require_reference {
+        println!("  Commits on issue branches must reference an issue.");
+    }
+
+    Ok(())
+}
+
+fn uninstall_hooks() -> Result<()> {
+    let dir = hooks_dir()?;
+    let mut removed = 0;
+
+    for hook in HOOKS {
+        let path = dir.join(hook);
+        let Ok(existing) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        if !existing.contains(HOOK_MARKER) {
+            println!(
+                "  Skipping {} (not installed by linear-cli)",
+                path.display()
+            );
+            continue;
+        }
+        std::fs::remove_file(&path)?;
+        removed += 1;
+        println!("{} Removed {}", "-".red(), path.display());
+
+        let backup = dir.join(format!("{}.bak", hook));
+        if backup.exists() {
+            std::fs::rename(&backup, &path)?;
+            println!("  Restored previous {}", hook);
+        }
+    }
+
+    if removed == 0 {
+        println!("No linear-cli hooks installed.");
+    }
+
+    Ok(())
+}
+
+fn current_branch_issue() -> Option<String> {
+    // symbolic-ref also works before the first commit, unlike rev-parse
+    let branch = run_git_command(&["symbolic-ref", "--short", "HEAD"]).ok()?;
+    let template = crate::config::git_config()
+        .ok()
+        .and_then(|g| g.branch.template);
+    issue_id_from_branch_with(&branch, template.as_deref())
+}
+
+/// prepare-commit-msg: `<file> [source] [sha]`. Never fails the commit.
+async fn prepare_commit_msg(args: &[String]) -> Result<()> {
+    let Some(file) = args.first() else {
+        anyhow::bail!("prepare-commit-msg: missing message file");
+    };
+
+    // Leave merges, squashes and amends (source "commit") alone
+    if matches!(
+        args.get(1).map(String::as_str),
+        Some("merge") | Some("squash") | Some("commit")
+    ) {
+        return Ok(());
+    }
+
+    let Some(identifier) = current_branch_issue() else {
+        return Ok(());
+    };
+
+    let message = std::fs::read_to_string(file)?;
+    if has_issue_trailer(&message) {
+        return Ok(());
+    }
+
+    // Best effort: a slow or unauthenticated API must not block the commit
+    let url = tokio::time::timeout(URL_LOOKUP_TIMEOUT, get_issue_info(&identifier))
+        .await
+        .ok()
+        .and_then(|r| r.ok())
+        .map(|(_, _, _, url)| url)
+        .filter(|u| !u.is_empty());
+
+    let updated = apply_issue_reference(&message, &identifier, url.as_deref());
+    std::fs::write(file, updated)?;
+
+    Ok(())
+}
+
+/// commit-msg: `<file>`. Rejects (or warns about) messages without an issue reference.
+fn commit_msg(args: &[String], require_reference: bool) -> Result<()> {
+    let Some(file) = args.first() else {
+        anyhow::bail!("commit-msg: missing message file");
+    };
+
+    let Some(identifier) = current_branch_issue() else {
+        return Ok(());
+    };
+
+    let message = std::fs::read_to_string(file)?;
+    if references_issue(&message, &identifier) {
+        return Ok(());
+    }
+
+    if require_reference {
+        anyhow::bail!(
+            "Commit message doesn't reference a Linear issue (branch is for {}). Add \"{}\" to the message or a Linear-Issue: trailer.",
+            identifier,
+            identifier
+        );
+    }
+
+    eprintln!(
+        "{} commit message doesn't reference {}",
+        "Warning:".yellow(),
+        identifier
+    );
+    Ok(())
+}
+
+/// Split a commit message file into (content, comments).
+///
+/// Git strips `#` lines, so everything from the first comment line onwards
+/// (status comments, the verbose scissors diff) is kept verbatim.
+fn split_comments(message: &str) -> (&str, &str) {
+    let mut offset = 0;
+    for line in message.split_inclusive('\n') {
+        if line.starts_with('#') {
+            return message.split_at(offset);
+        }
+        offset += line.len();
+    }
+    (message, "")
+}
+
+fn has_issue_trailer(message: &str) -> bool {
+    split_comments(message)
+        .0
+        .lines()
+        .any(|l| l.starts_with("Linear-Issue:"))
+}
+
+/// Whether the message (ignoring comments) mentions `identifier` or any Linear issue.
+fn references_issue(message: &str, identifier: &str) -> bool {
+    let content = split_comments(message).0;
+    content.to_uppercase().contains(&identifier.to_uppercase())
+        || extract_linear_issue(content).is_some()
+}
+
+fn is_trailer(line: &str) -> bool {
+    static TRAILER_RE: std::sync::OnceLock<regex::Regex> = std::sync::OnceLock::new();
+    let re = TRAILER_RE.get_or_init(|| regex::Regex::new(r"^[A-Za-z][A-Za-z0-9-]*: ").unwrap());
+    re.is_match(line)
+}
+
+/// Prefix the subject with the issue identifier and append Linear trailers,
+/// matching the format `generate_jj_description` produces for jj. A message
+/// without a subject only gets a commented hint.
+fn apply_issue_reference(message: &str, identifier: &str, url: Option<&str>) -> String {
+    let (content, comments) = split_comments(message);
+    let mut lines: Vec<String> = content.lines().map(String::from).collect();
+    while lines.last().is_some_and(|l| l.trim().is_empty()) {
+        lines.pop();
+    }
+
+    let upper = identifier.to_uppercase();
+    match lines.iter().position(|l| !l.trim().is_empty()) {
+        Some(idx) if !lines[idx].to_uppercase().contains(&upper) => {
+            lines[idx] = format!("{}: {}", identifier, lines[idx].trim_start());
+        }
+        Some(_) => {}
+        // Editor flow: only add a hint, so quitting without a subject still aborts
+        None => {
+            return format!(
+                "{}# Linear issue for this branch: {} (reference it in the subject)\n{}",
+                content, identifier, comments
+            );
+        }
+    }
+
+    // Join an existing trailer block (e.g. Signed-off-by) instead of starting a new one
+    let last_paragraph: Vec<&String> = lines
+        .iter()
+        .rev()
+        .take_while(|l| !l.trim().is_empty())
+        .collect();
+    let in_trailer_block = lines.len() > last_paragraph.len()
+        && !last_paragraph.is_empty()
+        && last_paragraph.iter().all(|l| is_trailer(l));
+    if !in_trailer_block {
+        lines.push(String::new());
+    }
+    lines.push(format!("Linear-Issue: {}", identifier));
+    if let Some(url) = url {
+        lines.push(format!("Linear-URL: {}", url));
+    }
+
+    let mut out = lines.join("\n");
+    out.push('\n');
+    if !comments.is_empty() {
+        out.push('\n');
+        out.push_str(comments);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_issue_reference_matches_jj_format() {
+        let out = apply_issue_reference(
+            "Fix login redirect\n",
+            "LIN-123",
+            Some("https://linear.app/acme/issue/LIN-123"),
+        );
+        assert_eq!(
+            out,
+            super::super::git::generate_jj_description(
+                "LIN-123",
+                "Fix login redirect",
+                "https://linear.app/acme/issue/LIN-123"
+            ) + "\n"
+        );
+    }
+
+    #[test]
+    fn test_apply_issue_reference_keeps_comments_and_existing_prefix() {
+        let message = "LIN-123 fix login\n\nBody text\n\n# Please enter the commit message\n# On branch lin-123/fix\n";
+        let out = apply_issue_reference(message, "LIN-123", None);
+        assert_eq!(
+            out,
+            "LIN-123 fix login\n\nBody text\n\nLinear-Issue: LIN-123\n\n# Please enter the commit message\n# On branch lin-123/fix\n"
+        );
+    }
+
+    #[test]
+    fn test_apply_issue_reference_empty_editor_message() {
+        let out = apply_issue_reference("\n# Please enter the commit message\n", "ENG-7", None);
+        assert_eq!(
+            out,
+            "\n# Linear issue for this branch: ENG-7 (reference it in the subject)\n# Please enter the commit message\n"
+        );
+        // Nothing but comments, so git still aborts on an empty message
+        assert!(split_comments(&out).0.trim().is_empty());
+    }
+
+    #[test]
+    fn test_apply_issue_reference_joins_trailer_block() {
+        let out = apply_issue_reference(
+            "Fix it\n\nSigned-off-by: Dev <dev@example.com>\n",
+            "LIN-1",
+            None,
+        );
+        assert_eq!(
+            out,
+            "LIN-1: Fix it\n\nSigned-off-by: Dev <dev@example.com>\nLinear-Issue: LIN-1\n"
+        );
+    }
+
+    #[test]
+    fn test_hook_script_prefers_path_and_skips_when_missing() {
+        let script = hook_script(
+            "commit-msg",
+            true,
+            Path::new("/opt/homebrew/Cellar/linear-cli/0.3.9/bin/linear-cli"),
+        );
+        assert!(script.contains(HOOK_MARKER));
+        assert!(script.contains("linear=$(command -v 'linear-cli' 2>/dev/null)"));
+        assert!(script.contains("linear='/opt/homebrew/Cellar/linear-cli/0.3.9/bin/linear-cli'"));
+        assert!(script.contains("|| exit 0\n"));
+        assert!(script.ends_with("git hooks run commit-msg --require-reference \"$@\"\n"));
+    }
+
+    #[test]
+    fn test_references_issue_ignores_comments() {
+        assert!(references_issue("lin-42: fix\n", "LIN-42"));
+        assert!(references_issue("Fix\n\nLinear-Issue: ENG-9\n", "LIN-42"));
+        assert!(!references_issue("Fix\n# On branch lin-42/fix\n", "LIN-42"));
+        assert!(has_issue_trailer("x\n\nLinear-Issue: LIN-1\n"));
+        assert!(!has_issue_trailer("x\n# Linear-Issue: LIN-1\n"));
+    }
+}
@@ -0,0 +1,225 @@
+use anyhow::Result;
+use colored::Colorize;
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::io::BufRead;
+use tabled::{Table, Tabled};
+
+use crate::api::{resolve_user_id, LinearClient};
+use crate::cache::CacheOptions;
+use crate::codeowners::{expand_paths, rank_owners, repo_root_and_prefix, CodeOwners};
+use crate::config;
+use crate::error::{CliError, ErrorKind};
+use crate::output::{print_json_owned, OutputOptions};
+
+/// A CODEOWNERS owner and the Linear user it maps to.
+pub struct Suggestion {
+    pub owner: String,
+    /// Number of the given paths this owner owns
+    pub paths: usize,
+    /// Email or name looked up in Linear
+    pub user: Option<String>,
+    pub user_id: Option<String>,
+}
+
+#[derive(Tabled)]
+struct OwnerRow {
+    #[tabled(rename = "Owner")]
+    owner: String,
+    #[tabled(rename = "Paths")]
+    paths: usize,
+    #[tabled(rename = "Linear User")]
+    user: String,
+}
+
+/// Linear user (email or name) for a CODEOWNERS owner: `[git.owners]` first,
+/// then the owner itself if it's an email or a plain `@handle`. Teams
+/// (`@org/team`) need an explicit mapping.
+fn linear_user_for(owner: &str, map: &BTreeMap<String, String>) -> Option<String> {
+    let bare = owner.trim_start_matches('@');
+    if let Some(user) = map
+        .iter()
+        .find(|(k, _)| k.trim_start_matches('@').eq_ignore_ascii_case(bare))
+        .map(|(_, v)| v)
+    {
+        return Some(user.clone());
+    }
+    if !owner.starts_with('@') && owner.contains('@') {
+        return Some(owner.to_string());
+    }
+    if owner.starts_with('@') && !bare.contains('/') && !bare.is_empty() {
+        return Some(bare.to_string());
+    }
+    None
+}
+
+/// Rank the CODEOWNERS owners of `values` (paths or globs relative to the
+/// current directory) and resolve them to Linear users.
+pub async fn suggest_for_paths(
+    client: &LinearClient,
+    values: &[String],
+    cache: &CacheOptions,
+) -> Result<(CodeOwners, Vec<String>, Vec<Suggestion>)> {
+    let (root, prefix) = repo_root_and_prefix();
+    let owners = CodeOwners::load(&root)?;
+    if owners.path.is_none() {
+        anyhow::bail!(
+            "No CODEOWNERS file found (looked in .github/, the repo root, docs/ and .gitlab/)"
+        );
+    }
+    let map = config::git_config()?.owners;
+    let paths = expand_paths(values, &prefix);
+
+    let mut suggestions = Vec::new();
+    for (owner, count) in rank_owners(&owners, &paths) {
+        let user = linear_user_for(&owner, &map);
+        let user_id = match &user {
+            // An unknown user is just an unmapped owner; auth or network errors aren't
+            Some(u) => match resolve_user_id(client, u, cache).await {
+                Ok(id) => Some(id),
+                Err(e)
+                    if e.downcast_ref::<CliError>()
+                        .is_some_and(|c| c.kind == ErrorKind::NotFound) =>
+                {
+                    None
+                }
+                Err(e) => return Err(e),
+            },
+            None => None,
+        };
+        suggestions.push(Suggestion {
+            owner,
+            paths: count,
+            user,
+            user_id,
+        });
+    }
+    Ok((owners, paths, suggestions))
+}
+
+/// The Linear user (email or name) of the top owner of `values` that exists
+/// in Linear, if any.
+pub async fn assignee_from_paths(
+    client: &LinearClient,
+    values: &[String],
+    cache: &CacheOptions,
+) -> Result<Option<String>> {
+    let (_, _, suggestions) = suggest_for_paths(client, values, cache).await?;
+    Ok(suggestions
+        .into_iter()
+        .find(|s| s.user_id.is_some())
+        .and_then(|s| s.user))
+}
+
+pub async fn suggest_assignee(paths: Vec<String>, output: &OutputOptions) -> Result<()> {
+    let paths = if paths.iter().any(|p| p == "-") {
+        std::io::stdin()
+            .lock()
+            .lines()
+            .map_while(Result::ok)
+            .map(|l| l.trim().to_string())
+            .filter(|l| !l.is_empty())
+            .collect()
+    } else {
+        paths
+    };
+    if paths.is_empty() {
+        anyhow::bail!("No paths given");
+    }
+
+    let client = LinearClient::new()?;
+    let (owners, expanded, suggestions) = suggest_for_paths(&client, &paths, &output.cache).await?;
+
+    if output.is_json() || output.has_template() {
+        let files: Vec<_> = expanded
+            .iter()
+            .map(|p| {
+                let rule = owners.rule_for(p);
+                json!({
+                    "path": p,
+                    "pattern": rule.map(|r| &r.pattern),
+                    "owners": rule.map(|r| r.owners.clone()).unwrap_or_default(),
+                })
+            })
+            .collect();
+        let ranked: Vec<_> = suggestions
+            .iter()
+            .map(|s| {
+                json!({
+                    "owner": s.owner,
+                    "paths": s.paths,
+                    "user": s.user,
+                    "user_id": s.user_id,
+                })
+            })
+            .collect();
+        let top = suggestions
+            .iter()
+            .find(|s| s.user_id.is_some())
+            .map(|s| json!({ "user": s.user, "user_id": s.user_id, "owner": s.owner }));
+        print_json_owned(
+            json!({
+                "codeowners": owners.path.as_ref().map(|p| p.display().to_string()),
+                "assignee": top,
+                "owners": ranked,
+                "files": files,
+            }),
+            output,
+        )?;
+        return Ok(());
+    }
+
+    if suggestions.is_empty() {
+        println!("No CODEOWNERS rule matches these paths.");
+        return Ok(());
+    }
+    let rows: Vec<OwnerRow> = suggestions
+        .iter()
+        .map(|s| OwnerRow {
+            owner: s.owner.clone(),
+            paths: s.paths,
+            user: match (&s.user, &s.user_id) {
+                (Some(u), Some(_)) => u.green().to_string(),
+                (Some(u), None) => format!("{} (not found)", u).red().to_string(),
+                (None, _) => "unmapped - add to [git.owners]".dimmed().to_string(),
+            },
+        })
+        .collect();
+    println!("{}", Table::new(rows));
+    match suggestions.iter().find(|s| s.user_id.is_some()) {
+        Some(s) => println!(
+            "\n{} Suggested assignee: {} ({})",
+            "+".green(),
+            s.user.as_deref().unwrap_or("").cyan(),
+            s.owner
+        ),
+        None => println!("\n{} No owner maps to a Linear user", "!".yellow()),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_linear_user_for() {
+        let mut map = BTreeMap::new();
+        map.insert("alice".to_string(), "alice@acme.com".to_string());
+        map.insert("@acme/payments".to_string(), "bob@acme.com".to_string());
+        assert_eq!(
+            linear_user_for("@alice", &map).as_deref(),
+            Some("alice@acme.com")
+        );
+        assert_eq!(
+            linear_user_for("@ACME/payments", &map).as_deref(),
+            Some("bob@acme.com")
+        );
+        assert_eq!(
+            linear_user_for("dev@acme.com", &map).as_deref(),
+            Some("dev@acme.com")
+        );
+        assert_eq!(linear_user_for("@carol", &map).as_deref(), Some("carol"));
+        assert_eq!(linear_user_for("@acme/core", &map), None);
+    }
+}
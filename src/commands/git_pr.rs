@@ -0,0 +1,533 @@
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use colored::Colorize;
+use serde_json::{json, Value};
+use std::process::Command;
+
+use crate::api::LinearClient;
+use crate::config::{self, GitConfig};
+use crate::display_options;
+use crate::output::{print_json_owned, render_template, OutputOptions};
+use crate::text::truncate;
+use crate::vcs::run_git_command;
+
+const DEFAULT_TITLE_TEMPLATE: &str = "[{{identifier}}] {{title}}";
+const DEFAULT_BODY_TEMPLATE: &str = "Linear: {{url}}\n\n{{description}}\n\n{{sub_issues}}";
+
+/// Code forge hosting the remote; selects the CLI used to open the PR.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Forge {
+    /// GitHub via `gh`
+    Github,
+    /// GitLab via `glab`
+    Gitlab,
+    /// Gitea via `tea`
+    Gitea,
+    /// Forgejo via `fj`
+    Forgejo,
+}
+
+impl Forge {
+    fn parse(name: &str) -> Option<Forge> {
+        match name.to_lowercase().as_str() {
+            "github" | "gh" => Some(Forge::Github),
+            "gitlab" | "glab" => Some(Forge::Gitlab),
+            "gitea" | "tea" => Some(Forge::Gitea),
+            "forgejo" | "fj" | "codeberg" => Some(Forge::Forgejo),
+            _ => None,
+        }
+    }
+
+    fn program(self) -> &'static str {
+        match self {
+            Forge::Github => "gh",
+            Forge::Gitlab => "glab",
+            Forge::Gitea => "tea",
+            Forge::Forgejo => "fj",
+        }
+    }
+}
+
+/// Everything a forge backend needs to open a PR.
+struct PrSpec {
+    title: String,
+    body: String,
+    base: String,
+    head: String,
+    draft: bool,
+    web: bool,
+}
+
+/// Host part of a remote URL (https://, ssh://, or scp-style git@host:path).
+fn remote_host(url: &str) -> Option<String> {
+    if let Ok(parsed) = url::Url::parse(url) {
+        if let Some(host) = parsed.host_str() {
+            return Some(host.to_lowercase());
+        }
+    }
+    // scp-style: [user@]host:owner/repo.git
+    let rest = url.split_once('@').map(|(_, r)| r).unwrap_or(url);
+    let (host, path) = rest.split_once(':')?;
+    if host.is_empty() || path.starts_with("//") {
+        return None;
+    }
+    Some(host.to_lowercase())
+}
+
+/// Pick the forge for a host: configured `[git.forges]` first, then
+/// well-known names. Unknown hosts (GitHub Enterprise on a custom domain,
+/// no remote at all) get GitHub, as `git pr` always used `gh`.
+fn detect_forge(host: &str, git: &GitConfig) -> Result<Forge> {
+    if let Some(name) = git
+        .forges
+        .iter()
+        .find(|(h, _)| h.eq_ignore_ascii_case(host))
+        .map(|(_, f)| f)
+    {
+        return Forge::parse(name).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Unknown forge '{}' for '{}' in [git.forges]; use github, gitlab, gitea or forgejo",
+                name,
+                host
+            )
+        });
+    }
+    Ok(if host.contains("gitlab") {
+        Forge::Gitlab
+    } else if host.contains("codeberg") || host.contains("forgejo") {
+        Forge::Forgejo
+    } else if host.contains("gitea") {
+        Forge::Gitea
+    } else {
+        Forge::Github
+    })
+}
+
+/// Command line for the forge CLI. Gitea and Forgejo CLIs have no draft flag,
+/// so drafts use the conventional "WIP: " title prefix there.
+fn forge_command(forge: Forge, spec: &PrSpec) -> Vec<String> {
+    let mut args: Vec<String> = Vec::new();
+    let mut push = |items: &[&str]| args.extend(items.iter().map(|s| s.to_string()));
+    match forge {
+        Forge::Github => {
+            push(&["pr", "create", "--title", &spec.title, "--body", &spec.body]);
+            push(&["--base", &spec.base]);
+            if spec.draft {
+                push(&["--draft"]);
+            }
+            if spec.web {
+                push(&["--web"]);
+            }
+        }
+        Forge::Gitlab => {
+            push(&[
+                "mr",
+                "create",
+                "--title",
+                &spec.title,
+                "--description",
+                &spec.body,
+            ]);
+            push(&["--target-branch", &spec.base, "--yes"]);
+            if spec.draft {
+                push(&["--draft"]);
+            }
+            if spec.web {
+                push(&["--web"]);
+            }
+        }
+        Forge::Gitea => {
+            let title = draft_title(&spec.title, spec.draft);
+            push(&[
+                "pulls",
+                "create",
+                "--title",
+                &title,
+                "--description",
+                &spec.body,
+            ]);
+            push(&["--base", &spec.base, "--head", &spec.head]);
+        }
+        Forge::Forgejo => {
+            let title = draft_title(&spec.title, spec.draft);
+            push(&["pr", "create", "--base", &spec.base, "--head", &spec.head]);
+            push(&["--body", &spec.body, &title]);
+            if spec.web {
+                push(&["--web"]);
+            }
+        }
+    }
+    args
+}
+
+fn draft_title(title: &str, draft: bool) -> String {
+    if draft {
+        format!("WIP: {}", title)
+    } else {
+        title.to_string()
+    }
+}
+
+/// Base branch from the remote's HEAD (e.g. refs/remotes/origin/HEAD -> main).
+fn remote_default_branch(remote: &str) -> Option<String> {
+    let head = format!("refs/remotes/{}/HEAD", remote);
+    if let Ok(full) = run_git_command(&["symbolic-ref", "--short", &head]) {
+        return full
+            .strip_prefix(&format!("{}/", remote))
+            .map(String::from)
+            .or(Some(full));
+    }
+    // origin/HEAD isn't set for every clone; ask the remote (needs network)
+    let shown = run_git_command(&["remote", "show", remote]).ok()?;
+    shown
+        .lines()
+        .find_map(|l| l.trim().strip_prefix("HEAD branch:"))
+        .map(|b| b.trim().to_string())
+        .filter(|b| !b.is_empty() && b != "(unknown)")
+}
+
+/// The section under an "Acceptance Criteria" heading (markdown `#` heading or
+/// a bold line), up to the next heading.
+fn acceptance_criteria(description: &str) -> String {
+    let is_heading = |line: &str| {
+        let t = line.trim();
+        t.starts_with('#')
+            || (t.starts_with("**") && t.ends_with("**") && t.len() > 4)
+            || (t.starts_with("**") && t.ends_with("**:"))
+    };
+    let mut lines = description.lines();
+    let found = lines.by_ref().any(|l| {
+        is_heading(l) && {
+            let t = l
+                .trim()
+                .trim_start_matches('#')
+                .trim()
+                .trim_matches('*')
+                .trim_end_matches(':');
+            t.trim().eq_ignore_ascii_case("acceptance criteria")
+        }
+    });
+    if !found {
+        return String::new();
+    }
+    lines
+        .take_while(|l| !is_heading(l))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+/// Markdown checklist of sub-issues, ticked when completed.
+fn sub_issue_checklist(children: &[Value]) -> String {
+    children
+        .iter()
+        .filter(|c| c["state"]["type"].as_str() != Some("canceled"))
+        .map(|c| {
+            let done = c["state"]["type"].as_str() == Some("completed");
+            format!(
+                "- [{}] {} {}",
+                if done { "x" } else { " " },
+                c["identifier"].as_str().unwrap_or(""),
+                c["title"].as_str().unwrap_or("")
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Fields available to `{{field}}` placeholders.
+fn template_context(issue: &Value, base: &str, head: &str) -> Value {
+    let description = issue["description"]
+        .as_str()
+        .unwrap_or("")
+        .trim()
+        .to_string();
+    let children = issue["children"]["nodes"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default();
+    let labels: Vec<&str> = issue["labels"]["nodes"]
+        .as_array()
+        .map(|n| n.iter().filter_map(|l| l["name"].as_str()).collect())
+        .unwrap_or_default();
+    json!({
+        "identifier": issue["identifier"],
+        "title": issue["title"],
+        "url": issue["url"],
+        "description": description,
+        "acceptance_criteria": acceptance_criteria(&description),
+        "sub_issues": sub_issue_checklist(&children),
+        "state": issue["state"]["name"],
+        "team": issue["team"]["key"],
+        "project": issue["project"]["name"],
+        "assignee": issue["assignee"]["name"],
+        "labels": labels.join(", "),
+        "priority": crate::priority::priority_to_string(issue["priority"].as_i64()),
+        "branch": head,
+        "base": base,
+        "issue": issue,
+    })
+}
+
+/// Render a template and drop the blank runs left by empty fields.
+fn render(template: &str, context: &Value) -> String {
+    let rendered = render_template(template, context);
+    let mut out = String::new();
+    let mut blank = 0;
+    for line in rendered.trim().lines() {
+        if line.trim().is_empty() {
+            blank += 1;
+            if blank > 1 {
+                continue;
+            }
+        } else {
+            blank = 0;
+        }
+        out.push_str(line.trim_end());
+        out.push('\n');
+    }
+    out.trim_end().to_string()
+}
+
+/// Template from a flag value: inline text, or `@path` to read a file.
+fn load_template(value: &str) -> Result<String> {
+    match value.strip_prefix('@') {
+        Some(path) => std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read template {}", path)),
+        None => Ok(value.replace("\\n", "\n")),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn create_pr(
+    issue_id: &str,
+    base: Option<String>,
+    draft: bool,
+    web: bool,
+    forge: Option<Forge>,
+    remote: &str,
+    title_template: Option<String>,
+    body_template: Option<String>,
+    output: &OutputOptions,
+) -> Result<()> {
+    let git = config::git_config()?;
+
+    let client = LinearClient::new()?;
+    let query = r#"
+        query($id: String!) {
+            issue(id: $id) {
+                id identifier title description url priority
+                state { name }
+                team { key }
+                project { name }
+                assignee { name }
+                labels { nodes { name } }
+                children(first: 100) {
+                    nodes { identifier title state { type } }
+                }
+            }
+        }
+    "#;
+    let result = client.query(query, Some(json!({ "id": issue_id }))).await?;
+    let issue = &result["data"]["issue"];
+    if issue.is_null() {
+        anyhow::bail!("Issue not found: {}", issue_id);
+    }
+
+    let forge = match forge {
+        Some(f) => f,
+        None => {
+            let host = run_git_command(&["remote", "get-url", remote])
+                .ok()
+                .and_then(|url| remote_host(&url))
+                .unwrap_or_default();
+            detect_forge(&host, &git)?
+        }
+    };
+
+    let base = base
+        .or(config::load_repo_config()?.pr_base)
+        .or_else(|| remote_default_branch(remote))
+        .unwrap_or_else(|| "main".to_string());
+    let head = run_git_command(&["symbolic-ref", "--short", "HEAD"]).unwrap_or_default();
+
+    let context = template_context(issue, &base, &head);
+    let title_template = match title_template.or(git.pr_title_template.clone()) {
+        Some(t) => load_template(&t)?,
+        None => DEFAULT_TITLE_TEMPLATE.to_string(),
+    };
+    let body_template = match body_template.or(git.pr_body_template.clone()) {
+        Some(t) => load_template(&t)?,
+        None => DEFAULT_BODY_TEMPLATE.to_string(),
+    };
+    let spec = PrSpec {
+        title: render(&title_template, &context).replace('\n', " "),
+        body: render(&body_template, &context),
+        base,
+        head,
+        draft,
+        web,
+    };
+    let args = forge_command(forge, &spec);
+
+    if output.dry_run {
+        if output.is_json() || output.has_template() {
+            print_json_owned(
+                json!({
+                    "dry_run": true,
+                    "forge": forge.program(),
+                    "title": spec.title,
+                    "body": spec.body,
+                    "base": spec.base,
+                    "head": spec.head,
+                    "command": std::iter::once(forge.program().to_string()).chain(args).collect::<Vec<_>>(),
+                }),
+                output,
+            )?;
+        } else {
+            println!("{}", "[DRY RUN] Would create PR:".yellow().bold());
+            println!("  Forge: {} ({})", forge.program(), spec.base);
+            println!("  Title: {}", spec.title);
+            println!("  Body:\n{}", spec.body);
+        }
+        return Ok(());
+    }
+
+    let title_width = display_options().max_width(60);
+    println!(
+        "{} {}",
+        issue["identifier"].as_str().unwrap_or("").cyan(),
+        truncate(issue["title"].as_str().unwrap_or(""), title_width).dimmed()
+    );
+    println!(
+        "Creating PR into {} with title: {}",
+        spec.base.cyan(),
+        truncate(&spec.title, title_width).green()
+    );
+
+    let result = Command::new(forge.program())
+        .args(&args)
+        .output()
+        .with_context(|| format!("Failed to run '{}'. Is it installed?", forge.program()))?;
+    if !result.status.success() {
+        let stderr = String::from_utf8_lossy(&result.stderr);
+        anyhow::bail!("{} command failed: {}", forge.program(), stderr.trim());
+    }
+    let stdout = String::from_utf8_lossy(&result.stdout).trim().to_string();
+
+    if output.is_json() || output.has_template() {
+        print_json_owned(
+            json!({ "forge": forge.program(), "title": spec.title, "base": spec.base, "result": stdout }),
+            output,
+        )?;
+    } else if !stdout.is_empty() {
+        println!("{} PR created: {}", "+".green(), stdout);
+    } else {
+        println!("{} PR created successfully!", "+".green());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec(draft: bool) -> PrSpec {
+        PrSpec {
+            title: "[LIN-1] Fix".to_string(),
+            body: "Body".to_string(),
+            base: "main".to_string(),
+            head: "lin-1/fix".to_string(),
+            draft,
+            web: false,
+        }
+    }
+
+    #[test]
+    fn test_remote_host() {
+        assert_eq!(
+            remote_host("git@github.com:acme/app.git").as_deref(),
+            Some("github.com")
+        );
+        assert_eq!(
+            remote_host("https://git.example.com/acme/app.git").as_deref(),
+            Some("git.example.com")
+        );
+        assert_eq!(
+            remote_host("ssh://git@gitlab.acme.io:2222/acme/app.git").as_deref(),
+            Some("gitlab.acme.io")
+        );
+        assert_eq!(remote_host("/srv/git/app.git"), None);
+    }
+
+    #[test]
+    fn test_detect_forge() {
+        let mut git = GitConfig::default();
+        assert_eq!(detect_forge("github.com", &git).unwrap(), Forge::Github);
+        assert_eq!(detect_forge("gitlab.acme.io", &git).unwrap(), Forge::Gitlab);
+        assert_eq!(detect_forge("codeberg.org", &git).unwrap(), Forge::Forgejo);
+        // Unknown hosts and missing remotes fall back to GitHub
+        assert_eq!(
+            detect_forge("git.example.com", &git).unwrap(),
+            Forge::Github
+        );
+        assert_eq!(detect_forge("", &git).unwrap(), Forge::Github);
+        git.forges.insert("git.example.com".into(), "gitlab".into());
+        assert_eq!(
+            detect_forge("git.example.com", &git).unwrap(),
+            Forge::Gitlab
+        );
+        git.forges.insert("git.example.com".into(), "svn".into());
+        assert!(detect_forge("git.example.com", &git).is_err());
+    }
+
+    #[test]
+    fn test_forge_commands() {
+        let gh = forge_command(Forge::Github, &spec(true));
+        assert_eq!(gh[..2], ["pr", "create"]);
+        assert!(gh.contains(&"--draft".to_string()));
+        let glab = forge_command(Forge::Gitlab, &spec(false));
+        assert!(glab.windows(2).any(|w| w == ["--target-branch", "main"]));
+        assert!(glab.windows(2).any(|w| w == ["--description", "Body"]));
+        let tea = forge_command(Forge::Gitea, &spec(true));
+        assert!(tea.windows(2).any(|w| w == ["--title", "WIP: [LIN-1] Fix"]));
+        assert!(tea.windows(2).any(|w| w == ["--head", "lin-1/fix"]));
+        let fj = forge_command(Forge::Forgejo, &spec(false));
+        assert_eq!(fj.last().unwrap(), "[LIN-1] Fix");
+    }
+
+    #[test]
+    fn test_acceptance_criteria() {
+        let desc = "Intro\n\n## Acceptance Criteria\n- works\n- fast\n\n## Notes\nmisc";
+        assert_eq!(acceptance_criteria(desc), "- works\n- fast");
+        let bold = "Intro\n**Acceptance criteria:**\n- a\n**Other**\nx";
+        assert_eq!(acceptance_criteria(bold), "- a");
+        assert_eq!(acceptance_criteria("nothing here"), "");
+    }
+
+    #[test]
+    fn test_render_pr_body() {
+        let issue = json!({
+            "identifier": "LIN-1",
+            "title": "Fix",
+            "url": "https://linear.app/acme/issue/LIN-1",
+            "description": "",
+            "children": { "nodes": [
+                { "identifier": "LIN-2", "title": "Part A", "state": { "type": "completed" } },
+                { "identifier": "LIN-3", "title": "Part B", "state": { "type": "started" } },
+            ]},
+        });
+        let context = template_context(&issue, "main", "lin-1/fix");
+        assert_eq!(render(DEFAULT_TITLE_TEMPLATE, &context), "[LIN-1] Fix");
+        assert_eq!(
+            render(DEFAULT_BODY_TEMPLATE, &context),
+            "Linear: https://linear.app/acme/issue/LIN-1\n\n- [x] LIN-2 Part A\n- [ ] LIN-3 Part B"
+        );
+        assert_eq!(
+            render("{{identifier}} -> {{base}}", &context),
+            "LIN-1 -> main"
+        );
+    }
+}
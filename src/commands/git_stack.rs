@@ -0,0 +1,537 @@
+use anyhow::{Context, Result};
+use clap::Subcommand;
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::path::PathBuf;
+use tabled::{Table, Tabled};
+
+use crate::api::LinearClient;
+use crate::config::BranchConfig;
+use crate::output::{print_json_owned, OutputOptions};
+use crate::vcs::{issue_branch_name, run_git_command, BranchInfo};
+
+use super::git::fetch_issues_by_identifier;
+
+/// Stacks are recorded per clone, next to the repo's other local state.
+const STACK_FILE: &str = "linear-stacks.json";
+
+#[derive(Subcommand)]
+pub enum StackCommands {
+    /// Show ahead/behind and issue state for each branch in a stack
+    #[command(after_help = r#"EXAMPLES:
+    linear git stack status                    # All recorded stacks
+    linear g stack status LIN-101              # One stack"#)]
+    Status {
+        /// Parent issue of the stack (default: all stacks)
+        issue: Option<String>,
+    },
+    /// Forget a recorded stack (branches are kept)
+    Forget {
+        /// Parent issue of the stack
+        issue: String,
+    },
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+struct StackBranch {
+    issue: String,
+    branch: String,
+    /// Branch this one is stacked on
+    onto: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+struct Stack {
+    parent: String,
+    base: String,
+    branches: Vec<StackBranch>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+struct StackFile {
+    #[serde(default)]
+    stacks: Vec<Stack>,
+}
+
+#[derive(Tabled)]
+struct StackRow {
+    #[tabled(rename = "Branch")]
+    branch: String,
+    #[tabled(rename = "Issue")]
+    issue: String,
+    #[tabled(rename = "Onto")]
+    onto: String,
+    #[tabled(rename = "Ahead")]
+    ahead: String,
+    #[tabled(rename = "Behind")]
+    behind: String,
+    #[tabled(rename = "State")]
+    state: String,
+}
+
+fn stack_file_path() -> Result<PathBuf> {
+    // --git-common-dir so linked worktrees share one stack file
+    let dir =
+        run_git_command(&["rev-parse", "--git-common-dir"]).context("Not in a git repository")?;
+    Ok(PathBuf::from(dir).join(STACK_FILE))
+}
+
+fn load_stacks() -> Result<StackFile> {
+    let path = stack_file_path()?;
+    if !path.exists() {
+        return Ok(StackFile::default());
+    }
+    let content = std::fs::read_to_string(&path)?;
+    serde_json::from_str(&content)
+        .with_context(|| format!("Invalid stack file: {}", path.display()))
+}
+
+fn save_stacks(file: &StackFile) -> Result<()> {
+    let path = stack_file_path()?;
+    // Write to a temp file and rename so a crash can't leave a torn file
+    let temp_path = path.with_extension("tmp");
+    std::fs::write(&temp_path, serde_json::to_string_pretty(file)?)?;
+    std::fs::rename(&temp_path, &path).context("Failed to update stack state")?;
+    Ok(())
+}
+
+fn branch_exists(branch: &str) -> bool {
+    run_git_command(&[
+        "rev-parse",
+        "--verify",
+        "--quiet",
+        &format!("refs/heads/{}", branch),
+    ])
+    .is_ok()
+}
+
+/// Branch for an issue, named by `[git.branch]` like every other git command.
+fn issue_branch(issue: &Value, config: &BranchConfig, user: Option<&str>) -> String {
+    let labels: Vec<String> = issue["labels"]["nodes"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|l| l["name"].as_str().map(String::from))
+        .collect();
+    issue_branch_name(
+        config,
+        &BranchInfo {
+            identifier: issue["identifier"].as_str().unwrap_or(""),
+            title: issue["title"].as_str().unwrap_or(""),
+            linear_branch: issue["branchName"].as_str().unwrap_or(""),
+            user,
+            labels: &labels,
+        },
+    )
+}
+
+/// Lay out the stack: the parent branch on `base`, then each child either
+/// chained on the previous one or (flat) directly on the parent branch.
+fn plan_stack(
+    parent: &Value,
+    children: &[Value],
+    base: &str,
+    flat: bool,
+    config: &BranchConfig,
+    user: Option<&str>,
+) -> Stack {
+    let parent_id = parent["identifier"].as_str().unwrap_or("").to_string();
+    let parent_branch = issue_branch(parent, config, user);
+    let mut branches = vec![StackBranch {
+        issue: parent_id.clone(),
+        branch: parent_branch.clone(),
+        onto: base.to_string(),
+    }];
+    let mut onto = parent_branch.clone();
+    for child in children {
+        let branch = issue_branch(child, config, user);
+        branches.push(StackBranch {
+            issue: child["identifier"].as_str().unwrap_or("").to_string(),
+            branch: branch.clone(),
+            onto: onto.clone(),
+        });
+        if !flat {
+            onto = branch;
+        }
+    }
+    Stack {
+        parent: parent_id,
+        base: base.to_string(),
+        branches,
+    }
+}
+
+/// Sort children by issue number so the stack follows creation order.
+fn sort_children(children: &mut [Value]) {
+    children.sort_by_key(|c| {
+        c["identifier"]
+            .as_str()
+            .and_then(|id| id.rsplit_once('-'))
+            .and_then(|(_, n)| n.parse::<i64>().ok())
+            .unwrap_or(i64::MAX)
+    });
+}
+
+pub async fn create_stack(
+    issue_id: &str,
+    base: Option<String>,
+    flat: bool,
+    output: &OutputOptions,
+) -> Result<()> {
+    let config = crate::config::git_config()?.branch;
+    let needs_user = config
+        .template
+        .as_deref()
+        .is_some_and(|t| t.contains("{{user}}"));
+    let client = LinearClient::new()?;
+    let query = format!(
+        r#"
+        query($id: String!) {{
+            issue(id: $id) {{
+                identifier title branchName
+                labels {{ nodes {{ name }} }}
+                children(first: 100) {{
+                    nodes {{ identifier title branchName labels {{ nodes {{ name }} }} state {{ type }} }}
+                }}
+            }}
+            {}
+        }}
+    "#,
+        if needs_user {
+            "viewer { displayName }"
+        } else {
+            ""
+        }
+    );
+    let result = client
+        .query(&query, Some(json!({ "id": issue_id })))
+        .await?;
+    let parent = &result["data"]["issue"];
+    if parent.is_null() {
+        anyhow::bail!("Issue not found: {}", issue_id);
+    }
+
+    let mut children: Vec<Value> = parent["children"]["nodes"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|c| c["state"]["type"].as_str() != Some("canceled"))
+        .collect();
+    if children.is_empty() {
+        anyhow::bail!(
+            "{} has no sub-issues to stack. Use: linear git checkout {}",
+            issue_id,
+            issue_id
+        );
+    }
+    sort_children(&mut children);
+
+    let base = match base {
+        Some(b) => b,
+        None => run_git_command(&["symbolic-ref", "--short", "HEAD"])
+            .context("Detached HEAD; pass --base")?,
+    };
+    let user = result["data"]["viewer"]["displayName"].as_str();
+    let stack = plan_stack(parent, &children, &base, flat, &config, user);
+
+    if output.dry_run {
+        if output.is_json() || output.has_template() {
+            print_json_owned(json!({ "dry_run": true, "would_create": stack }), output)?;
+        } else {
+            println!("{}", "[DRY RUN] Would create stack:".yellow().bold());
+            for b in &stack.branches {
+                println!(
+                    "  {} {} (onto {})",
+                    b.issue.cyan(),
+                    b.branch,
+                    b.onto.dimmed()
+                );
+            }
+        }
+        return Ok(());
+    }
+
+    let mut created = Vec::new();
+    for b in &stack.branches {
+        if branch_exists(&b.branch) {
+            if !output.is_json() {
+                println!("{} {} exists, keeping it", "!".yellow(), b.branch);
+            }
+            continue;
+        }
+        run_git_command(&["branch", &b.branch, &b.onto])?;
+        created.push(b.branch.clone());
+        if !output.is_json() {
+            println!(
+                "{} {} {} (onto {})",
+                "+".green(),
+                b.issue.cyan(),
+                b.branch,
+                b.onto.dimmed()
+            );
+        }
+    }
+
+    let mut file = load_stacks()?;
+    file.stacks
+        .retain(|s| !s.parent.eq_ignore_ascii_case(&stack.parent));
+    file.stacks.push(stack.clone());
+    save_stacks(&file)?;
+
+    if output.is_json() || output.has_template() {
+        print_json_owned(json!({ "stack": stack, "created": created }), output)?;
+    } else {
+        println!(
+            "\nRecorded stack for {} ({} branches). Check it with: linear git stack status",
+            stack.parent,
+            stack.branches.len()
+        );
+    }
+
+    Ok(())
+}
+
+pub async fn handle(cmd: StackCommands, output: &OutputOptions) -> Result<()> {
+    match cmd {
+        StackCommands::Status { issue } => stack_status(issue, output).await,
+        StackCommands::Forget { issue } => {
+            let mut file = load_stacks()?;
+            let before = file.stacks.len();
+            file.stacks
+                .retain(|s| !s.parent.eq_ignore_ascii_case(&issue));
+            if file.stacks.len() == before {
+                anyhow::bail!("No stack recorded for {}", issue);
+            }
+            save_stacks(&file)?;
+            println!("{} Forgot stack for {}", "-".red(), issue);
+            Ok(())
+        }
+    }
+}
+
+/// (ahead, behind) of `branch` relative to `onto`.
+fn ahead_behind(branch: &str, onto: &str) -> Option<(u64, u64)> {
+    let out = run_git_command(&[
+        "rev-list",
+        "--left-right",
+        "--count",
+        &format!("{}...{}", branch, onto),
+    ])
+    .ok()?;
+    let mut parts = out.split_whitespace().map(|n| n.parse::<u64>().ok());
+    Some((parts.next()??, parts.next()??))
+}
+
+async fn stack_status(issue: Option<String>, output: &OutputOptions) -> Result<()> {
+    let file = load_stacks()?;
+    let stacks: Vec<&Stack> = file
+        .stacks
+        .iter()
+        .filter(|s| {
+            issue
+                .as_ref()
+                .is_none_or(|i| s.parent.eq_ignore_ascii_case(i))
+        })
+        .collect();
+    if stacks.is_empty() {
+        match issue {
+            Some(i) => anyhow::bail!("No stack recorded for {}", i),
+            None => {
+                println!("No stacks recorded. Create one with: linear git stack ISSUE");
+                return Ok(());
+            }
+        }
+    }
+
+    let identifiers: Vec<String> = stacks
+        .iter()
+        .flat_map(|s| s.branches.iter().map(|b| b.issue.clone()))
+        .collect();
+    let client = LinearClient::new()?;
+    let issues = fetch_issues_by_identifier(&client, &identifiers).await?;
+    let state_of = |id: &str| {
+        issues
+            .iter()
+            .find(|i| i["identifier"].as_str() == Some(id))
+            .and_then(|i| i["state"]["name"].as_str())
+            .map(String::from)
+    };
+
+    let report: Vec<Value> = stacks
+        .iter()
+        .map(|s| {
+            let branches: Vec<Value> = s
+                .branches
+                .iter()
+                .map(|b| {
+                    let exists = branch_exists(&b.branch);
+                    let counts = if exists {
+                        ahead_behind(&b.branch, &b.onto)
+                    } else {
+                        None
+                    };
+                    json!({
+                        "issue": b.issue,
+                        "branch": b.branch,
+                        "onto": b.onto,
+                        "exists": exists,
+                        "ahead": counts.map(|c| c.0),
+                        "behind": counts.map(|c| c.1),
+                        "state": state_of(&b.issue),
+                    })
+                })
+                .collect();
+            json!({ "parent": s.parent, "base": s.base, "branches": branches })
+        })
+        .collect();
+
+    if output.is_json() || output.has_template() {
+        print_json_owned(json!(report), output)?;
+        return Ok(());
+    }
+
+    for stack in &report {
+        println!(
+            "{} {}",
+            stack["parent"].as_str().unwrap_or("").cyan().bold(),
+            format!("(base {})", stack["base"].as_str().unwrap_or("")).dimmed()
+        );
+        let count = |v: &Value, color: fn(&str) -> String| match v.as_u64() {
+            Some(0) => "0".to_string(),
+            Some(n) => color(&n.to_string()),
+            None => "-".to_string(),
+        };
+        let rows: Vec<StackRow> = stack["branches"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|b| StackRow {
+                branch: if b["exists"].as_bool() == Some(true) {
+                    b["branch"].as_str().unwrap_or("").to_string()
+                } else {
+                    format!("{} (missing)", b["branch"].as_str().unwrap_or(""))
+                        .red()
+                        .to_string()
+                },
+                issue: b["issue"].as_str().unwrap_or("").to_string(),
+                onto: b["onto"].as_str().unwrap_or("").to_string(),
+                ahead: count(&b["ahead"], |s| s.green().to_string()),
+                // Behind means the branch below moved on and this one needs a rebase
+                behind: count(&b["behind"], |s| s.yellow().to_string()),
+                state: b["state"].as_str().unwrap_or("-").to_string(),
+            })
+            .collect();
+        println!("{}\n", Table::new(rows));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(identifier: &str, title: &str, branch: &str) -> Value {
+        json!({ "identifier": identifier, "title": title, "branchName": branch })
+    }
+
+    #[test]
+    fn test_plan_stack_chained() {
+        let parent = issue("LIN-101", "Big feature", "lin-101-big-feature");
+        let children = vec![
+            issue("LIN-102", "Schema", "lin-102-schema"),
+            issue("LIN-103", "API", ""),
+        ];
+        let stack = plan_stack(
+            &parent,
+            &children,
+            "main",
+            false,
+            &BranchConfig::default(),
+            None,
+        );
+        let layout: Vec<(&str, &str)> = stack
+            .branches
+            .iter()
+            .map(|b| (b.branch.as_str(), b.onto.as_str()))
+            .collect();
+        assert_eq!(
+            layout,
+            vec![
+                ("lin-101-big-feature", "main"),
+                ("lin-102-schema", "lin-101-big-feature"),
+                ("lin-103/api", "lin-102-schema"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_plan_stack_flat() {
+        let parent = issue("LIN-101", "Big feature", "feat");
+        let children = vec![issue("LIN-102", "A", "a"), issue("LIN-103", "B", "b")];
+        let stack = plan_stack(
+            &parent,
+            &children,
+            "develop",
+            true,
+            &BranchConfig::default(),
+            None,
+        );
+        assert!(stack.branches[1..].iter().all(|b| b.onto == "feat"));
+        assert_eq!(stack.base, "develop");
+    }
+
+    #[test]
+    fn test_plan_stack_uses_branch_template() {
+        let config = BranchConfig {
+            template: Some("{{user}}/{{identifier}}-{{slug}}".to_string()),
+            ..Default::default()
+        };
+        let parent = issue("LIN-101", "Big feature", "lin-101-big-feature");
+        let stack = plan_stack(
+            &parent,
+            &[issue("LIN-102", "Schema", "")],
+            "main",
+            false,
+            &config,
+            Some("Ada"),
+        );
+        assert_eq!(stack.branches[0].branch, "ada/lin-101-big-feature");
+        assert_eq!(stack.branches[1].branch, "ada/lin-102-schema");
+        assert_eq!(stack.branches[1].onto, "ada/lin-101-big-feature");
+    }
+
+    #[test]
+    fn test_sort_children_by_number() {
+        let mut children = vec![
+            issue("LIN-110", "", ""),
+            issue("LIN-9", "", ""),
+            issue("LIN-12", "", ""),
+        ];
+        sort_children(&mut children);
+        let ids: Vec<&str> = children
+            .iter()
+            .map(|c| c["identifier"].as_str().unwrap())
+            .collect();
+        assert_eq!(ids, vec!["LIN-9", "LIN-12", "LIN-110"]);
+    }
+
+    #[test]
+    fn test_stack_file_roundtrip() {
+        let file = StackFile {
+            stacks: vec![plan_stack(
+                &issue("LIN-1", "P", "p"),
+                &[issue("LIN-2", "C", "c")],
+                "main",
+                false,
+                &BranchConfig::default(),
+                None,
+            )],
+        };
+        let parsed: StackFile =
+            serde_json::from_str(&serde_json::to_string(&file).unwrap()).unwrap();
+        assert_eq!(parsed.stacks, file.stacks);
+    }
+}
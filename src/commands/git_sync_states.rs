@@ -0,0 +1,498 @@
+use anyhow::Result;
+use colored::Colorize;
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap};
+use tabled::{Table, Tabled};
+
+use crate::api::LinearClient;
+use crate::config::{self, GitConfig};
+use crate::output::{print_json_owned, OutputOptions};
+use crate::vcs::run_git_command;
+
+use super::git::fetch_issues_by_identifier;
+
+/// Built-in magic words. Targets are state types, so they work for any
+/// team's workflow; configured targets may also be state names.
+const DEFAULT_MAGIC_WORDS: [(&str, &str); 14] = [
+    ("fix", "completed"),
+    ("fixes", "completed"),
+    ("fixed", "completed"),
+    ("close", "completed"),
+    ("closes", "completed"),
+    ("closed", "completed"),
+    ("resolve", "completed"),
+    ("resolves", "completed"),
+    ("resolved", "completed"),
+    ("complete", "completed"),
+    ("completes", "completed"),
+    ("ref", "started"),
+    ("refs", "started"),
+    ("references", "started"),
+];
+
+const STATE_TYPES: [&str; 6] = [
+    "triage",
+    "backlog",
+    "unstarted",
+    "started",
+    "completed",
+    "canceled",
+];
+
+#[derive(Tabled)]
+struct TransitionRow {
+    #[tabled(rename = "Issue")]
+    identifier: String,
+    #[tabled(rename = "Word")]
+    word: String,
+    #[tabled(rename = "From")]
+    from: String,
+    #[tabled(rename = "To")]
+    to: String,
+    #[tabled(rename = "Result")]
+    result: String,
+}
+
+/// Progress rank of a workflow state type; transitions never move backwards.
+fn state_rank(state_type: &str) -> u8 {
+    match state_type {
+        "triage" | "backlog" => 0,
+        "unstarted" => 1,
+        "started" => 2,
+        "completed" | "canceled" => 3,
+        _ => 0,
+    }
+}
+
+/// Whether `a` is further along the workflow than `b`: by state type, then
+/// by workflow position within the same type (In Progress -> Ready for QA).
+fn is_further(a: &Value, b: &Value) -> bool {
+    let rank = |s: &Value| state_rank(s["type"].as_str().unwrap_or(""));
+    let position = |s: &Value| s["position"].as_f64().unwrap_or(0.0);
+    match rank(a).cmp(&rank(b)) {
+        std::cmp::Ordering::Equal => position(a) > position(b),
+        ordering => ordering.is_gt(),
+    }
+}
+
+/// Word -> target mapping for a team: built-ins, then `[git.magic_words]`,
+/// then `[git.team_magic_words.TEAM]`, then `--map` overrides.
+fn magic_words_for_team(
+    git: &GitConfig,
+    overrides: &[(String, String)],
+    team_key: Option<&str>,
+) -> BTreeMap<String, String> {
+    let mut words: BTreeMap<String, String> = DEFAULT_MAGIC_WORDS
+        .iter()
+        .map(|(w, t)| (w.to_string(), t.to_string()))
+        .collect();
+    let team_words = team_key.and_then(|key| {
+        git.team_magic_words
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(key))
+            .map(|(_, v)| v)
+    });
+    for layer in [Some(&git.magic_words), team_words].into_iter().flatten() {
+        for (word, target) in layer {
+            words.insert(word.to_lowercase(), target.clone());
+        }
+    }
+    for (word, target) in overrides {
+        words.insert(word.to_lowercase(), target.clone());
+    }
+    words.retain(|_, target| !target.trim().is_empty());
+    words
+}
+
+/// Find `<word> ID[, ID and ID]` references. Returns (word, identifier) pairs.
+fn parse_magic_words(message: &str, words: &[String]) -> Vec<(String, String)> {
+    if words.is_empty() {
+        return Vec::new();
+    }
+    let alternatives: Vec<String> = words.iter().map(|w| regex::escape(w)).collect();
+    // IDs may be bare or inside a linear.app URL
+    let reference =
+        r"(?:https?://linear\.app/[^\s/]+/issue/)?[A-Za-z][A-Za-z0-9]*-\d+(?:/[^\s,]*)?";
+    let pattern = format!(
+        r"(?i)\b({})\b:?\s+((?:{})(?:\s*(?:,|\band\b)\s*(?:{}))*)",
+        alternatives.join("|"),
+        reference,
+        reference
+    );
+    let Ok(re) = regex::Regex::new(&pattern) else {
+        return Vec::new();
+    };
+    // Same reference shape with the identifier captured; URL slugs are consumed, not matched
+    let id_re = regex::Regex::new(
+        r"(?:https?://linear\.app/[^\s/]+/issue/)?([A-Za-z][A-Za-z0-9]*-\d+)(?:/[^\s,]*)?",
+    )
+    .unwrap();
+
+    let mut found = Vec::new();
+    for caps in re.captures_iter(message) {
+        let word = caps[1].to_lowercase();
+        for id in id_re.captures_iter(&caps[2]) {
+            let pair = (word.clone(), id[1].to_uppercase());
+            if !found.contains(&pair) {
+                found.push(pair);
+            }
+        }
+    }
+    found
+}
+
+/// Pick the team state for a target: exact name first, else the first
+/// state (by position) of the matching state type.
+fn resolve_target<'a>(states: &'a [Value], target: &str) -> Option<&'a Value> {
+    if let Some(state) = states.iter().find(|s| {
+        s["name"]
+            .as_str()
+            .is_some_and(|n| n.eq_ignore_ascii_case(target))
+    }) {
+        return Some(state);
+    }
+    let target = target.to_lowercase();
+    if !STATE_TYPES.contains(&target.as_str()) {
+        return None;
+    }
+    states
+        .iter()
+        .filter(|s| s["type"].as_str() == Some(target.as_str()))
+        .min_by(|a, b| {
+            let pa = a["position"].as_f64().unwrap_or(0.0);
+            let pb = b["position"].as_f64().unwrap_or(0.0);
+            pa.partial_cmp(&pb).unwrap_or(std::cmp::Ordering::Equal)
+        })
+}
+
+/// Decide what to do with an issue currently in `current`, given the
+/// candidate target state. Returns None when a transition should happen.
+fn skip_reason(current: &Value, target: &Value) -> Option<&'static str> {
+    if current["id"] == target["id"] {
+        return Some("already in state");
+    }
+    let current_type = current["type"].as_str().unwrap_or("");
+    if current_type == "canceled" {
+        return Some("canceled");
+    }
+    if !is_further(target, current) {
+        return Some("already further along");
+    }
+    None
+}
+
+async fn fetch_team_states(client: &LinearClient, team_id: &str) -> Result<Vec<Value>> {
+    let query = r#"
+        query($teamId: String!) {
+            team(id: $teamId) {
+                states { nodes { id name type position } }
+            }
+        }
+    "#;
+    let result = client
+        .query(query, Some(json!({ "teamId": team_id })))
+        .await?;
+    Ok(result["data"]["team"]["states"]["nodes"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default())
+}
+
+pub async fn sync_states(
+    range: &str,
+    map: Vec<String>,
+    merges: bool,
+    output: &OutputOptions,
+) -> Result<()> {
+    let overrides: Vec<(String, String)> = map
+        .iter()
+        .map(|m| {
+            m.split_once('=')
+                .map(|(w, s)| (w.trim().to_string(), s.trim().to_string()))
+                .ok_or_else(|| {
+                    anyhow::anyhow!("Invalid --map '{}'. Use WORD=STATE, e.g. fixes=Done", m)
+                })
+        })
+        .collect::<Result<_>>()?;
+
+    let git = config::git_config()?;
+
+    // The regex needs every word any team might use
+    let mut all_words: Vec<String> = magic_words_for_team(&git, &overrides, None)
+        .into_keys()
+        .collect();
+    for team in git.team_magic_words.keys() {
+        all_words.extend(magic_words_for_team(&git, &overrides, Some(team)).into_keys());
+    }
+    all_words.sort();
+    all_words.dedup();
+    // Longest first so "references" wins over "ref"
+    all_words.sort_by_key(|w| std::cmp::Reverse(w.len()));
+
+    let mut args = vec!["log", "--reverse", "--format=%H%x1f%B%x1e"];
+    if !merges {
+        args.push("--no-merges");
+    }
+    args.push(range);
+    let log = run_git_command(&args)?;
+
+    // identifier -> [(word, sha)], in commit order
+    let mut mentions: Vec<(String, Vec<(String, String)>)> = Vec::new();
+    for record in log.split('\x1e') {
+        let Some((sha, message)) = record.trim_start_matches('\n').split_once('\x1f') else {
+            continue;
+        };
+        for (word, id) in parse_magic_words(message, &all_words) {
+            match mentions.iter_mut().find(|(i, _)| *i == id) {
+                Some((_, list)) => list.push((word, sha.to_string())),
+                None => mentions.push((id, vec![(word, sha.to_string())])),
+            }
+        }
+    }
+
+    if mentions.is_empty() {
+        if output.is_json() || output.has_template() {
+            print_json_owned(json!([]), output)?;
+        } else {
+            println!("No magic words found in {}", range);
+        }
+        return Ok(());
+    }
+
+    let client = LinearClient::new()?;
+    let identifiers: Vec<String> = mentions.iter().map(|(id, _)| id.clone()).collect();
+    let issues = fetch_issues_by_identifier(&client, &identifiers).await?;
+    let by_identifier: HashMap<String, &Value> = issues
+        .iter()
+        .filter_map(|i| Some((i["identifier"].as_str()?.to_uppercase(), i)))
+        .collect();
+
+    let mut team_states: HashMap<String, Vec<Value>> = HashMap::new();
+    let mut results: Vec<Value> = Vec::new();
+
+    for (identifier, words) in &mentions {
+        let Some(issue) = by_identifier.get(identifier) else {
+            results.push(json!({ "identifier": identifier, "action": "skipped", "reason": "issue not found" }));
+            continue;
+        };
+        let team_id = issue["team"]["id"].as_str().unwrap_or("").to_string();
+        let team_key = issue["team"]["key"].as_str();
+        if !team_states.contains_key(&team_id) {
+            let states = fetch_team_states(&client, &team_id).await?;
+            team_states.insert(team_id.clone(), states);
+        }
+        let states = &team_states[&team_id];
+        let mapping = magic_words_for_team(&git, &overrides, team_key);
+
+        // The furthest target wins when several commits mention the issue
+        let mut best: Option<(&Value, &str, &str)> = None;
+        let mut unresolved = Vec::new();
+        for (word, sha) in words {
+            let Some(target_name) = mapping.get(word) else {
+                continue;
+            };
+            match resolve_target(states, target_name) {
+                Some(state) => {
+                    if best.is_none_or(|(b, _, _)| is_further(state, b)) {
+                        best = Some((state, word, sha));
+                    }
+                }
+                None => unresolved.push(target_name.clone()),
+            }
+        }
+
+        // The team's copy of the state carries its workflow position
+        let current = states
+            .iter()
+            .find(|s| s["id"] == issue["state"]["id"])
+            .unwrap_or(&issue["state"]);
+        let Some((target, word, sha)) = best else {
+            let reason = if unresolved.is_empty() {
+                "no mapping for this team".to_string()
+            } else {
+                format!("state '{}' not found", unresolved.join("', '"))
+            };
+            results.push(json!({
+                "identifier": identifier,
+                "from": current["name"],
+                "action": "skipped",
+                "reason": reason,
+            }));
+            continue;
+        };
+
+        let mut entry = json!({
+            "identifier": identifier,
+            "word": word,
+            "commit": sha,
+            "from": current["name"],
+            "to": target["name"],
+        });
+
+        if let Some(reason) = skip_reason(current, target) {
+            entry["action"] = json!("unchanged");
+            entry["reason"] = json!(reason);
+        } else if output.dry_run {
+            entry["action"] = json!("would_update");
+        } else {
+            let mutation = r#"
+                mutation($id: String!, $input: IssueUpdateInput!) {
+                    issueUpdate(id: $id, input: $input) { success }
+                }
+            "#;
+            let result = client
+                .mutate(
+                    mutation,
+                    Some(json!({ "id": issue["id"], "input": { "stateId": target["id"] } })),
+                )
+                .await?;
+            entry["action"] = if result["data"]["issueUpdate"]["success"].as_bool() == Some(true) {
+                json!("updated")
+            } else {
+                json!("failed")
+            };
+        }
+        results.push(entry);
+    }
+
+    if output.is_json() || output.has_template() {
+        if output.dry_run {
+            print_json_owned(json!({ "dry_run": true, "transitions": results }), output)?;
+        } else {
+            print_json_owned(json!(results), output)?;
+        }
+        return Ok(());
+    }
+
+    if output.dry_run {
+        println!(
+            "{}",
+            "[DRY RUN] Would apply state transitions:".yellow().bold()
+        );
+    }
+
+    let text = |v: &Value| v.as_str().unwrap_or("-").to_string();
+    let rows: Vec<TransitionRow> = results
+        .iter()
+        .map(|r| {
+            let action = r["action"].as_str().unwrap_or("");
+            let result = match action {
+                "updated" => "updated".green().to_string(),
+                "would_update" => "would update".yellow().to_string(),
+                "failed" => "failed".red().to_string(),
+                _ => format!("{} ({})", action, r["reason"].as_str().unwrap_or(""))
+                    .dimmed()
+                    .to_string(),
+            };
+            TransitionRow {
+                identifier: text(&r["identifier"]),
+                word: text(&r["word"]),
+                from: text(&r["from"]),
+                to: text(&r["to"]),
+                result,
+            }
+        })
+        .collect();
+    println!("{}", Table::new(rows));
+
+    let updated = results.iter().filter(|r| r["action"] == "updated").count();
+    if !output.dry_run {
+        println!(
+            "\n{} issues updated, {} unchanged",
+            updated,
+            results.len() - updated
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words() -> Vec<String> {
+        let mut words: Vec<String> = magic_words_for_team(&GitConfig::default(), &[], None)
+            .into_keys()
+            .collect();
+        words.sort_by_key(|w| std::cmp::Reverse(w.len()));
+        words
+    }
+
+    #[test]
+    fn test_parse_magic_words() {
+        let found = parse_magic_words(
+            "Fix login\n\nFixes LIN-123, LIN-124 and eng-5\nRefs: OPS-9\nUTF-8 handling",
+            &words(),
+        );
+        assert_eq!(
+            found,
+            vec![
+                ("fixes".to_string(), "LIN-123".to_string()),
+                ("fixes".to_string(), "LIN-124".to_string()),
+                ("fixes".to_string(), "ENG-5".to_string()),
+                ("refs".to_string(), "OPS-9".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_magic_words_url_and_no_match() {
+        let found = parse_magic_words(
+            "closes https://linear.app/acme/issue/LIN-42/fix-login-7",
+            &words(),
+        );
+        assert_eq!(found, vec![("closes".to_string(), "LIN-42".to_string())]);
+        assert!(parse_magic_words("prefix LIN-1 fixes nothing", &words()).is_empty());
+        assert!(parse_magic_words("hotfixes LIN-1", &words()).is_empty());
+    }
+
+    #[test]
+    fn test_magic_words_layering() {
+        let mut git = GitConfig::default();
+        git.magic_words.insert("fixes".into(), "Done".into());
+        git.magic_words.insert("ref".into(), "".into());
+        let mut eng = BTreeMap::new();
+        eng.insert("fixes".into(), "Deployed".into());
+        git.team_magic_words.insert("ENG".into(), eng);
+
+        let base = magic_words_for_team(&git, &[], Some("OPS"));
+        assert_eq!(base["fixes"], "Done");
+        assert!(!base.contains_key("ref"));
+        let eng = magic_words_for_team(&git, &[], Some("eng"));
+        assert_eq!(eng["fixes"], "Deployed");
+        let cli = magic_words_for_team(&git, &[("Fixes".into(), "QA".into())], Some("ENG"));
+        assert_eq!(cli["fixes"], "QA");
+    }
+
+    #[test]
+    fn test_resolve_target_and_skip_rules() {
+        let states = vec![
+            json!({ "id": "s1", "name": "Todo", "type": "unstarted", "position": 0.0 }),
+            json!({ "id": "s2", "name": "In Review", "type": "started", "position": 2.0 }),
+            json!({ "id": "s3", "name": "In Progress", "type": "started", "position": 1.0 }),
+            json!({ "id": "s4", "name": "Done", "type": "completed", "position": 3.0 }),
+            json!({ "id": "s5", "name": "Canceled", "type": "canceled", "position": 4.0 }),
+        ];
+        assert_eq!(resolve_target(&states, "in review").unwrap()["id"], "s2");
+        assert_eq!(resolve_target(&states, "started").unwrap()["id"], "s3");
+        assert!(resolve_target(&states, "Shipped").is_none());
+
+        let done = &states[3];
+        let progress = &states[2];
+        assert_eq!(skip_reason(done, done), Some("already in state"));
+        assert_eq!(skip_reason(done, progress), Some("already further along"));
+        assert_eq!(skip_reason(&states[4], done), Some("canceled"));
+        assert_eq!(skip_reason(&states[0], progress), None);
+        assert_eq!(skip_reason(progress, done), None);
+    }
+
+    #[test]
+    fn test_skip_rules_within_state_type() {
+        let progress =
+            json!({ "id": "s3", "name": "In Progress", "type": "started", "position": 1.0 });
+        let qa = json!({ "id": "s6", "name": "Ready for QA", "type": "started", "position": 2.0 });
+        assert_eq!(skip_reason(&progress, &qa), None);
+        assert_eq!(skip_reason(&qa, &progress), Some("already further along"));
+    }
+}
@@ -0,0 +1,662 @@
+use anyhow::{Context, Result};
+use clap::Subcommand;
+use colored::Colorize;
+use serde_json::{json, Value};
+use std::path::{Component, Path, PathBuf};
+use std::process::Command;
+use tabled::{Table, Tabled};
+
+use crate::api::LinearClient;
+use crate::config;
+use crate::output::{print_json_owned, OutputOptions};
+use crate::vcs::{
+    generate_branch_name, issue_id_from_branch, issue_id_from_branch_with, run_git_command,
+};
+
+use super::git::{
+    fetch_issues_by_identifier, get_issue_branch, get_vcs, run_jj_command, IssueBranch, Vcs,
+};
+
+/// Worktrees go next to the repository unless `[git] worktree_dir` says otherwise.
+const DEFAULT_WORKTREE_DIR: &str = "../{{repo}}-worktrees";
+
+#[derive(Subcommand)]
+pub enum WorktreeCommands {
+    /// List worktrees (or jj workspaces) with their issue state
+    List {
+        /// Version control system to use (auto-detected by default)
+        #[arg(long, value_enum)]
+        vcs: Option<Vcs>,
+    },
+    /// Remove worktrees whose issues are completed or canceled
+    #[command(after_help = r#"EXAMPLES:
+    linear git worktree prune --dry-run        # Show what would be removed
+    linear g worktree prune                    # Remove finished worktrees
+    linear g worktree prune --delete-branch    # Also delete their branches
+    linear g worktree prune --force            # Even with uncommitted changes
+    linear g worktree prune --all              # Include worktrees outside worktree_dir"#)]
+    Prune {
+        /// Remove worktrees with uncommitted changes too
+        #[arg(long)]
+        force: bool,
+        /// Also prune worktrees outside the configured worktree directory
+        #[arg(long)]
+        all: bool,
+        /// Also delete the issue branch (git only)
+        #[arg(long)]
+        delete_branch: bool,
+        /// Version control system to use (auto-detected by default)
+        #[arg(long, value_enum)]
+        vcs: Option<Vcs>,
+    },
+}
+
+/// One entry of `git worktree list --porcelain` (or `jj workspace list`).
+#[derive(Debug, Clone, PartialEq)]
+struct WorktreeEntry {
+    /// Worktree path (git) or workspace name (jj)
+    path: String,
+    branch: Option<String>,
+    /// The main worktree / default workspace is never pruned
+    main: bool,
+}
+
+#[derive(Tabled)]
+struct WorktreeRow {
+    #[tabled(rename = "Path")]
+    path: String,
+    #[tabled(rename = "Branch")]
+    branch: String,
+    #[tabled(rename = "Issue")]
+    issue: String,
+    #[tabled(rename = "State")]
+    state: String,
+}
+
+fn parse_worktree_list(porcelain: &str) -> Vec<WorktreeEntry> {
+    porcelain
+        .split("\n\n")
+        .filter_map(|block| {
+            let mut path = None;
+            let mut branch = None;
+            for line in block.lines() {
+                if let Some(p) = line.strip_prefix("worktree ") {
+                    path = Some(p.to_string());
+                } else if let Some(b) = line.strip_prefix("branch ") {
+                    branch = Some(b.strip_prefix("refs/heads/").unwrap_or(b).to_string());
+                }
+            }
+            path.map(|path| WorktreeEntry {
+                path,
+                branch,
+                main: false,
+            })
+        })
+        .enumerate()
+        .map(|(i, mut entry)| {
+            // git always lists the main worktree first
+            entry.main = i == 0;
+            entry
+        })
+        .collect()
+}
+
+/// `jj workspace list` prints `name: change_id commit_id description`.
+fn parse_jj_workspaces(list: &str) -> Vec<WorktreeEntry> {
+    list.lines()
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, _)| WorktreeEntry {
+            path: name.trim().to_string(),
+            branch: None,
+            main: name.trim() == "default",
+        })
+        .collect()
+}
+
+/// Directory name for an issue worktree: the generated branch name, flattened.
+fn worktree_dir_name(identifier: &str, title: &str) -> String {
+    generate_branch_name(identifier, title).replace('/', "-")
+}
+
+/// Resolve the configured worktree directory against the repository root.
+fn resolve_worktree_root(template: &str, repo_root: &Path) -> PathBuf {
+    let repo = repo_root
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| "repo".to_string());
+    let expanded = template.replace("{{repo}}", &repo);
+    let path = match expanded.strip_prefix("~/") {
+        Some(rest) => dirs::home_dir().unwrap_or_default().join(rest),
+        None => PathBuf::from(expanded),
+    };
+    let path = if path.is_absolute() {
+        path
+    } else {
+        repo_root.join(path)
+    };
+    // Fold "..", so the default reads as a sibling of the repo
+    let mut clean = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::ParentDir if clean.file_name().is_some() => {
+                clean.pop();
+            }
+            Component::CurDir => {}
+            other => clean.push(other),
+        }
+    }
+    clean
+}
+
+fn repo_root(vcs: Vcs) -> Result<PathBuf> {
+    match vcs {
+        Vcs::Git => {
+            // The main worktree's root, so worktrees made from a worktree land together
+            let common =
+                run_git_command(&["rev-parse", "--path-format=absolute", "--git-common-dir"])
+                    .context("Not in a git repository")?;
+            let common = PathBuf::from(common);
+            Ok(common.parent().map(Path::to_path_buf).unwrap_or(common))
+        }
+        Vcs::Jj => Ok(PathBuf::from(run_jj_command(&["workspace", "root"])?)),
+    }
+}
+
+fn worktree_root(vcs: Vcs, dir: Option<PathBuf>) -> Result<PathBuf> {
+    let root = repo_root(vcs)?;
+    Ok(match dir {
+        Some(dir) => resolve_worktree_root(&dir.to_string_lossy(), &root),
+        None => {
+            let template = config::git_config()?
+                .worktree_dir
+                .unwrap_or_else(|| DEFAULT_WORKTREE_DIR.to_string());
+            resolve_worktree_root(&template, &root)
+        }
+    })
+}
+
+/// The repo store a jj workspace points at: `.jj/repo` is the store itself
+/// in the default workspace and a file holding its path in the others.
+fn jj_repo_store(workspace: &Path) -> Option<PathBuf> {
+    let repo = workspace.join(".jj").join("repo");
+    if repo.is_dir() {
+        return repo.canonicalize().ok();
+    }
+    let target = PathBuf::from(std::fs::read_to_string(&repo).ok()?.trim());
+    workspace.join(".jj").join(target).canonicalize().ok()
+}
+
+/// Where a jj workspace lives. jj doesn't list workspace paths, so this is
+/// the directory `worktree create` would use, and only when it really is a
+/// workspace of this repository.
+fn jj_workspace_dir(root: &Path, name: &str, store: Option<&Path>) -> Option<PathBuf> {
+    let dir = root.join(name);
+    (store.is_some() && jj_repo_store(&dir).as_deref() == store).then_some(dir)
+}
+
+fn run_jj_in(dir: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("jj").args(args).current_dir(dir).output()?;
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("Jujutsu command failed: {}", stderr.trim());
+    }
+}
+
+fn git_worktrees() -> Result<Vec<WorktreeEntry>> {
+    Ok(parse_worktree_list(&run_git_command(&[
+        "worktree",
+        "list",
+        "--porcelain",
+    ])?))
+}
+
+fn jj_workspaces() -> Result<Vec<WorktreeEntry>> {
+    Ok(parse_jj_workspaces(&run_jj_command(&[
+        "workspace",
+        "list",
+    ])?))
+}
+
+fn git_branch_exists(branch: &str) -> bool {
+    run_git_command(&[
+        "rev-parse",
+        "--verify",
+        "--quiet",
+        &format!("refs/heads/{}", branch),
+    ])
+    .is_ok()
+}
+
+pub async fn create_worktree(
+    issue_id: &str,
+    custom_branch: Option<String>,
+    dir: Option<PathBuf>,
+    base: Option<String>,
+    vcs: Option<Vcs>,
+    output: &OutputOptions,
+) -> Result<()> {
+    let vcs = get_vcs(vcs)?;
+    let IssueBranch {
+        identifier,
+        title,
+        url,
+        branch,
+        ..
+    } = get_issue_branch(issue_id).await?;
+    let branch = custom_branch.unwrap_or(branch);
+    let name = worktree_dir_name(&identifier, &title);
+    let path = worktree_root(vcs, dir)?.join(&name);
+    let path_str = path.to_string_lossy().to_string();
+
+    let existing = match vcs {
+        Vcs::Git => git_worktrees()?
+            .into_iter()
+            .find(|w| w.branch.as_deref() == Some(branch.as_str()))
+            .map(|w| w.path),
+        Vcs::Jj => jj_workspaces()?
+            .into_iter()
+            .find(|w| w.path == name)
+            .map(|_| path_str.clone()),
+    };
+
+    let report = |created: bool, path: &str| -> Result<()> {
+        if output.is_json() || output.has_template() {
+            print_json_owned(
+                json!({
+                    "issue": identifier,
+                    "branch": branch,
+                    "path": path,
+                    "vcs": vcs.to_string(),
+                    "created": created,
+                    "dry_run": output.dry_run,
+                }),
+                output,
+            )?;
+        } else if output.dry_run {
+            println!(
+                "{} {} {} at {}",
+                "[DRY RUN] Would create worktree for".yellow().bold(),
+                identifier.cyan(),
+                format!("({})", branch).dimmed(),
+                path
+            );
+        } else {
+            let verb = if created {
+                "Created"
+            } else {
+                "Already checked out"
+            };
+            println!(
+                "{} {} {} {} at {}",
+                "+".green(),
+                verb,
+                identifier.cyan(),
+                format!("({})", branch).dimmed(),
+                path
+            );
+            println!("  cd {}", path);
+        }
+        Ok(())
+    };
+
+    if let Some(existing) = existing {
+        return report(false, &existing);
+    }
+    if output.dry_run {
+        return report(true, &path_str);
+    }
+    if path.exists() {
+        anyhow::bail!(
+            "{} already exists and is not a worktree for {}",
+            path_str,
+            branch
+        );
+    }
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+
+    match vcs {
+        Vcs::Git => {
+            if git_branch_exists(&branch) {
+                run_git_command(&["worktree", "add", &path_str, &branch])?;
+            } else {
+                let mut args = vec!["worktree", "add", "-b", &branch, &path_str];
+                if let Some(base) = base.as_deref() {
+                    args.push(base);
+                }
+                run_git_command(&args)?;
+            }
+        }
+        Vcs::Jj => {
+            let bookmark_exists = run_jj_command(&["bookmark", "list", &branch])
+                .is_ok_and(|out| out.lines().any(|l| l.starts_with(branch.as_str())));
+            let rev = if bookmark_exists {
+                branch.clone()
+            } else {
+                base.clone().unwrap_or_else(|| "@".to_string())
+            };
+            run_jj_command(&["workspace", "add", "--name", &name, "-r", &rev, &path_str])?;
+            if !bookmark_exists {
+                let description = super::git::generate_jj_description(&identifier, &title, &url);
+                run_jj_in(&path, &["describe", "-m", &description])?;
+                run_jj_in(&path, &["bookmark", "create", &branch, "-r", "@"])?;
+            }
+        }
+    }
+
+    report(true, &path_str)
+}
+
+pub async fn handle(cmd: WorktreeCommands, output: &OutputOptions) -> Result<()> {
+    match cmd {
+        WorktreeCommands::List { vcs } => list_worktrees(get_vcs(vcs)?, output).await,
+        WorktreeCommands::Prune {
+            force,
+            all,
+            delete_branch,
+            vcs,
+        } => prune_worktrees(get_vcs(vcs)?, force, all, delete_branch, output).await,
+    }
+}
+
+/// Linked worktrees paired with their issue (from the branch, else the directory name).
+async fn worktrees_with_issues(vcs: Vcs) -> Result<Vec<(WorktreeEntry, Option<Value>)>> {
+    let entries = match vcs {
+        Vcs::Git => git_worktrees()?,
+        Vcs::Jj => jj_workspaces()?,
+    };
+    let template = config::git_config()?.branch.template;
+    let issue_of = |w: &WorktreeEntry| {
+        w.branch
+            .as_deref()
+            .and_then(|b| issue_id_from_branch_with(b, template.as_deref()))
+            .or_else(|| {
+                let name = Path::new(&w.path)
+                    .file_name()?
+                    .to_string_lossy()
+                    .to_string();
+                issue_id_from_branch(&name)
+            })
+    };
+    let identifiers: Vec<String> = entries
+        .iter()
+        .filter(|w| !w.main)
+        .filter_map(issue_of)
+        .collect();
+    let issues = if identifiers.is_empty() {
+        Vec::new()
+    } else {
+        let client = LinearClient::new()?;
+        fetch_issues_by_identifier(&client, &identifiers).await?
+    };
+    Ok(entries
+        .into_iter()
+        .map(|w| {
+            let issue = if w.main {
+                None
+            } else {
+                issue_of(&w).and_then(|id| {
+                    issues
+                        .iter()
+                        .find(|i| i["identifier"].as_str() == Some(id.as_str()))
+                        .cloned()
+                })
+            };
+            (w, issue)
+        })
+        .collect())
+}
+
+async fn list_worktrees(vcs: Vcs, output: &OutputOptions) -> Result<()> {
+    let worktrees = worktrees_with_issues(vcs).await?;
+    let report: Vec<Value> = worktrees
+        .iter()
+        .map(|(w, issue)| {
+            json!({
+                "path": w.path,
+                "branch": w.branch,
+                "main": w.main,
+                "issue": issue.as_ref().map(|i| &i["identifier"]),
+                "state": issue.as_ref().map(|i| &i["state"]["name"]),
+                "state_type": issue.as_ref().map(|i| &i["state"]["type"]),
+            })
+        })
+        .collect();
+
+    if output.is_json() || output.has_template() {
+        print_json_owned(json!(report), output)?;
+        return Ok(());
+    }
+
+    let rows: Vec<WorktreeRow> = report
+        .iter()
+        .map(|w| WorktreeRow {
+            path: w["path"].as_str().unwrap_or("").to_string(),
+            branch: w["branch"].as_str().unwrap_or("-").to_string(),
+            issue: w["issue"].as_str().unwrap_or("-").to_string(),
+            state: match w["state_type"].as_str() {
+                Some("completed") | Some("canceled") => {
+                    w["state"].as_str().unwrap_or("").dimmed().to_string()
+                }
+                _ => w["state"].as_str().unwrap_or("-").to_string(),
+            },
+        })
+        .collect();
+    println!("{}", Table::new(rows));
+    Ok(())
+}
+
+async fn prune_worktrees(
+    vcs: Vcs,
+    force: bool,
+    all: bool,
+    delete_branch: bool,
+    output: &OutputOptions,
+) -> Result<()> {
+    let root = worktree_root(vcs, None)?;
+    let store = match vcs {
+        Vcs::Git => None,
+        Vcs::Jj => jj_repo_store(&repo_root(vcs)?),
+    };
+    // Directory to delete after `jj workspace forget`; None leaves the files alone
+    let jj_dir = |w: &WorktreeEntry| jj_workspace_dir(&root, &w.path, store.as_deref());
+    let in_root = |w: &WorktreeEntry| match vcs {
+        Vcs::Git => Path::new(&w.path).starts_with(&root),
+        Vcs::Jj => jj_dir(w).is_some(),
+    };
+
+    let mut outside = 0;
+    let finished: Vec<(WorktreeEntry, Value)> = worktrees_with_issues(vcs)
+        .await?
+        .into_iter()
+        .filter_map(|(w, issue)| {
+            let issue = issue?;
+            matches!(
+                issue["state"]["type"].as_str(),
+                Some("completed") | Some("canceled")
+            )
+            .then_some((w, issue))
+        })
+        .filter(|(w, _)| {
+            let keep = all || in_root(w);
+            if !keep {
+                outside += 1;
+            }
+            keep
+        })
+        .collect();
+
+    let mut results = Vec::new();
+    for (w, issue) in &finished {
+        let identifier = issue["identifier"].as_str().unwrap_or("");
+        let state = issue["state"]["name"].as_str().unwrap_or("");
+        let outcome = if output.dry_run {
+            Ok(())
+        } else {
+            match vcs {
+                Vcs::Git => {
+                    let mut args = vec!["worktree", "remove"];
+                    if force {
+                        args.push("--force");
+                    }
+                    args.push(&w.path);
+                    run_git_command(&args).map(|_| ())
+                }
+                Vcs::Jj => {
+                    // jj forgets the workspace but leaves its files behind
+                    let dir = jj_dir(w);
+                    run_jj_command(&["workspace", "forget", &w.path]).and_then(|_| {
+                        if let Some(dir) = dir {
+                            std::fs::remove_dir_all(&dir)?;
+                        }
+                        Ok(())
+                    })
+                }
+            }
+        };
+        let branch_deleted = match (&outcome, &w.branch) {
+            (Ok(()), Some(branch)) if delete_branch && !output.dry_run => {
+                let flag = if force { "-D" } else { "-d" };
+                Some(run_git_command(&["branch", flag, branch]).is_ok())
+            }
+            _ => None,
+        };
+        results.push(json!({
+            "path": w.path,
+            "branch": w.branch,
+            "issue": identifier,
+            "state": state,
+            "removed": outcome.is_ok() && !output.dry_run,
+            "branch_deleted": branch_deleted,
+            "error": outcome.err().map(|e| e.to_string()),
+        }));
+    }
+
+    if output.is_json() || output.has_template() {
+        print_json_owned(
+            json!({ "dry_run": output.dry_run, "worktrees": results }),
+            output,
+        )?;
+        return Ok(());
+    }
+
+    if outside > 0 {
+        println!(
+            "{} {} finished worktree(s) outside {} left alone (use --all)",
+            "!".yellow(),
+            outside,
+            root.display()
+        );
+    }
+    if results.is_empty() {
+        println!("No worktrees with completed or canceled issues.");
+        return Ok(());
+    }
+    for r in &results {
+        let label = format!(
+            "{} {} {}",
+            r["issue"].as_str().unwrap_or("").cyan(),
+            r["path"].as_str().unwrap_or(""),
+            format!("({})", r["state"].as_str().unwrap_or("")).dimmed()
+        );
+        if output.dry_run {
+            println!("{} {}", "[DRY RUN] Would remove".yellow().bold(), label);
+        } else if let Some(err) = r["error"].as_str() {
+            println!("{} Skipped {}: {}", "!".yellow(), label, err);
+        } else {
+            println!("{} Removed {}", "-".red(), label);
+            if r["branch_deleted"].as_bool() == Some(false) {
+                println!(
+                    "  {} Branch {} not deleted (unmerged; use --force)",
+                    "!".yellow(),
+                    r["branch"].as_str().unwrap_or("")
+                );
+            }
+        }
+    }
+    if !output.dry_run && results.iter().any(|r| !r["error"].is_null()) && !force {
+        println!("Use --force to remove worktrees with uncommitted changes.");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_worktree_list() {
+        let porcelain = "worktree /src/app\nHEAD abc\nbranch refs/heads/main\n\n\
+            worktree /src/app-worktrees/lin-1-fix\nHEAD def\nbranch refs/heads/lin-1/fix\n\n\
+            worktree /src/app-worktrees/detached\nHEAD 123\ndetached\n";
+        let entries = parse_worktree_list(porcelain);
+        assert_eq!(entries.len(), 3);
+        assert!(entries[0].main);
+        assert_eq!(entries[1].branch.as_deref(), Some("lin-1/fix"));
+        assert!(!entries[1].main);
+        assert_eq!(entries[2].branch, None);
+    }
+
+    #[test]
+    fn test_parse_jj_workspaces() {
+        let list = "default: qpvuntsm 230dd059 (empty) (no description set)\n\
+            lin-1-fix: kkmpptxz 4fd3c1a2 LIN-1: Fix";
+        let entries = parse_jj_workspaces(list);
+        assert!(entries[0].main);
+        assert_eq!(entries[1].path, "lin-1-fix");
+    }
+
+    #[test]
+    fn test_jj_workspace_dir_requires_same_repo() {
+        let tmp = std::env::temp_dir().join(format!("linear-jj-ws-{}", std::process::id()));
+        let store = tmp.join("main/.jj/repo");
+        std::fs::create_dir_all(&store).unwrap();
+        std::fs::create_dir_all(tmp.join("wt/lin-1-fix/.jj")).unwrap();
+        std::fs::write(
+            tmp.join("wt/lin-1-fix/.jj/repo"),
+            store.to_string_lossy().as_bytes(),
+        )
+        .unwrap();
+        std::fs::create_dir_all(tmp.join("wt/unrelated")).unwrap();
+
+        let store = jj_repo_store(&tmp.join("main"));
+        let root = tmp.join("wt");
+        assert!(store.is_some());
+        assert_eq!(
+            jj_workspace_dir(&root, "lin-1-fix", store.as_deref()),
+            Some(root.join("lin-1-fix"))
+        );
+        assert_eq!(jj_workspace_dir(&root, "unrelated", store.as_deref()), None);
+        assert_eq!(jj_workspace_dir(&root, "missing", store.as_deref()), None);
+        std::fs::remove_dir_all(&tmp).unwrap();
+    }
+
+    #[test]
+    fn test_worktree_dir_name() {
+        assert_eq!(
+            worktree_dir_name("LIN-12", "Fix login bug"),
+            "lin-12-fix-login-bug"
+        );
+    }
+
+    #[test]
+    fn test_resolve_worktree_root() {
+        let root = Path::new("/src/app");
+        assert_eq!(
+            resolve_worktree_root(DEFAULT_WORKTREE_DIR, root),
+            PathBuf::from("/src/app-worktrees")
+        );
+        assert_eq!(
+            resolve_worktree_root("/tmp/wt/{{repo}}", root),
+            PathBuf::from("/tmp/wt/app")
+        );
+        assert_eq!(
+            resolve_worktree_root(".worktrees", root),
+            PathBuf::from("/src/app/.worktrees")
+        );
+    }
+}
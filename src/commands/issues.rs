@@ -18,7 +18,7 @@ use crate::output::{
 use crate::pagination::{paginate_nodes, stream_nodes};
 use crate::priority::priority_to_string;
 use crate::text::truncate;
-use crate::vcs::{generate_branch_name, run_git_command};
+use crate::vcs::{issue_branch_name, run_git_command, BranchInfo};
 use crate::AgentOptions;
 
 use super::templates;
@@ -99,7 +99,11 @@ pub enum IssueCommands {
     linear i create "Feature" -t ENG -p 2      # Create with high priority
     linear i create "Task" -t ENG -a me        # Assign to yourself
     linear i create "Task" -t ENG --due +3d    # Due in 3 days
-    linear i create "Bug" -t ENG --dry-run     # Preview without creating"#)]
+    linear i create "Bug" -t ENG --dry-run     # Preview without creating
+    linear i create "Task" --project Payments  # Add to a project
+    linear i create "Test failed" --auto-assign-from-paths 'src/foo/**'
+
+Team, project and labels default to .linear.toml in this directory or a parent."#)]
     Create {
         /// Issue title
         title: String,
@@ -130,6 +134,12 @@ pub enum IssueCommands {
         /// Estimate in points (e.g., 1, 2, 3, 5, 8)
         #[arg(short, long)]
         estimate: Option<f64>,
+        /// Project name or ID
+        #[arg(long)]
+        project: Option<String>,
+        /// Assign to the CODEOWNERS owner of these paths or globs (when --assignee is not set)
+        #[arg(long, value_delimiter = ',', value_name = "PATHS")]
+        auto_assign_from_paths: Vec<String>,
         /// Template name to use for default values
         #[arg(long)]
         template: Option<String>,
@@ -198,7 +208,8 @@ pub enum IssueCommands {
     #[command(after_help = r#"EXAMPLES:
     linear issues start LIN-123                # Start working on issue
     linear i start LIN-123 --checkout          # Start and checkout git branch
-    linear i start LIN-123 -c -b feature/fix   # Start with custom branch"#)]
+    linear i start LIN-123 -c -b feature/fix   # Start with custom branch
+    linear i start LIN-123 --timer             # Also start the time tracking clock"#)]
     Start {
         /// Issue ID or identifier (e.g., "LIN-123")
         id: String,
@@ -208,17 +219,24 @@ pub enum IssueCommands {
         /// Custom branch name (optional, uses issue's branch name by default)
         #[arg(short, long)]
         branch: Option<String>,
+        /// Start a timer on the issue (see `linear time start`)
+        #[arg(long)]
+        timer: bool,
     },
     /// Stop working on an issue (return to backlog state)
     #[command(after_help = r#"EXAMPLES:
     linear issues stop LIN-123                 # Stop working on issue
-    linear i stop LIN-123 --unassign           # Stop and unassign"#)]
+    linear i stop LIN-123 --unassign           # Stop and unassign
+    linear i stop LIN-123 --timer              # Also stop the clock and log the time"#)]
     Stop {
         /// Issue ID or identifier (e.g., "LIN-123")
         id: String,
         /// Unassign the issue
         #[arg(short, long)]
         unassign: bool,
+        /// Stop the issue's running timer and log the time (see `linear time stop`)
+        #[arg(long)]
+        timer: bool,
     },
     /// Close an issue (mark as Done)
     #[command(alias = "done")]
@@ -331,10 +349,32 @@ pub async fn handle(
             labels,
             due,
             estimate,
+            project,
+            auto_assign_from_paths,
             template,
             dry_run,
         } => {
             let dry_run = dry_run || output.dry_run || agent_opts.dry_run;
+            let repo = crate::config::load_repo_config()?;
+            let assignee = match assignee {
+                None if !auto_assign_from_paths.is_empty() => {
+                    let client = LinearClient::new()?;
+                    let owner = super::git_owners::assignee_from_paths(
+                        &client,
+                        &auto_assign_from_paths,
+                        &output.cache,
+                    )
+                    .await?;
+                    if owner.is_none() {
+                        eprintln!(
+                            "{} No CODEOWNERS owner maps to a Linear user; creating unassigned",
+                            "Warning:".yellow()
+                        );
+                    }
+                    owner
+                }
+                a => a,
+            };
             // Load template if specified
             let tpl = if let Some(ref tpl_name) = template {
                 templates::get_template(tpl_name)?
@@ -350,7 +390,7 @@ pub async fn handle(
                 }
             };
 
-            // Team from CLI arg takes precedence, then template, then error
+            // Team from CLI arg takes precedence, then template, then .linear.toml, then error
             let data_json = read_json_data(data.as_deref())?;
             let data_team = data_json.as_ref().and_then(|v| {
                 v.get("team")
@@ -366,8 +406,11 @@ pub async fn handle(
                 .or(tpl.team.clone())
                 .or(data_team)
                 .or(data_team_id)
+                .or(repo.team.clone())
                 .ok_or_else(|| {
-                    anyhow::anyhow!("--team is required (or use a template with a default team)")
+                    anyhow::anyhow!(
+                        "--team is required (or use a template or .linear.toml with a default team)"
+                    )
                 })?;
 
             // Build title with optional prefix from template
@@ -394,9 +437,33 @@ pub async fn handle(
             };
             let final_priority = priority.or(tpl.default_priority);
 
-            // Merge labels: template labels + CLI labels
-            let mut final_labels = tpl.default_labels.clone();
-            final_labels.extend(labels);
+            // Repo labels and project belong to the repo's team (like `[states]`),
+            // so issues created for another team don't pick them up
+            let repo_team_applies = match repo.team.as_deref() {
+                Some(t)
+                    if !t.eq_ignore_ascii_case(&final_team)
+                        && (!repo.labels.is_empty() || repo.project.is_some()) =>
+                {
+                    let client = LinearClient::new()?;
+                    let team_id = resolve_team_id(&client, &final_team, &output.cache).await?;
+                    resolve_team_id(&client, t, &output.cache).await.ok() == Some(team_id)
+                }
+                _ => true,
+            };
+            let (repo_labels, repo_project) = if repo_team_applies {
+                (repo.labels, repo.project)
+            } else {
+                (Vec::new(), None)
+            };
+
+            // Merge labels: repo labels + template labels + CLI labels
+            let mut final_labels = repo_labels;
+            for label in tpl.default_labels.iter().cloned().chain(labels) {
+                if !final_labels.iter().any(|l| l.eq_ignore_ascii_case(&label)) {
+                    final_labels.push(label);
+                }
+            }
+            let final_project = project.or(repo_project);
 
             create_issue(
                 &final_title,
@@ -409,6 +476,7 @@ pub async fn handle(
                 final_labels,
                 due,
                 estimate,
+                final_project,
                 output,
                 agent_opts,
                 dry_run,
@@ -468,8 +536,13 @@ pub async fn handle(
             id,
             checkout,
             branch,
-        } => start_issue(&id, checkout, branch, agent_opts).await,
-        IssueCommands::Stop { id, unassign } => stop_issue(&id, unassign, agent_opts).await,
+            timer,
+        } => start_issue(&id, checkout, branch, timer, agent_opts).await,
+        IssueCommands::Stop {
+            id,
+            unassign,
+            timer,
+        } => stop_issue(&id, unassign, timer, agent_opts).await,
         IssueCommands::Close { id } => close_issue(&id).await,
         IssueCommands::Archive { id } => archive_issue(&id, true).await,
         IssueCommands::Unarchive { id } => archive_issue(&id, false).await,
@@ -1129,6 +1202,7 @@ async fn create_issue(
     labels: Vec<String>,
     due: Option<String>,
     estimate: Option<f64>,
+    project: Option<String>,
     output: &OutputOptions,
     agent_opts: AgentOptions,
     dry_run: bool,
@@ -1218,6 +1292,14 @@ async fn create_issue(
     if let Some(e) = estimate {
         input["estimate"] = json!(e);
     }
+    if let Some(ref p) = project {
+        if dry_run {
+            input["projectId"] = json!(p);
+        } else {
+            let project_id = resolve_project_id(&client, p, &output.cache).await?;
+            input["projectId"] = json!(project_id);
+        }
+    }
 
     // Dry run: show what would be created without actually creating
     if dry_run {
@@ -1236,6 +1318,7 @@ async fn create_issue(
                         "labels": labels,
                         "dueDate": due,
                         "estimate": estimate,
+                        "project": project,
                     }
                 }),
                 output,
@@ -1271,6 +1354,9 @@ async fn create_issue(
             if let Some(e) = estimate {
                 println!("  Estimate:    {}", e);
             }
+            if let Some(ref p) = project {
+                println!("  Project:     {}", p);
+            }
         }
         return Ok(());
     }
@@ -1571,10 +1657,55 @@ fn branch_exists(branch: &str) -> bool {
     run_git_command(&["rev-parse", "--verify", branch]).is_ok()
 }
 
+/// Whether the repo's `[states]` mapping covers an issue's team: it is
+/// written for the repo's own team, so in a monorepo other teams keep their
+/// defaults. Without a repo team it applies everywhere.
+fn repo_states_apply(repo_team: Option<&str>, team: &Value) -> bool {
+    repo_team.is_none_or(|t| {
+        ["key", "name", "id"]
+            .iter()
+            .any(|f| team[*f].as_str().is_some_and(|v| v.eq_ignore_ascii_case(t)))
+    })
+}
+
+/// Target state for a workflow action (start, stop, close): the state named in
+/// `.linear.toml` `[states]` when it applies to the issue's team, otherwise
+/// the first state of the first matching type.
+fn workflow_state<'a>(
+    states: &'a [Value],
+    team: &Value,
+    action: &str,
+    types: &[&str],
+) -> Result<Option<&'a Value>> {
+    let repo = crate::config::load_repo_config()?;
+    if let Some(name) = repo
+        .states
+        .get(action)
+        .filter(|_| repo_states_apply(repo.team.as_deref(), team))
+    {
+        return match states.iter().find(|s| {
+            s["name"]
+                .as_str()
+                .is_some_and(|n| n.eq_ignore_ascii_case(name))
+        }) {
+            Some(state) => Ok(Some(state)),
+            None => anyhow::bail!(
+                "State '{}' ({} in .linear.toml) not found for this team",
+                name,
+                action
+            ),
+        };
+    }
+    Ok(types
+        .iter()
+        .find_map(|t| states.iter().find(|s| s["type"].as_str() == Some(*t))))
+}
+
 async fn start_issue(
     id: &str,
     checkout: bool,
     custom_branch: Option<String>,
+    timer: bool,
     agent_opts: AgentOptions,
 ) -> Result<()> {
     let client = LinearClient::new()?;
@@ -1587,8 +1718,11 @@ async fn start_issue(
                 identifier
                 title
                 branchName
+                labels { nodes { name } }
                 team {
                     id
+                    key
+                    name
                     states {
                         nodes {
                             id
@@ -1600,6 +1734,7 @@ async fn start_issue(
             }
             viewer {
                 id
+                displayName
             }
         }
     "#;
@@ -1626,9 +1761,7 @@ async fn start_issue(
         .as_array()
         .unwrap_or(&empty);
 
-    let started_state = states
-        .iter()
-        .find(|s| s["type"].as_str() == Some("started"));
+    let started_state = workflow_state(states, &issue["team"], "start", &["started"])?;
 
     let state_id = match started_state {
         Some(s) => s["id"].as_str().unwrap_or(""),
@@ -1689,15 +1822,34 @@ async fn start_issue(
         anyhow::bail!("Failed to start issue");
     }
 
+    // The issue is already started, so a timer problem is only a warning
+    if timer {
+        if let Err(e) = super::time_timer::start_timer(identifier, None, true).await {
+            eprintln!("{} Timer not started: {}", "!".yellow(), e);
+        }
+    }
+
     // Optionally checkout a git branch
     if checkout {
-        let branch_name = custom_branch
-            .or(if linear_branch.is_empty() {
-                None
-            } else {
-                Some(linear_branch)
-            })
-            .unwrap_or_else(|| generate_branch_name(identifier, title));
+        let branch_config = crate::config::git_config()?.branch;
+        let labels: Vec<String> = issue["labels"]["nodes"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|l| l["name"].as_str().map(String::from))
+            .collect();
+        let branch_name = custom_branch.unwrap_or_else(|| {
+            issue_branch_name(
+                &branch_config,
+                &BranchInfo {
+                    identifier,
+                    title,
+                    linear_branch: &linear_branch,
+                    user: result["data"]["viewer"]["displayName"].as_str(),
+                    labels: &labels,
+                },
+            )
+        });
 
         if !agent_opts.quiet {
             println!();
@@ -1723,7 +1875,12 @@ async fn start_issue(
     Ok(())
 }
 
-async fn stop_issue(id: &str, unassign: bool, agent_opts: AgentOptions) -> Result<()> {
+async fn stop_issue(
+    id: &str,
+    unassign: bool,
+    timer: bool,
+    agent_opts: AgentOptions,
+) -> Result<()> {
     let client = LinearClient::new()?;
 
     // First, get the issue details including team info to find the "backlog" or "unstarted" state
@@ -1735,6 +1892,8 @@ async fn stop_issue(id: &str, unassign: bool, agent_opts: AgentOptions) -> Resul
                 title
                 team {
                     id
+                    key
+                    name
                     states {
                         nodes {
                             id
@@ -1761,14 +1920,7 @@ async fn stop_issue(id: &str, unassign: bool, agent_opts: AgentOptions) -> Resul
         .unwrap_or(&empty);
 
     // Prefer backlog, fall back to unstarted
-    let stop_state = states
-        .iter()
-        .find(|s| s["type"].as_str() == Some("backlog"))
-        .or_else(|| {
-            states
-                .iter()
-                .find(|s| s["type"].as_str() == Some("unstarted"))
-        });
+    let stop_state = workflow_state(states, &issue["team"], "stop", &["backlog", "unstarted"])?;
 
     let state_id = match stop_state {
         Some(s) => s["id"].as_str().unwrap_or(""),
@@ -1830,6 +1982,14 @@ async fn stop_issue(id: &str, unassign: bool, agent_opts: AgentOptions) -> Resul
                 println!("  Assignee: {}", assignee);
             }
         }
+        // The issue is already stopped, so a timer problem is only a warning
+        if timer {
+            if let Err(e) =
+                super::time_timer::stop_timer(None, None, None, false, Some(updated_id)).await
+            {
+                eprintln!("{} Timer not stopped: {}", "!".yellow(), e);
+            }
+        }
     } else {
         anyhow::bail!("Failed to stop issue");
     }
@@ -1847,6 +2007,8 @@ async fn close_issue(id: &str) -> Result<()> {
                 identifier
                 title
                 team {
+                    key
+                    name
                     states {
                         nodes {
                             id
@@ -1872,9 +2034,7 @@ async fn close_issue(id: &str) -> Result<()> {
         .unwrap_or(&empty);
 
     // Find a "completed" type state (e.g., "Done")
-    let done_state = states
-        .iter()
-        .find(|s| s["type"].as_str() == Some("completed"));
+    let done_state = workflow_state(states, &issue["team"], "close", &["completed"])?;
 
     let state_id = match done_state {
         Some(s) => s["id"].as_str().unwrap_or(""),
@@ -2144,6 +2304,15 @@ async fn transfer_issue(id: &str, team: &str) -> Result<()> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_repo_states_apply_to_repo_team_only() {
+        let team = json!({ "id": "t1", "key": "ENG", "name": "Engineering" });
+        assert!(repo_states_apply(None, &team));
+        assert!(repo_states_apply(Some("eng"), &team));
+        assert!(repo_states_apply(Some("Engineering"), &team));
+        assert!(!repo_states_apply(Some("OPS"), &team));
+    }
+
     #[test]
     fn test_format_history_state_change() {
         let entry = serde_json::json!({
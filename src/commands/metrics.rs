@@ -1,25 +1,138 @@
-use anyhow::Result;
-use clap::Subcommand;
-use serde_json::json;
+use anyhow::{Context, Result};
+use chrono::{Duration, NaiveDate};
+use clap::{Subcommand, ValueEnum};
+use colored::{Color, Colorize};
+use serde_json::{json, Value};
+use std::path::PathBuf;
 
+use super::metrics_aging::aging_metrics;
+use super::metrics_flow::{flow_metrics, FlowGroup};
+use super::metrics_forecast::forecast_metrics;
 use crate::api::{resolve_team_id, LinearClient};
+use crate::charts::{self, LineChart, Marker, Series};
 use crate::output::{print_json_owned, OutputOptions};
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ChartKind {
+    Burndown,
+    Burnup,
+    Both,
+    None,
+}
+
 #[derive(Subcommand, Debug)]
 pub enum MetricsCommands {
     /// Show cycle metrics (velocity, burndown)
+    #[command(after_help = r#"EXAMPLES:
+    linear metrics cycle CYCLE_ID                   # Totals + burndown chart
+    linear mt cycle 12 -t ENG --chart both          # Burndown and burnup
+    linear mt cycle 12 -t ENG --issues              # Chart issue counts
+    linear mt cycle 12 -t ENG --svg burndown.svg    # Export for docs
+
+Charts use estimate points, or issue counts when the cycle has no estimates.
+Scope changes are marked with ▲ (added) and ▼ (removed) below the x axis.
+Use --no-color when pasting into chat."#)]
     Cycle {
         /// Cycle ID or number
         id: String,
         /// Team key (required if using cycle number)
         #[arg(short, long)]
         team: Option<String>,
+        /// Chart to draw
+        #[arg(long, value_enum, default_value = "burndown")]
+        chart: ChartKind,
+        /// Chart issue counts instead of estimate points
+        #[arg(long)]
+        issues: bool,
+        /// Write the chart(s) as SVG to FILE ("-" for stdout, which replaces the
+        /// normal output and can't be combined with --output json)
+        #[arg(long, value_name = "FILE")]
+        svg: Option<PathBuf>,
     },
     /// Show project progress metrics
     Project {
         /// Project ID or slug
         id: String,
     },
+    /// Show flow metrics: lead time, cycle time, throughput and WIP
+    #[command(after_help = r#"EXAMPLES:
+    linear metrics flow --team ENG                  # Last 90 days
+    linear mt flow -t ENG --since -30d --by label   # Per-label breakdown
+    linear mt flow -t ENG --by assignee --output json
+
+Lead time runs from creation to completion; cycle time from the first move
+into a started state (from issue history) to completion. Percentiles are
+nearest-rank. WIP is counted at the end of each week."#)]
+    Flow {
+        /// Team key or ID (defaults to team in .linear.toml)
+        #[arg(short, long)]
+        team: Option<String>,
+        /// Start of the window (-90d, 2024-01-15, ...)
+        #[arg(long, default_value = "-90d", allow_hyphen_values = true)]
+        since: String,
+        /// Break down by label or assignee
+        #[arg(long, value_enum)]
+        by: Option<FlowGroup>,
+    },
+    /// Show open issues by time in their current state and SLA breaches
+    #[command(after_help = r#"EXAMPLES:
+    linear metrics aging --team ENG                 # Longest in state first
+    linear mt aging -t ENG --breaches               # Only SLA breaches
+    linear mt aging -t ENG --check --output json    # Exit 5 on breaches (CI)
+
+SLAs are [[sla]] rules in the config file. A rule with a state limits the
+time spent in that state (name or type); without one it limits the age of
+open issues:
+    [[sla]]
+    priority = "urgent"
+    state = "triage"
+    max = "4h"
+
+    [[sla]]
+    label = "Bug"
+    max = "7d""#)]
+    Aging {
+        /// Team key or ID (defaults to team in .linear.toml)
+        #[arg(short, long)]
+        team: Option<String>,
+        /// Only list issues breaching an SLA
+        #[arg(long)]
+        breaches: bool,
+        /// Exit with status 5 when any issue breaches an SLA (1 is left for errors)
+        #[arg(long)]
+        check: bool,
+    },
+    /// Forecast project completion with a Monte Carlo simulation
+    #[command(after_help = r#"EXAMPLES:
+    linear metrics forecast --project "Q1 Roadmap"
+    linear mt forecast --project Payments --team ENG   # Use team throughput
+    linear mt forecast --project Payments --points --weeks 8
+    linear mt forecast --project Payments --output json
+
+Each run draws a week's throughput from the last --weeks full weeks until the
+remaining work is done. Dates are p50/p85/p95 across runs; "By target" is the
+share of runs finishing by the project's (or milestone's) target date.
+Milestones are forecast in target-date order, each including earlier ones."#)]
+    Forecast {
+        /// Project name, ID or slug
+        #[arg(long)]
+        project: String,
+        /// Sample the team's throughput instead of the project's
+        #[arg(short, long)]
+        team: Option<String>,
+        /// Weeks of throughput history to sample
+        #[arg(long, default_value = "12")]
+        weeks: usize,
+        /// Number of simulation runs
+        #[arg(long, default_value = "10000")]
+        runs: usize,
+        /// Forecast estimate points instead of issue count
+        #[arg(long)]
+        points: bool,
+        /// Random seed for reproducible results
+        #[arg(long)]
+        seed: Option<u64>,
+    },
     /// Show team velocity over time
     Velocity {
         /// Team key or ID
@@ -32,13 +145,235 @@ pub enum MetricsCommands {
 
 pub async fn handle(cmd: MetricsCommands, output: &OutputOptions) -> Result<()> {
     match cmd {
-        MetricsCommands::Cycle { id, team } => cycle_metrics(&id, team, output).await,
+        MetricsCommands::Cycle {
+            id,
+            team,
+            chart,
+            issues,
+            svg,
+        } => cycle_metrics(&id, team, chart, issues, svg, output).await,
         MetricsCommands::Project { id } => project_metrics(&id, output).await,
+        MetricsCommands::Flow { team, since, by } => {
+            flow_metrics(team, &since, by, output).await
+        }
+        MetricsCommands::Aging {
+            team,
+            breaches,
+            check,
+        } => aging_metrics(team, breaches, check, output).await,
+        MetricsCommands::Forecast {
+            project,
+            team,
+            weeks,
+            runs,
+            points,
+            seed,
+        } => forecast_metrics(&project, team, weeks, runs, points, seed, output).await,
         MetricsCommands::Velocity { team, cycles } => velocity_metrics(&team, cycles, output).await,
     }
 }
 
-async fn cycle_metrics(id: &str, team: Option<String>, output: &OutputOptions) -> Result<()> {
+/// Day-by-day cycle scope and completion, in points or issues.
+pub(super) struct BurnData {
+    start: NaiveDate,
+    /// Days from start to end of the cycle, inclusive
+    days: usize,
+    pub unit: &'static str,
+    pub scope: Vec<f64>,
+    pub completed: Vec<f64>,
+}
+
+fn history(value: &Value) -> Vec<f64> {
+    value
+        .as_array()
+        .map(|a| a.iter().map(|v| v.as_f64().unwrap_or(0.0)).collect())
+        .unwrap_or_default()
+}
+
+fn date_of(value: &Value) -> Option<NaiveDate> {
+    value
+        .as_str()
+        .and_then(|s| s.get(..10))
+        .and_then(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok())
+}
+
+impl BurnData {
+    /// Build from a cycle's history arrays. Falls back to issue counts when
+    /// nothing in the cycle is estimated.
+    pub(super) fn from_cycle(cycle: &Value, issues: bool) -> Option<BurnData> {
+        let start = date_of(&cycle["startsAt"])?;
+        let end = date_of(&cycle["endsAt"])?;
+        let points = history(&cycle["scopeHistory"]);
+        let use_issues = issues || points.iter().all(|v| *v == 0.0);
+        let (scope, completed, unit) = if use_issues {
+            (
+                history(&cycle["issueCountHistory"]),
+                history(&cycle["completedIssueCountHistory"]),
+                "issues",
+            )
+        } else {
+            (points, history(&cycle["completedScopeHistory"]), "points")
+        };
+        let days = ((end - start).num_days().max(1) as usize + 1).max(scope.len());
+        Some(BurnData {
+            start,
+            days,
+            unit,
+            scope,
+            completed,
+        })
+    }
+
+    fn remaining(&self) -> Vec<f64> {
+        self.scope
+            .iter()
+            .enumerate()
+            .map(|(i, s)| (s - self.completed.get(i).copied().unwrap_or(0.0)).max(0.0))
+            .collect()
+    }
+
+    /// Straight line from `from` to `to` across the whole cycle.
+    fn ideal(&self, from: f64, to: f64) -> Vec<f64> {
+        let last = (self.days - 1).max(1) as f64;
+        (0..self.days)
+            .map(|i| from + (to - from) * i as f64 / last)
+            .collect()
+    }
+
+    /// Days on which the scope changed, with the change.
+    pub(super) fn scope_changes(&self) -> Vec<(usize, f64)> {
+        self.scope
+            .windows(2)
+            .enumerate()
+            .filter(|(_, w)| w[1] != w[0])
+            .map(|(i, w)| (i + 1, w[1] - w[0]))
+            .collect()
+    }
+
+    fn markers(&self) -> Vec<Marker> {
+        self.scope_changes()
+            .into_iter()
+            .map(|(x, delta)| Marker {
+                x,
+                glyph: if delta > 0.0 { '▲' } else { '▼' },
+                label: if delta > 0.0 {
+                    "scope added".to_string()
+                } else {
+                    "scope removed".to_string()
+                },
+            })
+            .collect()
+    }
+
+    fn x_labels(&self) -> (String, String) {
+        let end = self.start + Duration::days(self.days as i64 - 1);
+        (
+            self.start.format("%b %d").to_string(),
+            end.format("%b %d").to_string(),
+        )
+    }
+
+    fn burndown(&self, name: &str) -> LineChart {
+        let initial = self.scope.first().copied().unwrap_or(0.0);
+        LineChart {
+            title: format!("Burndown: {} ({})", name, self.unit),
+            points: self.days,
+            x_labels: self.x_labels(),
+            series: vec![
+                Series {
+                    label: "ideal".to_string(),
+                    values: self.ideal(initial, 0.0),
+                    glyph: '·',
+                    color: Color::BrightBlack,
+                    dashed: true,
+                },
+                Series {
+                    label: "remaining".to_string(),
+                    values: self.remaining(),
+                    glyph: '●',
+                    color: Color::Cyan,
+                    dashed: false,
+                },
+            ],
+            markers: self.markers(),
+        }
+    }
+
+    fn burnup(&self, name: &str) -> LineChart {
+        let final_scope = self.scope.last().copied().unwrap_or(0.0);
+        LineChart {
+            title: format!("Burnup: {} ({})", name, self.unit),
+            points: self.days,
+            x_labels: self.x_labels(),
+            series: vec![
+                Series {
+                    label: "ideal".to_string(),
+                    values: self.ideal(0.0, final_scope),
+                    glyph: '·',
+                    color: Color::BrightBlack,
+                    dashed: true,
+                },
+                Series {
+                    label: "scope".to_string(),
+                    values: self.scope.clone(),
+                    glyph: '─',
+                    color: Color::Yellow,
+                    dashed: false,
+                },
+                Series {
+                    label: "completed".to_string(),
+                    values: self.completed.clone(),
+                    glyph: '●',
+                    color: Color::Green,
+                    dashed: false,
+                },
+            ],
+            markers: self.markers(),
+        }
+    }
+
+    fn charts(&self, kind: ChartKind, name: &str) -> Vec<LineChart> {
+        match kind {
+            ChartKind::Burndown => vec![self.burndown(name)],
+            ChartKind::Burnup => vec![self.burnup(name)],
+            ChartKind::Both => vec![self.burndown(name), self.burnup(name)],
+            ChartKind::None => vec![],
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        let initial = self.scope.first().copied().unwrap_or(0.0);
+        json!({
+            "unit": self.unit,
+            "start": self.start.to_string(),
+            "days": self.days,
+            "scope": self.scope,
+            "completed": self.completed,
+            "remaining": self.remaining(),
+            "ideal_remaining": self.ideal(initial, 0.0),
+            "scope_changes": self.scope_changes().iter().map(|(day, delta)| json!({
+                "day": day,
+                "date": (self.start + Duration::days(*day as i64)).to_string(),
+                "delta": delta,
+            })).collect::<Vec<_>>(),
+        })
+    }
+}
+
+async fn cycle_metrics(
+    id: &str,
+    team: Option<String>,
+    chart: ChartKind,
+    issues_only: bool,
+    svg: Option<PathBuf>,
+    output: &OutputOptions,
+) -> Result<()> {
+    let svg_to_stdout = svg.as_ref().is_some_and(|p| p.as_os_str() == "-");
+    if svg_to_stdout && (output.is_json() || output.has_template()) {
+        anyhow::bail!(
+            "--svg - writes to stdout; write the SVG to a file to combine it with --output json"
+        );
+    }
     let client = LinearClient::new()?;
 
     // If --team is provided and id looks like a cycle number, resolve by team + number
@@ -80,6 +415,8 @@ async fn cycle_metrics(id: &str, team: Option<String>, output: &OutputOptions) -
                 progress
                 scopeHistory
                 completedScopeHistory
+                issueCountHistory
+                completedIssueCountHistory
                 issues {
                     nodes {
                         id
@@ -103,6 +440,32 @@ async fn cycle_metrics(id: &str, team: Option<String>, output: &OutputOptions) -
         anyhow::bail!("Cycle not found: {}", id);
     }
 
+    let burn = BurnData::from_cycle(cycle, issues_only);
+    let name = match cycle["name"].as_str() {
+        Some(n) if !n.is_empty() => n.to_string(),
+        _ => format!("Cycle {}", cycle["number"].as_u64().unwrap_or(0)),
+    };
+    let charts = burn
+        .as_ref()
+        .map(|b| b.charts(chart, &name))
+        .unwrap_or_default();
+
+    if let Some(ref path) = svg {
+        if charts.is_empty() {
+            anyhow::bail!("Nothing to export: the cycle has no history or --chart is none");
+        }
+        let doc = charts::to_svg(&charts);
+        if path.as_os_str() == "-" {
+            print!("{}", doc);
+            return Ok(());
+        }
+        std::fs::write(path, doc)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        if !output.is_json() {
+            eprintln!("{} Wrote {}", "+".green(), path.display());
+        }
+    }
+
     if output.is_json() {
         // Add computed metrics
         let issues = cycle["issues"]["nodes"].as_array();
@@ -135,7 +498,8 @@ async fn cycle_metrics(id: &str, team: Option<String>, output: &OutputOptions) -
                 "total_points": total_points,
                 "completed_points": completed_points,
                 "velocity": completed_points,
-            }
+            },
+            "burn": burn.as_ref().map(BurnData::to_json),
         });
         print_json_owned(metrics, output)?;
     } else {
@@ -168,14 +532,16 @@ async fn cycle_metrics(id: &str, team: Option<String>, output: &OutputOptions) -
                 .take(10)
                 .collect::<String>()
         );
+        for chart in &charts {
+            println!("\n{}", chart.render_text());
+        }
     }
 
     Ok(())
 }
 
-async fn project_metrics(id: &str, output: &OutputOptions) -> Result<()> {
-    let client = LinearClient::new()?;
-
+/// A project with the issue and milestone data progress reporting needs.
+pub(super) async fn fetch_project(client: &LinearClient, id: &str) -> Result<Value> {
     let query = r#"
         query($id: String!) {
             project(id: $id) {
@@ -185,11 +551,17 @@ async fn project_metrics(id: &str, output: &OutputOptions) -> Result<()> {
                 progress
                 targetDate
                 startDate
-                issues {
+                createdAt
+                issues(first: 250) {
                     nodes {
                         id
+                        identifier
+                        title
                         estimate
+                        createdAt
+                        completedAt
                         state { type }
+                        projectMilestone { id }
                     }
                 }
                 projectMilestones {
@@ -209,6 +581,12 @@ async fn project_metrics(id: &str, output: &OutputOptions) -> Result<()> {
     if project.is_null() {
         anyhow::bail!("Project not found: {}", id);
     }
+    Ok(project.clone())
+}
+
+async fn project_metrics(id: &str, output: &OutputOptions) -> Result<()> {
+    let client = LinearClient::new()?;
+    let project = &fetch_project(&client, id).await?;
 
     if output.is_json() {
         let issues = project["issues"]["nodes"].as_array();
@@ -337,3 +715,55 @@ async fn velocity_metrics(team: &str, cycles: usize, output: &OutputOptions) ->
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cycle() -> Value {
+        json!({
+            "startsAt": "2026-03-02T00:00:00.000Z",
+            "endsAt": "2026-03-09T00:00:00.000Z",
+            "scopeHistory": [10, 10, 13, 13, 11],
+            "completedScopeHistory": [0, 2, 4, 6, 8],
+            "issueCountHistory": [4, 4, 5, 5, 4],
+            "completedIssueCountHistory": [0, 1, 1, 2, 3],
+        })
+    }
+
+    #[test]
+    fn test_burn_data_points() {
+        let burn = BurnData::from_cycle(&cycle(), false).unwrap();
+        assert_eq!(burn.unit, "points");
+        assert_eq!(burn.days, 8);
+        assert_eq!(burn.remaining(), vec![10.0, 8.0, 9.0, 7.0, 3.0]);
+        assert_eq!(burn.scope_changes(), vec![(2, 3.0), (4, -2.0)]);
+        let ideal = burn.ideal(10.0, 0.0);
+        assert_eq!(ideal.len(), 8);
+        assert_eq!(ideal[0], 10.0);
+        assert_eq!(ideal[7], 0.0);
+        let markers = burn.markers();
+        assert_eq!(markers[0].glyph, '▲');
+        assert_eq!(markers[1].glyph, '▼');
+    }
+
+    #[test]
+    fn test_burn_data_falls_back_to_issue_counts() {
+        let mut c = cycle();
+        c["scopeHistory"] = json!([0, 0, 0]);
+        let burn = BurnData::from_cycle(&c, false).unwrap();
+        assert_eq!(burn.unit, "issues");
+        assert_eq!(burn.scope, vec![4.0, 4.0, 5.0, 5.0, 4.0]);
+        assert_eq!(BurnData::from_cycle(&cycle(), true).unwrap().unit, "issues");
+    }
+
+    #[test]
+    fn test_burn_charts() {
+        let burn = BurnData::from_cycle(&cycle(), false).unwrap();
+        assert_eq!(burn.charts(ChartKind::Both, "Sprint 1").len(), 2);
+        assert!(burn.charts(ChartKind::None, "Sprint 1").is_empty());
+        let up = burn.burnup("Sprint 1");
+        assert_eq!(up.series.len(), 3);
+        assert_eq!(up.x_labels, ("Mar 02".to_string(), "Mar 09".to_string()));
+    }
+}
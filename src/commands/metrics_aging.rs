@@ -0,0 +1,437 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use colored::Colorize;
+use serde_json::{json, Value};
+use tabled::{Table, Tabled};
+
+use super::metrics_flow::timestamp;
+use crate::api::{resolve_team_id, LinearClient};
+use crate::config::SlaRule;
+use crate::display_options;
+use crate::error::CliError;
+use crate::output::{print_json_owned, OutputOptions};
+use crate::pagination::{paginate_nodes, PaginationOptions};
+use crate::text::truncate;
+
+const PRIORITIES: [&str; 5] = ["none", "urgent", "high", "medium", "low"];
+
+/// An SLA rule with its limit parsed.
+#[derive(Debug)]
+struct Sla {
+    name: String,
+    priority: Option<i64>,
+    label: Option<String>,
+    state: Option<String>,
+    max_minutes: i64,
+}
+
+/// Parse "30m", "4h", "2d", "1w" (or combinations like "1d12h") into minutes.
+fn parse_limit(input: &str) -> Option<i64> {
+    let mut total = 0;
+    let mut num = String::new();
+    for c in input.trim().to_lowercase().chars() {
+        if c.is_ascii_digit() {
+            num.push(c);
+            continue;
+        }
+        let n: i64 = num.parse().ok()?;
+        num.clear();
+        total += n * match c {
+            'm' => 1,
+            'h' => 60,
+            'd' => 60 * 24,
+            'w' => 60 * 24 * 7,
+            _ => return None,
+        };
+    }
+    (num.is_empty() && total > 0).then_some(total)
+}
+
+impl Sla {
+    fn from_rule(rule: &SlaRule) -> Result<Sla> {
+        let max_minutes = parse_limit(&rule.max).ok_or_else(|| {
+            anyhow::anyhow!("Invalid SLA max '{}': use e.g. 4h, 2d, 1w", rule.max)
+        })?;
+        let priority = match rule.priority.as_deref() {
+            Some(p) => Some(
+                PRIORITIES
+                    .iter()
+                    .position(|name| name.eq_ignore_ascii_case(p))
+                    .ok_or_else(|| anyhow::anyhow!("Invalid SLA priority '{}'", p))?
+                    as i64,
+            ),
+            None => None,
+        };
+        let name = rule.name.clone().unwrap_or_else(|| {
+            let mut parts: Vec<&str> = Vec::new();
+            parts.extend(rule.priority.as_deref());
+            parts.extend(rule.label.as_deref());
+            let scope = if parts.is_empty() {
+                "any".to_string()
+            } else {
+                parts.join(" ")
+            };
+            match rule.state {
+                Some(ref s) => format!("{}: {} < {}", scope, s, rule.max),
+                None => format!("{}: open < {}", scope, rule.max),
+            }
+        });
+        Ok(Sla {
+            name,
+            priority,
+            label: rule.label.clone(),
+            state: rule.state.clone(),
+            max_minutes,
+        })
+    }
+
+    fn applies_to(&self, issue: &Aging) -> bool {
+        self.priority.is_none_or(|p| p == issue.priority)
+            && self
+                .label
+                .as_ref()
+                .is_none_or(|l| issue.labels.iter().any(|il| il.eq_ignore_ascii_case(l)))
+            && self.state.as_ref().is_none_or(|s| {
+                issue.state.eq_ignore_ascii_case(s) || issue.state_type.eq_ignore_ascii_case(s)
+            })
+    }
+
+    /// How long the issue has been under this rule's clock.
+    fn measured(&self, issue: &Aging) -> i64 {
+        if self.state.is_some() {
+            issue.in_state
+        } else {
+            issue.age
+        }
+    }
+}
+
+/// An open issue with the durations aging is about, in minutes.
+#[derive(Debug)]
+struct Aging {
+    identifier: String,
+    title: String,
+    url: String,
+    priority: i64,
+    state: String,
+    state_type: String,
+    labels: Vec<String>,
+    in_state: i64,
+    age: i64,
+    breaches: Vec<String>,
+}
+
+impl Aging {
+    /// Time in the current state starts at the latest transition into it,
+    /// or creation when the history has none.
+    fn from_json(issue: &Value, now: DateTime<Utc>) -> Option<Aging> {
+        let created = timestamp(&issue["createdAt"])?;
+        let state_id = issue["state"]["id"].as_str();
+        let entered = issue["history"]["nodes"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter(|h| state_id.is_some() && h["toState"]["id"].as_str() == state_id)
+            .filter_map(|h| timestamp(&h["createdAt"]))
+            .max()
+            .unwrap_or(created);
+        Some(Aging {
+            identifier: issue["identifier"].as_str().unwrap_or("").to_string(),
+            title: issue["title"].as_str().unwrap_or("").to_string(),
+            url: issue["url"].as_str().unwrap_or("").to_string(),
+            priority: issue["priority"].as_i64().unwrap_or(0),
+            state: issue["state"]["name"].as_str().unwrap_or("").to_string(),
+            state_type: issue["state"]["type"].as_str().unwrap_or("").to_string(),
+            labels: issue["labels"]["nodes"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|l| l["name"].as_str().map(String::from))
+                .collect(),
+            in_state: (now - entered).num_minutes().max(0),
+            age: (now - created).num_minutes().max(0),
+            breaches: Vec::new(),
+        })
+    }
+
+    fn check(&mut self, slas: &[Sla]) {
+        self.breaches = slas
+            .iter()
+            .filter(|s| s.applies_to(self) && s.measured(self) > s.max_minutes)
+            .map(|s| s.name.clone())
+            .collect();
+    }
+}
+
+/// "3d 4h", "5h 12m", "40m"
+fn fmt_minutes(minutes: i64) -> String {
+    let (days, hours, mins) = (minutes / 1440, minutes % 1440 / 60, minutes % 60);
+    if days > 0 {
+        format!("{}d {}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, mins)
+    } else {
+        format!("{}m", mins)
+    }
+}
+
+#[derive(Tabled)]
+struct AgingRow {
+    #[tabled(rename = "Issue")]
+    identifier: String,
+    #[tabled(rename = "Title")]
+    title: String,
+    #[tabled(rename = "Priority")]
+    priority: String,
+    #[tabled(rename = "State")]
+    state: String,
+    #[tabled(rename = "In state")]
+    in_state: String,
+    #[tabled(rename = "Age")]
+    age: String,
+    #[tabled(rename = "SLA")]
+    sla: String,
+}
+
+pub async fn aging_metrics(
+    team: Option<String>,
+    breaches_only: bool,
+    check: bool,
+    output: &OutputOptions,
+) -> Result<()> {
+    let team = crate::config::team_or_repo_default(team)?;
+    let slas: Vec<Sla> = crate::config::load_config()?
+        .sla
+        .iter()
+        .map(Sla::from_rule)
+        .collect::<Result<_>>()?;
+
+    let client = LinearClient::new()?;
+    let team_id = resolve_team_id(&client, &team, &output.cache).await?;
+    let query = r#"
+        query($filter: IssueFilter, $first: Int, $after: String, $last: Int, $before: String) {
+            issues(first: $first, after: $after, last: $last, before: $before, filter: $filter) {
+                nodes {
+                    identifier
+                    title
+                    url
+                    priority
+                    createdAt
+                    state { id name type }
+                    labels { nodes { name } }
+                    history(first: 50, orderBy: createdAt) {
+                        nodes { createdAt toState { id } }
+                    }
+                }
+                pageInfo { hasNextPage endCursor hasPreviousPage startCursor }
+            }
+        }
+    "#;
+    let mut vars = serde_json::Map::new();
+    vars.insert(
+        "filter".to_string(),
+        json!({
+            "team": { "id": { "eq": team_id } },
+            "state": { "type": { "in": ["triage", "backlog", "unstarted", "started"] } },
+        }),
+    );
+    let pagination = PaginationOptions {
+        all: true,
+        ..Default::default()
+    };
+    let nodes = paginate_nodes(
+        &client,
+        query,
+        vars,
+        &["data", "issues", "nodes"],
+        &["data", "issues", "pageInfo"],
+        &pagination,
+        50,
+    )
+    .await?;
+
+    let now = Utc::now();
+    let mut issues: Vec<Aging> = nodes
+        .iter()
+        .filter_map(|n| Aging::from_json(n, now))
+        .collect();
+    for issue in issues.iter_mut() {
+        issue.check(&slas);
+    }
+    let breaching = issues.iter().filter(|i| !i.breaches.is_empty()).count();
+    if breaches_only {
+        issues.retain(|i| !i.breaches.is_empty());
+    }
+    // Breaches first, then the longest in their current state
+    issues.sort_by(|a, b| {
+        a.breaches
+            .is_empty()
+            .cmp(&b.breaches.is_empty())
+            .then(b.in_state.cmp(&a.in_state))
+    });
+
+    if output.is_json() || output.has_template() {
+        print_json_owned(
+            json!({
+                "team": team,
+                "open": nodes.len(),
+                "breaching": breaching,
+                "slas": slas.iter().map(|s| json!({ "name": s.name, "max_minutes": s.max_minutes })).collect::<Vec<_>>(),
+                "issues": issues.iter().map(|i| json!({
+                    "identifier": i.identifier,
+                    "title": i.title,
+                    "url": i.url,
+                    "priority": PRIORITIES.get(i.priority as usize).copied().unwrap_or("none"),
+                    "state": i.state,
+                    "state_type": i.state_type,
+                    "minutes_in_state": i.in_state,
+                    "age_minutes": i.age,
+                    "breaches": i.breaches,
+                })).collect::<Vec<_>>(),
+            }),
+            output,
+        )?;
+    } else if issues.is_empty() {
+        println!(
+            "{}",
+            if breaches_only {
+                "No SLA breaches."
+            } else {
+                "No open issues."
+            }
+        );
+    } else {
+        let title_width = display_options().max_width(40);
+        let rows: Vec<AgingRow> = issues
+            .iter()
+            .map(|i| AgingRow {
+                identifier: i.identifier.clone(),
+                title: truncate(&i.title, title_width),
+                priority: PRIORITIES
+                    .get(i.priority as usize)
+                    .copied()
+                    .unwrap_or("none")
+                    .to_string(),
+                state: i.state.clone(),
+                in_state: fmt_minutes(i.in_state),
+                age: fmt_minutes(i.age),
+                sla: if i.breaches.is_empty() {
+                    "-".to_string()
+                } else {
+                    i.breaches.join(", ").red().bold().to_string()
+                },
+            })
+            .collect();
+        println!("{}", Table::new(rows));
+        if slas.is_empty() {
+            println!(
+                "{}",
+                "No SLAs configured; add [[sla]] rules to the config file.".dimmed()
+            );
+        } else if breaching > 0 {
+            println!("{} {} issue(s) breaching SLA", "!".red(), breaching);
+        } else {
+            println!("{} All {} open issues within SLA", "+".green(), nodes.len());
+        }
+    }
+
+    if check && breaching > 0 {
+        return Err(CliError::check_failed(format!("{} issue(s) breaching SLA", breaching)).into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(priority: Option<&str>, label: Option<&str>, state: Option<&str>, max: &str) -> Sla {
+        Sla::from_rule(&SlaRule {
+            name: None,
+            priority: priority.map(String::from),
+            label: label.map(String::from),
+            state: state.map(String::from),
+            max: max.to_string(),
+        })
+        .unwrap()
+    }
+
+    fn issue(json: Value) -> Aging {
+        Aging::from_json(&json, "2026-10-18T12:00:00Z".parse().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_parse_limit() {
+        assert_eq!(parse_limit("4h"), Some(240));
+        assert_eq!(parse_limit("2d"), Some(2880));
+        assert_eq!(parse_limit("1w"), Some(10080));
+        assert_eq!(parse_limit("1d12h"), Some(2160));
+        assert_eq!(parse_limit("4"), None);
+        assert_eq!(parse_limit("4x"), None);
+    }
+
+    #[test]
+    fn test_time_in_state_from_latest_transition() {
+        let i = issue(json!({
+            "createdAt": "2026-10-10T12:00:00Z",
+            "state": { "id": "s2", "name": "Triage", "type": "triage" },
+            "history": { "nodes": [
+                { "createdAt": "2026-10-11T12:00:00Z", "toState": { "id": "s2" } },
+                { "createdAt": "2026-10-12T12:00:00Z", "toState": { "id": "s1" } },
+                { "createdAt": "2026-10-18T06:00:00Z", "toState": { "id": "s2" } },
+                { "createdAt": "2026-10-18T07:00:00Z", "toState": null },
+            ]},
+        }));
+        assert_eq!(i.in_state, 6 * 60);
+        assert_eq!(i.age, 8 * 24 * 60);
+    }
+
+    #[test]
+    fn test_sla_breaches() {
+        let slas = vec![
+            rule(Some("urgent"), None, Some("triage"), "4h"),
+            rule(None, Some("Bug"), None, "7d"),
+        ];
+        let mut urgent = issue(json!({
+            "createdAt": "2026-10-18T06:00:00Z",
+            "priority": 1,
+            "state": { "id": "s", "name": "Triage", "type": "triage" },
+        }));
+        urgent.check(&slas);
+        assert_eq!(urgent.breaches, vec!["urgent: triage < 4h"]);
+
+        let mut bug = issue(json!({
+            "createdAt": "2026-10-01T00:00:00Z",
+            "priority": 3,
+            "state": { "id": "s", "name": "In Progress", "type": "started" },
+            "labels": { "nodes": [{ "name": "bug" }] },
+        }));
+        bug.check(&slas);
+        assert_eq!(bug.breaches, vec!["Bug: open < 7d"]);
+
+        let mut fine = issue(json!({
+            "createdAt": "2026-10-18T11:00:00Z",
+            "priority": 1,
+            "state": { "id": "s", "name": "Triage", "type": "triage" },
+        }));
+        fine.check(&slas);
+        assert!(fine.breaches.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_rule() {
+        let bad = SlaRule {
+            priority: Some("critical".to_string()),
+            max: "4h".to_string(),
+            ..Default::default()
+        };
+        assert!(Sla::from_rule(&bad).is_err());
+    }
+
+    #[test]
+    fn test_fmt_minutes() {
+        assert_eq!(fmt_minutes(40), "40m");
+        assert_eq!(fmt_minutes(312), "5h 12m");
+        assert_eq!(fmt_minutes(4 * 1440 + 180), "4d 3h");
+    }
+}
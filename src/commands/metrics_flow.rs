@@ -0,0 +1,570 @@
+use anyhow::Result;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use clap::ValueEnum;
+use colored::Colorize;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use tabled::{Table, Tabled};
+
+use crate::api::{resolve_team_id, LinearClient};
+use crate::output::{print_json_owned, OutputOptions};
+use crate::pagination::{paginate_nodes, PaginationOptions};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum FlowGroup {
+    Label,
+    Assignee,
+}
+
+/// The timestamps flow metrics need from one issue.
+pub(super) struct FlowIssue {
+    pub created: DateTime<Utc>,
+    /// First move into a started state
+    pub started: Option<DateTime<Utc>>,
+    pub completed: Option<DateTime<Utc>>,
+    /// Completed or canceled
+    pub finished: Option<DateTime<Utc>>,
+    pub labels: Vec<String>,
+    pub assignee: Option<String>,
+}
+
+pub(super) fn timestamp(value: &Value) -> Option<DateTime<Utc>> {
+    value
+        .as_str()
+        .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+        .map(|d| d.with_timezone(&Utc))
+}
+
+/// Earliest transition into a started state in an issue's history.
+fn started_from_history(history: &Value) -> Option<DateTime<Utc>> {
+    history
+        .as_array()?
+        .iter()
+        .filter(|h| h["toState"]["type"].as_str() == Some("started"))
+        .filter_map(|h| timestamp(&h["createdAt"]))
+        .min()
+}
+
+impl FlowIssue {
+    pub(super) fn from_json(issue: &Value) -> Option<FlowIssue> {
+        let completed = timestamp(&issue["completedAt"]);
+        Some(FlowIssue {
+            created: timestamp(&issue["createdAt"])?,
+            started: started_from_history(&issue["history"]["nodes"])
+                .or_else(|| timestamp(&issue["startedAt"])),
+            completed,
+            finished: completed.or_else(|| timestamp(&issue["canceledAt"])),
+            labels: issue["labels"]["nodes"]
+                .as_array()
+                .map(|a| {
+                    a.iter()
+                        .filter_map(|l| l["name"].as_str().map(String::from))
+                        .collect()
+                })
+                .unwrap_or_default(),
+            assignee: issue["assignee"]["name"].as_str().map(String::from),
+        })
+    }
+
+    /// Days from creation to completion.
+    fn lead_time(&self) -> Option<f64> {
+        Some(days_between(self.created, self.completed?))
+    }
+
+    /// Days from first start to completion.
+    fn cycle_time(&self) -> Option<f64> {
+        Some(days_between(self.started?, self.completed?))
+    }
+
+    fn in_progress_at(&self, at: DateTime<Utc>) -> bool {
+        self.started.is_some_and(|s| s <= at) && self.finished.is_none_or(|f| f > at)
+    }
+}
+
+fn days_between(from: DateTime<Utc>, to: DateTime<Utc>) -> f64 {
+    ((to - from).num_minutes() as f64 / (24.0 * 60.0)).max(0.0)
+}
+
+/// Nearest-rank percentile of sorted values.
+pub(super) fn percentile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+pub(super) struct Stats {
+    pub count: usize,
+    pub p50: f64,
+    pub p85: f64,
+    pub p95: f64,
+    pub mean: f64,
+}
+
+impl Stats {
+    pub(super) fn of(mut values: Vec<f64>) -> Option<Stats> {
+        if values.is_empty() {
+            return None;
+        }
+        values.sort_by(f64::total_cmp);
+        Some(Stats {
+            count: values.len(),
+            p50: percentile(&values, 50.0),
+            p85: percentile(&values, 85.0),
+            p95: percentile(&values, 95.0),
+            mean: values.iter().sum::<f64>() / values.len() as f64,
+        })
+    }
+
+    fn to_json(&self) -> Value {
+        json!({
+            "count": self.count,
+            "p50": round1(self.p50),
+            "p85": round1(self.p85),
+            "p95": round1(self.p95),
+            "mean": round1(self.mean),
+        })
+    }
+}
+
+fn round1(v: f64) -> f64 {
+    (v * 10.0).round() / 10.0
+}
+
+fn stats_json(stats: &Option<Stats>) -> Value {
+    stats.as_ref().map(Stats::to_json).unwrap_or(Value::Null)
+}
+
+/// Monday of the week containing `date`.
+pub(super) fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(date.weekday().num_days_from_monday() as i64)
+}
+
+pub(super) struct Week {
+    pub start: NaiveDate,
+    pub throughput: usize,
+    /// Issues in progress at the end of the week (or now, for this week)
+    pub wip: usize,
+}
+
+/// Weekly throughput and WIP for whole weeks, from the Monday of the week
+/// containing `since` up to `now`. `issues` must cover that Monday onwards.
+pub(super) fn weeks(issues: &[FlowIssue], since: NaiveDate, now: DateTime<Utc>) -> Vec<Week> {
+    let mut weeks = Vec::new();
+    let mut start = week_start(since);
+    while start <= now.date_naive() {
+        let end = start + Duration::days(7);
+        let end_at = end
+            .and_hms_opt(0, 0, 0)
+            .map(|d| d.and_utc())
+            .unwrap_or(now)
+            .min(now);
+        let throughput = issues
+            .iter()
+            .filter_map(|i| i.completed)
+            .filter(|c| c.date_naive() >= start && c.date_naive() < end)
+            .count();
+        let wip = issues.iter().filter(|i| i.in_progress_at(end_at)).count();
+        weeks.push(Week {
+            start,
+            throughput,
+            wip,
+        });
+        start = end;
+    }
+    weeks
+}
+
+struct Group {
+    completed: usize,
+    lead: Option<Stats>,
+    cycle: Option<Stats>,
+}
+
+/// Completed issues grouped by label (issues count once per label) or assignee.
+fn breakdown<'a>(issues: &[&'a FlowIssue], by: FlowGroup) -> Vec<(String, Group)> {
+    let mut groups: BTreeMap<String, Vec<&'a FlowIssue>> = BTreeMap::new();
+    for &issue in issues.iter().filter(|i| i.completed.is_some()) {
+        let keys = match by {
+            FlowGroup::Label if !issue.labels.is_empty() => issue.labels.clone(),
+            FlowGroup::Assignee if issue.assignee.is_some() => {
+                vec![issue.assignee.clone().unwrap_or_default()]
+            }
+            _ => vec!["(none)".to_string()],
+        };
+        for key in keys {
+            groups.entry(key).or_default().push(issue);
+        }
+    }
+    let mut rows: Vec<(String, Group)> = groups
+        .into_iter()
+        .map(|(key, members)| {
+            let group = Group {
+                completed: members.len(),
+                lead: Stats::of(members.iter().filter_map(|i| i.lead_time()).collect()),
+                cycle: Stats::of(members.iter().filter_map(|i| i.cycle_time()).collect()),
+            };
+            (key, group)
+        })
+        .collect();
+    rows.sort_by_key(|(_, g)| std::cmp::Reverse(g.completed));
+    rows
+}
+
+#[derive(Tabled)]
+struct StatsRow {
+    #[tabled(rename = "Metric")]
+    metric: String,
+    #[tabled(rename = "Issues")]
+    count: usize,
+    #[tabled(rename = "p50")]
+    p50: String,
+    #[tabled(rename = "p85")]
+    p85: String,
+    #[tabled(rename = "p95")]
+    p95: String,
+    #[tabled(rename = "Mean")]
+    mean: String,
+}
+
+#[derive(Tabled)]
+struct WeekRow {
+    #[tabled(rename = "Week of")]
+    week: String,
+    #[tabled(rename = "Done")]
+    throughput: String,
+    #[tabled(rename = "WIP")]
+    wip: usize,
+}
+
+#[derive(Tabled)]
+struct GroupRow {
+    #[tabled(rename = "Group")]
+    group: String,
+    #[tabled(rename = "Done")]
+    completed: usize,
+    #[tabled(rename = "Lead p50")]
+    lead_p50: String,
+    #[tabled(rename = "Lead p85")]
+    lead_p85: String,
+    #[tabled(rename = "Cycle p50")]
+    cycle_p50: String,
+    #[tabled(rename = "Cycle p85")]
+    cycle_p85: String,
+}
+
+fn days(v: Option<f64>) -> String {
+    v.map(|d| format!("{:.1}d", d))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn stats_row(metric: &str, stats: &Option<Stats>) -> StatsRow {
+    StatsRow {
+        metric: metric.to_string(),
+        count: stats.as_ref().map(|s| s.count).unwrap_or(0),
+        p50: days(stats.as_ref().map(|s| s.p50)),
+        p85: days(stats.as_ref().map(|s| s.p85)),
+        p95: days(stats.as_ref().map(|s| s.p95)),
+        mean: days(stats.as_ref().map(|s| s.mean)),
+    }
+}
+
+/// Issues of a team completed or canceled since `since`, plus those in
+/// progress now, with the oldest state history needed for cycle time.
+pub(super) async fn fetch_flow_issues(
+    client: &LinearClient,
+    team_id: &str,
+    since: &str,
+) -> Result<Vec<Value>> {
+    let query = r#"
+        query($filter: IssueFilter, $first: Int, $after: String, $last: Int, $before: String) {
+            issues(first: $first, after: $after, last: $last, before: $before, filter: $filter) {
+                nodes {
+                    identifier
+                    estimate
+                    createdAt
+                    startedAt
+                    completedAt
+                    canceledAt
+                    assignee { name }
+                    labels { nodes { name } }
+                    history(last: 50, orderBy: createdAt) {
+                        nodes {
+                            createdAt
+                            toState { type }
+                        }
+                    }
+                }
+                pageInfo {
+                    hasNextPage
+                    endCursor
+                    hasPreviousPage
+                    startCursor
+                }
+            }
+        }
+    "#;
+
+    let filter = json!({
+        "team": { "id": { "eq": team_id } },
+        "or": [
+            { "completedAt": { "gte": since } },
+            { "canceledAt": { "gte": since } },
+            { "state": { "type": { "eq": "started" } } },
+        ]
+    });
+    let mut vars = serde_json::Map::new();
+    vars.insert("filter".to_string(), filter);
+
+    let pagination = PaginationOptions {
+        all: true,
+        page_size: Some(50),
+        ..Default::default()
+    };
+    paginate_nodes(
+        client,
+        query,
+        vars,
+        &["data", "issues", "nodes"],
+        &["data", "issues", "pageInfo"],
+        &pagination,
+        50,
+    )
+    .await
+}
+
+pub async fn flow_metrics(
+    team: Option<String>,
+    since: &str,
+    by: Option<FlowGroup>,
+    output: &OutputOptions,
+) -> Result<()> {
+    let team = crate::config::team_or_repo_default(team)?;
+    let since_date = crate::dates::parse_due_date(since)
+        .and_then(|d| NaiveDate::parse_from_str(&d, "%Y-%m-%d").ok())
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "Invalid --since date: '{}'. Use -90d, 2024-01-15, etc.",
+                since
+            )
+        })?;
+
+    let client = LinearClient::new()?;
+    let team_id = resolve_team_id(&client, &team, &output.cache).await?;
+    // Fetch from the start of the first week so its bucket is complete
+    let fetch_from = week_start(since_date).to_string();
+    let nodes = fetch_flow_issues(&client, &team_id, &fetch_from).await?;
+    let issues: Vec<FlowIssue> = nodes.iter().filter_map(FlowIssue::from_json).collect();
+
+    let now = Utc::now();
+    let done: Vec<&FlowIssue> = issues
+        .iter()
+        .filter(|i| i.completed.is_some_and(|c| c.date_naive() >= since_date))
+        .collect();
+    let lead = Stats::of(done.iter().filter_map(|i| i.lead_time()).collect());
+    let cycle = Stats::of(done.iter().filter_map(|i| i.cycle_time()).collect());
+    let weekly = weeks(&issues, since_date, now);
+    let wip_now = issues.iter().filter(|i| i.in_progress_at(now)).count();
+    let groups = by.map(|by| breakdown(&done, by));
+
+    if output.is_json() || output.has_template() {
+        print_json_owned(
+            json!({
+                "team": team,
+                "since": since_date.to_string(),
+                "until": now.date_naive().to_string(),
+                "completed": done.len(),
+                "wip": wip_now,
+                "lead_time_days": stats_json(&lead),
+                "cycle_time_days": stats_json(&cycle),
+                "weeks": weekly.iter().map(|w| json!({
+                    "week": w.start.to_string(),
+                    "throughput": w.throughput,
+                    "wip": w.wip,
+                })).collect::<Vec<_>>(),
+                "breakdown": groups.as_ref().map(|rows| rows.iter().map(|(key, g)| json!({
+                    "group": key,
+                    "completed": g.completed,
+                    "lead_time_days": stats_json(&g.lead),
+                    "cycle_time_days": stats_json(&g.cycle),
+                })).collect::<Vec<_>>()),
+            }),
+            output,
+        )?;
+        return Ok(());
+    }
+
+    println!(
+        "{} {} ({} to {})",
+        "Flow:".bold(),
+        team,
+        since_date,
+        now.date_naive()
+    );
+    println!("Completed: {}   In progress now: {}\n", done.len(), wip_now);
+    println!(
+        "{}",
+        Table::new(vec![
+            stats_row("Lead time", &lead),
+            stats_row("Cycle time", &cycle),
+        ])
+    );
+
+    let max = weekly
+        .iter()
+        .map(|w| w.throughput)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    let rows: Vec<WeekRow> = weekly
+        .iter()
+        .map(|w| WeekRow {
+            week: w.start.to_string(),
+            throughput: format!(
+                "{:>3} {}",
+                w.throughput,
+                "█".repeat(w.throughput * 20 / max).cyan()
+            ),
+            wip: w.wip,
+        })
+        .collect();
+    println!("\n{}", Table::new(rows));
+
+    if let Some(groups) = groups {
+        let rows: Vec<GroupRow> = groups
+            .into_iter()
+            .map(|(key, g)| GroupRow {
+                group: key,
+                completed: g.completed,
+                lead_p50: days(g.lead.as_ref().map(|s| s.p50)),
+                lead_p85: days(g.lead.as_ref().map(|s| s.p85)),
+                cycle_p50: days(g.cycle.as_ref().map(|s| s.p50)),
+                cycle_p85: days(g.cycle.as_ref().map(|s| s.p85)),
+            })
+            .collect();
+        println!("\n{}", Table::new(rows));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    fn issue(created: &str, started: Option<&str>, completed: Option<&str>) -> FlowIssue {
+        FlowIssue {
+            created: at(created),
+            started: started.map(at),
+            completed: completed.map(at),
+            finished: completed.map(at),
+            labels: vec![],
+            assignee: None,
+        }
+    }
+
+    #[test]
+    fn test_percentile_nearest_rank() {
+        let values: Vec<f64> = (1..=20).map(f64::from).collect();
+        assert_eq!(percentile(&values, 50.0), 10.0);
+        assert_eq!(percentile(&values, 85.0), 17.0);
+        assert_eq!(percentile(&values, 95.0), 19.0);
+        assert_eq!(percentile(&[4.0], 95.0), 4.0);
+        assert_eq!(percentile(&[], 50.0), 0.0);
+    }
+
+    #[test]
+    fn test_started_from_history_uses_first_start() {
+        let issue = json!({
+            "createdAt": "2026-01-01T00:00:00Z",
+            "startedAt": "2026-01-09T00:00:00Z",
+            "completedAt": "2026-01-10T00:00:00Z",
+            "history": { "nodes": [
+                { "createdAt": "2026-01-09T00:00:00Z", "toState": { "type": "started" } },
+                { "createdAt": "2026-01-06T00:00:00Z", "toState": { "type": "unstarted" } },
+                { "createdAt": "2026-01-05T00:00:00Z", "toState": { "type": "started" } },
+            ]},
+            "labels": { "nodes": [{ "name": "Bug" }] },
+            "assignee": { "name": "Alice" },
+        });
+        let flow = FlowIssue::from_json(&issue).unwrap();
+        assert_eq!(flow.started, Some(at("2026-01-05T00:00:00Z")));
+        assert_eq!(flow.lead_time(), Some(9.0));
+        assert_eq!(flow.cycle_time(), Some(5.0));
+        assert_eq!(flow.labels, vec!["Bug"]);
+    }
+
+    #[test]
+    fn test_weeks_throughput_and_wip() {
+        let issues = vec![
+            // Done in week 1
+            issue(
+                "2026-01-01T00:00:00Z",
+                Some("2026-01-05T10:00:00Z"),
+                Some("2026-01-07T10:00:00Z"),
+            ),
+            // Started week 1, done week 2
+            issue(
+                "2026-01-01T00:00:00Z",
+                Some("2026-01-06T10:00:00Z"),
+                Some("2026-01-14T10:00:00Z"),
+            ),
+            // Still in progress
+            issue("2026-01-01T00:00:00Z", Some("2026-01-08T10:00:00Z"), None),
+        ];
+        let since = NaiveDate::from_ymd_opt(2026, 1, 5).unwrap();
+        let weekly = weeks(&issues, since, at("2026-01-15T12:00:00Z"));
+        assert_eq!(weekly.len(), 2);
+        assert_eq!(weekly[0].start, since);
+        assert_eq!((weekly[0].throughput, weekly[0].wip), (1, 2));
+        assert_eq!((weekly[1].throughput, weekly[1].wip), (1, 1));
+    }
+
+    #[test]
+    fn test_weeks_first_bucket_is_whole_week() {
+        let issues = vec![issue(
+            "2026-01-01T00:00:00Z",
+            Some("2026-01-05T10:00:00Z"),
+            Some("2026-01-06T10:00:00Z"),
+        )];
+        // --since on a Thursday still counts the Tuesday completion in its week
+        let since = NaiveDate::from_ymd_opt(2026, 1, 8).unwrap();
+        let weekly = weeks(&issues, since, at("2026-01-09T12:00:00Z"));
+        assert_eq!(weekly.len(), 1);
+        assert_eq!(
+            weekly[0].start,
+            NaiveDate::from_ymd_opt(2026, 1, 5).unwrap()
+        );
+        assert_eq!(weekly[0].throughput, 1);
+    }
+
+    #[test]
+    fn test_breakdown_by_label() {
+        let mut a = issue(
+            "2026-01-01T00:00:00Z",
+            Some("2026-01-02T00:00:00Z"),
+            Some("2026-01-03T00:00:00Z"),
+        );
+        a.labels = vec!["Bug".to_string(), "API".to_string()];
+        let b = issue("2026-01-01T00:00:00Z", None, Some("2026-01-05T00:00:00Z"));
+        let groups = breakdown(&[&a, &b], FlowGroup::Label);
+        let keys: Vec<&str> = groups.iter().map(|(k, _)| k.as_str()).collect();
+        assert_eq!(keys, vec!["(none)", "API", "Bug"]);
+        assert_eq!(groups[0].1.lead.as_ref().unwrap().p50, 4.0);
+        assert!(groups[0].1.cycle.is_none());
+    }
+
+    #[test]
+    fn test_week_start_is_monday() {
+        let sunday = NaiveDate::from_ymd_opt(2026, 1, 11).unwrap();
+        assert_eq!(
+            week_start(sunday),
+            NaiveDate::from_ymd_opt(2026, 1, 5).unwrap()
+        );
+    }
+}
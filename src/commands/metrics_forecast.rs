@@ -0,0 +1,460 @@
+use anyhow::Result;
+use chrono::{Duration, NaiveDate, Utc};
+use colored::Colorize;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde_json::{json, Value};
+use tabled::{Table, Tabled};
+
+use super::metrics_flow::{percentile, timestamp, week_start};
+use crate::api::{resolve_project_id, resolve_team_id, LinearClient};
+use crate::output::{print_json_owned, OutputOptions};
+use crate::pagination::{paginate_nodes, PaginationOptions};
+
+/// Give up on a run after this many weeks (ten years).
+const MAX_WEEKS: usize = 520;
+
+/// Completed work per week for the `weeks` full weeks before `today`'s week.
+fn weekly_throughput(completed: &[(NaiveDate, f64)], today: NaiveDate, weeks: usize) -> Vec<f64> {
+    let current = week_start(today);
+    (1..=weeks)
+        .rev()
+        .map(|back| {
+            let start = current - Duration::weeks(back as i64);
+            let end = start + Duration::weeks(1);
+            completed
+                .iter()
+                .filter(|(d, _)| *d >= start && *d < end)
+                .map(|(_, v)| v)
+                .sum()
+        })
+        .collect()
+}
+
+/// Monte Carlo: in each run, draw weekly throughput from `samples` until
+/// every threshold (cumulative remaining work, ascending) is reached.
+/// Returns, per threshold, the sorted weeks each run needed.
+fn simulate(samples: &[f64], thresholds: &[f64], runs: usize, rng: &mut impl Rng) -> Vec<Vec<f64>> {
+    let mut results = vec![Vec::with_capacity(runs); thresholds.len()];
+    for _ in 0..runs {
+        let mut done = 0.0;
+        let mut week = 0;
+        let mut next = 0;
+        while next < thresholds.len() {
+            while next < thresholds.len() && done >= thresholds[next] {
+                results[next].push(week as f64);
+                next += 1;
+            }
+            if next == thresholds.len() {
+                break;
+            }
+            if week >= MAX_WEEKS {
+                for r in results.iter_mut().skip(next) {
+                    r.push(MAX_WEEKS as f64);
+                }
+                break;
+            }
+            done += samples[rng.gen_range(0..samples.len())];
+            week += 1;
+        }
+    }
+    for r in &mut results {
+        r.sort_by(f64::total_cmp);
+    }
+    results
+}
+
+/// One thing being forecast: the whole project or a milestone.
+struct Target {
+    name: String,
+    target_date: Option<NaiveDate>,
+    remaining: f64,
+}
+
+struct Forecast {
+    p50: NaiveDate,
+    p85: NaiveDate,
+    p95: NaiveDate,
+    /// Share of runs finishing on or before the target date
+    on_time: Option<f64>,
+}
+
+fn forecast(weeks: &[f64], today: NaiveDate, target: Option<NaiveDate>) -> Forecast {
+    let date = |p: f64| today + Duration::weeks(percentile(weeks, p) as i64);
+    Forecast {
+        p50: date(50.0),
+        p85: date(85.0),
+        p95: date(95.0),
+        on_time: target.map(|t| {
+            let hits = weeks
+                .iter()
+                .filter(|w| today + Duration::weeks(**w as i64) <= t)
+                .count();
+            hits as f64 / weeks.len().max(1) as f64
+        }),
+    }
+}
+
+#[derive(Tabled)]
+struct ForecastRow {
+    #[tabled(rename = "Scope")]
+    name: String,
+    #[tabled(rename = "Remaining")]
+    remaining: String,
+    #[tabled(rename = "Target")]
+    target: String,
+    #[tabled(rename = "p50")]
+    p50: String,
+    #[tabled(rename = "p85")]
+    p85: String,
+    #[tabled(rename = "p95")]
+    p95: String,
+    #[tabled(rename = "By target")]
+    on_time: String,
+}
+
+fn issue_value(issue: &Value, points: bool) -> f64 {
+    if points {
+        issue["estimate"].as_f64().unwrap_or(0.0)
+    } else {
+        1.0
+    }
+}
+
+fn is_open(issue: &Value) -> bool {
+    !matches!(
+        issue["state"]["type"].as_str(),
+        Some("completed") | Some("canceled")
+    )
+}
+
+/// Completion dates (and sizes) of issues completed since `since`, for a
+/// team or a project.
+async fn completed_since(
+    client: &LinearClient,
+    filter: Value,
+    since: NaiveDate,
+    points: bool,
+) -> Result<Vec<(NaiveDate, f64)>> {
+    let query = r#"
+        query($filter: IssueFilter, $first: Int, $after: String, $last: Int, $before: String) {
+            issues(first: $first, after: $after, last: $last, before: $before, filter: $filter) {
+                nodes { completedAt estimate }
+                pageInfo { hasNextPage endCursor hasPreviousPage startCursor }
+            }
+        }
+    "#;
+    let mut filter = filter;
+    filter["completedAt"] = json!({ "gte": since.to_string() });
+    let mut vars = serde_json::Map::new();
+    vars.insert("filter".to_string(), filter);
+    let pagination = PaginationOptions {
+        all: true,
+        page_size: Some(250),
+        ..Default::default()
+    };
+    let nodes = paginate_nodes(
+        client,
+        query,
+        vars,
+        &["data", "issues", "nodes"],
+        &["data", "issues", "pageInfo"],
+        &pagination,
+        250,
+    )
+    .await?;
+    Ok(nodes
+        .iter()
+        .filter_map(|i| {
+            Some((
+                timestamp(&i["completedAt"])?.date_naive(),
+                issue_value(i, points),
+            ))
+        })
+        .collect())
+}
+
+/// Every open issue in the project, across all pages.
+async fn open_issues(client: &LinearClient, project_id: &str) -> Result<Vec<Value>> {
+    let query = r#"
+        query($filter: IssueFilter, $first: Int, $after: String, $last: Int, $before: String) {
+            issues(first: $first, after: $after, last: $last, before: $before, filter: $filter) {
+                nodes {
+                    estimate
+                    state { type }
+                    projectMilestone { id }
+                }
+                pageInfo { hasNextPage endCursor hasPreviousPage startCursor }
+            }
+        }
+    "#;
+    let mut vars = serde_json::Map::new();
+    vars.insert(
+        "filter".to_string(),
+        json!({
+            "project": { "id": { "eq": project_id } },
+            "state": { "type": { "nin": ["completed", "canceled"] } },
+        }),
+    );
+    let pagination = PaginationOptions {
+        all: true,
+        page_size: Some(250),
+        ..Default::default()
+    };
+    paginate_nodes(
+        client,
+        query,
+        vars,
+        &["data", "issues", "nodes"],
+        &["data", "issues", "pageInfo"],
+        &pagination,
+        250,
+    )
+    .await
+}
+
+fn date_of(value: &Value) -> Option<NaiveDate> {
+    value
+        .as_str()
+        .and_then(|s| NaiveDate::parse_from_str(s.get(..10)?, "%Y-%m-%d").ok())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn forecast_metrics(
+    project: &str,
+    team: Option<String>,
+    weeks: usize,
+    runs: usize,
+    points: bool,
+    seed: Option<u64>,
+    output: &OutputOptions,
+) -> Result<()> {
+    let client = LinearClient::new()?;
+    let query = r#"
+        query($id: String!) {
+            project(id: $id) {
+                id
+                name
+                targetDate
+                projectMilestones {
+                    nodes {
+                        id
+                        name
+                        targetDate
+                    }
+                }
+            }
+        }
+    "#;
+    let project_id = resolve_project_id(&client, project, &output.cache).await?;
+    let result = client
+        .query(query, Some(json!({ "id": project_id })))
+        .await?;
+    let data = &result["data"]["project"];
+    if data.is_null() {
+        anyhow::bail!("Project not found: {}", project);
+    }
+    let name = data["name"].as_str().unwrap_or(project).to_string();
+    let issues = open_issues(&client, &project_id).await?;
+    let open: Vec<&Value> = issues.iter().filter(|i| is_open(i)).collect();
+    let unestimated = open.iter().filter(|i| i["estimate"].is_null()).count();
+
+    // Throughput history: the team's if given, otherwise the project's own
+    let today = Utc::now().date_naive();
+    let since = week_start(today) - Duration::weeks(weeks as i64);
+    let (filter, source) = match team {
+        Some(ref t) => {
+            let team_id = resolve_team_id(&client, t, &output.cache).await?;
+            (
+                json!({ "team": { "id": { "eq": team_id } } }),
+                format!("team {}", t),
+            )
+        }
+        None => (
+            json!({ "project": { "id": { "eq": project_id } } }),
+            "project".to_string(),
+        ),
+    };
+    let completed = completed_since(&client, filter, since, points).await?;
+    let samples = weekly_throughput(&completed, today, weeks);
+    if samples.iter().all(|s| *s == 0.0) {
+        anyhow::bail!(
+            "No completed {} in the last {} weeks to forecast from{}",
+            if points { "points" } else { "issues" },
+            weeks,
+            if team.is_none() {
+                " (try --team to use the team's throughput)"
+            } else {
+                ""
+            }
+        );
+    }
+
+    // Milestones in target-date order, each needing everything before it
+    let mut milestones: Vec<&Value> = data["projectMilestones"]["nodes"]
+        .as_array()
+        .map(|a| a.iter().collect())
+        .unwrap_or_default();
+    milestones.sort_by_key(|m| date_of(&m["targetDate"]).unwrap_or(NaiveDate::MAX));
+    let mut targets = Vec::new();
+    let mut cumulative = 0.0;
+    for m in &milestones {
+        cumulative += open
+            .iter()
+            .filter(|i| i["projectMilestone"]["id"] == m["id"])
+            .map(|i| issue_value(i, points))
+            .sum::<f64>();
+        targets.push(Target {
+            name: format!("Milestone: {}", m["name"].as_str().unwrap_or("-")),
+            target_date: date_of(&m["targetDate"]),
+            remaining: cumulative,
+        });
+    }
+    let total: f64 = open.iter().map(|i| issue_value(i, points)).sum();
+    targets.push(Target {
+        name: name.clone(),
+        target_date: date_of(&data["targetDate"]),
+        remaining: total,
+    });
+
+    // Cumulative, so already ascending as simulate() expects
+    let thresholds: Vec<f64> = targets.iter().map(|t| t.remaining).collect();
+    let mut rng = match seed {
+        Some(s) => StdRng::seed_from_u64(s),
+        None => StdRng::from_entropy(),
+    };
+    let simulated = simulate(&samples, &thresholds, runs.max(1), &mut rng);
+    let forecasts: Vec<Forecast> = targets
+        .iter()
+        .zip(&simulated)
+        .map(|(t, weeks)| forecast(weeks, today, t.target_date))
+        .collect();
+
+    let unit = if points { "points" } else { "issues" };
+    let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+
+    if output.is_json() || output.has_template() {
+        print_json_owned(
+            json!({
+                "project": name,
+                "unit": unit,
+                "runs": runs,
+                "throughput": {
+                    "source": source,
+                    "weeks": samples,
+                    "mean": (mean * 10.0).round() / 10.0,
+                },
+                "unestimated_open_issues": if points { Some(unestimated) } else { None },
+                "forecasts": targets.iter().zip(&forecasts).map(|(t, f)| json!({
+                    "name": t.name,
+                    "remaining": t.remaining,
+                    "target_date": t.target_date.map(|d| d.to_string()),
+                    "p50": f.p50.to_string(),
+                    "p85": f.p85.to_string(),
+                    "p95": f.p95.to_string(),
+                    "probability_by_target": f.on_time.map(|p| (p * 100.0).round() / 100.0),
+                })).collect::<Vec<_>>(),
+            }),
+            output,
+        )?;
+        return Ok(());
+    }
+
+    println!("{} {}", "Forecast:".bold(), name);
+    println!(
+        "Throughput ({}, last {} weeks): {:.1} {}/week, {} runs",
+        source, weeks, mean, unit, runs
+    );
+    if points && unestimated > 0 {
+        println!(
+            "{} {} open issues have no estimate and count as 0 points",
+            "Warning:".yellow(),
+            unestimated
+        );
+    }
+    let rows: Vec<ForecastRow> = targets
+        .iter()
+        .zip(&forecasts)
+        .map(|(t, f)| ForecastRow {
+            name: t.name.clone(),
+            remaining: format!("{}", t.remaining),
+            target: t
+                .target_date
+                .map(|d| d.to_string())
+                .unwrap_or_else(|| "-".to_string()),
+            p50: f.p50.to_string(),
+            p85: f.p85.to_string(),
+            p95: f.p95.to_string(),
+            on_time: match f.on_time {
+                Some(p) => {
+                    let pct = format!("{:.0}%", p * 100.0);
+                    if p >= 0.85 {
+                        pct.green().to_string()
+                    } else if p >= 0.5 {
+                        pct.yellow().to_string()
+                    } else {
+                        pct.red().to_string()
+                    }
+                }
+                None => "-".to_string(),
+            },
+        })
+        .collect();
+    println!("\n{}", Table::new(rows));
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_weekly_throughput_full_weeks_only() {
+        let completed = vec![
+            (date("2026-01-05"), 1.0),
+            (date("2026-01-06"), 2.0),
+            (date("2026-01-13"), 1.0),
+            // Current week is excluded
+            (date("2026-01-20"), 5.0),
+        ];
+        let weeks = weekly_throughput(&completed, date("2026-01-21"), 3);
+        assert_eq!(weeks, vec![0.0, 3.0, 1.0]);
+    }
+
+    #[test]
+    fn test_simulate_constant_throughput() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let results = simulate(&[2.0], &[0.0, 3.0, 10.0], 100, &mut rng);
+        assert!(results[0].iter().all(|w| *w == 0.0));
+        assert!(results[1].iter().all(|w| *w == 2.0));
+        assert!(results[2].iter().all(|w| *w == 5.0));
+    }
+
+    #[test]
+    fn test_simulate_is_sorted_and_bounded() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let results = simulate(&[0.0, 1.0, 4.0], &[20.0], 500, &mut rng);
+        assert_eq!(results[0].len(), 500);
+        assert!(results[0].windows(2).all(|w| w[0] <= w[1]));
+        assert!(results[0][0] >= 5.0);
+
+        let stuck = simulate(&[0.0], &[1.0], 3, &mut rng);
+        assert_eq!(stuck[0], vec![MAX_WEEKS as f64; 3]);
+    }
+
+    #[test]
+    fn test_forecast_probability_by_target() {
+        let weeks = vec![1.0, 2.0, 3.0, 4.0];
+        let today = date("2026-01-01");
+        let f = forecast(&weeks, today, Some(date("2026-01-15")));
+        assert_eq!(f.p50, date("2026-01-15"));
+        assert_eq!(f.p95, date("2026-01-29"));
+        assert_eq!(f.on_time, Some(0.5));
+        assert!(forecast(&weeks, today, None).on_time.is_none());
+    }
+}
@@ -3,21 +3,37 @@ pub mod auth;
 pub mod bulk;
 pub mod cache;
 pub mod comments;
+pub mod context;
 pub mod cycles;
+pub mod cycles_plan;
+pub mod cycles_report;
 pub mod doctor;
 pub mod documents;
 pub mod export;
+#[cfg(feature = "sqlite")]
+pub mod export_sqlite;
 pub mod favorites;
 pub mod git;
+pub mod git_changelog;
+pub mod git_hooks;
+pub mod git_owners;
+pub mod git_pr;
+pub mod git_stack;
+pub mod git_sync_states;
+pub mod git_worktree;
 pub mod history;
 pub mod initiatives;
 pub mod interactive;
 pub mod issues;
 pub mod labels;
 pub mod metrics;
+pub mod metrics_aging;
+pub mod metrics_flow;
+pub mod metrics_forecast;
 pub mod milestones;
 pub mod notifications;
 pub mod projects;
+pub mod projects_updates;
 pub mod relations;
 pub mod roadmaps;
 pub mod search;
@@ -26,9 +42,12 @@ pub mod sync;
 pub mod teams;
 pub mod templates;
 pub mod time;
+pub mod time_report;
+pub mod time_timer;
 pub mod triage;
 pub mod uploads;
 pub mod users;
+pub mod users_workload;
 pub mod views;
 pub mod watch;
 pub mod webhooks;
@@ -4,6 +4,7 @@ use colored::Colorize;
 use serde_json::json;
 use tabled::{Table, Tabled};
 
+use super::projects_updates::{list_updates, update_status, Health};
 use crate::api::{resolve_project_id, resolve_team_id, LinearClient};
 use crate::cache::{Cache, CacheType};
 use crate::display_options;
@@ -115,6 +116,43 @@ pub enum ProjectCommands {
         /// Project ID or name
         id: String,
     },
+    /// Post a project update (health + body)
+    #[command(after_help = r#"EXAMPLES:
+    linear projects update-status "Q1 Roadmap" --health at-risk --body -  # Body from stdin
+    linear p update-status PROJ --health on-track -b "Beta shipped"
+    linear p update-status PROJ --draft > update.md   # Generated draft only
+    linear p update-status PROJ --health on-track --use-draft  # Post the draft as is
+
+--draft and --use-draft build the update from project data since the last
+update: issues completed, scope added, milestones reached. Edit the --draft
+output and post it with --body - to add risks and next steps."#)]
+    UpdateStatus {
+        /// Project ID or name
+        id: String,
+        /// Project health
+        #[arg(long, value_enum)]
+        health: Option<Health>,
+        /// Update body (Markdown). Use "-" to read from stdin
+        #[arg(short, long)]
+        body: Option<String>,
+        /// Print the generated draft body without posting
+        #[arg(long, conflicts_with = "body")]
+        draft: bool,
+        /// Post the generated draft as the body
+        #[arg(long, conflicts_with_all = ["body", "draft"])]
+        use_draft: bool,
+        /// Preview without posting (dry run)
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// List a project's updates, newest first
+    #[command(after_help = r#"EXAMPLES:
+    linear projects updates "Q1 Roadmap"
+    linear p updates PROJ --output json"#)]
+    Updates {
+        /// Project ID or name
+        id: String,
+    },
 }
 
 #[derive(Tabled)]
@@ -160,6 +198,18 @@ pub async fn handle(cmd: ProjectCommands, output: &OutputOptions) -> Result<()>
         ProjectCommands::Delete { id, force } => delete_project(&id, force).await,
         ProjectCommands::AddLabels { id, labels } => add_labels(&id, labels, output).await,
         ProjectCommands::Members { id } => list_project_members(&id, output).await,
+        ProjectCommands::UpdateStatus {
+            id,
+            health,
+            body,
+            draft,
+            use_draft,
+            dry_run,
+        } => {
+            let dry_run = dry_run || output.dry_run;
+            update_status(&id, health, body, draft, use_draft, dry_run, output).await
+        }
+        ProjectCommands::Updates { id } => list_updates(&id, output).await,
     }
 }
 
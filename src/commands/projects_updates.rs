@@ -0,0 +1,358 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use clap::ValueEnum;
+use colored::Colorize;
+use serde_json::{json, Value};
+use std::io::{self, BufRead};
+use tabled::{Table, Tabled};
+
+use super::metrics::fetch_project;
+use super::metrics_flow::timestamp;
+use crate::api::{resolve_project_id, LinearClient};
+use crate::display_options;
+use crate::output::{print_json_owned, OutputOptions};
+use crate::text::truncate;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Health {
+    OnTrack,
+    AtRisk,
+    OffTrack,
+}
+
+impl Health {
+    fn api_value(self) -> &'static str {
+        match self {
+            Health::OnTrack => "onTrack",
+            Health::AtRisk => "atRisk",
+            Health::OffTrack => "offTrack",
+        }
+    }
+}
+
+fn colored_health(health: &str) -> String {
+    match health {
+        "onTrack" => "On track".green().to_string(),
+        "atRisk" => "At risk".yellow().to_string(),
+        "offTrack" => "Off track".red().to_string(),
+        other => other.to_string(),
+    }
+}
+
+#[derive(Tabled)]
+struct UpdateRow {
+    #[tabled(rename = "Date")]
+    date: String,
+    #[tabled(rename = "Health")]
+    health: String,
+    #[tabled(rename = "Author")]
+    author: String,
+    #[tabled(rename = "Update")]
+    body: String,
+}
+
+/// The project's updates, newest first.
+async fn fetch_updates(client: &LinearClient, project_id: &str) -> Result<Vec<Value>> {
+    let query = r#"
+        query($id: String!) {
+            project(id: $id) {
+                projectUpdates(first: 50) {
+                    nodes {
+                        id
+                        body
+                        health
+                        createdAt
+                        url
+                        user { name }
+                    }
+                }
+            }
+        }
+    "#;
+    let result = client
+        .query(query, Some(json!({ "id": project_id })))
+        .await?;
+    let mut updates: Vec<Value> = result["data"]["project"]["projectUpdates"]["nodes"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default();
+    updates.sort_by(|a, b| b["createdAt"].as_str().cmp(&a["createdAt"].as_str()));
+    Ok(updates)
+}
+
+/// Markdown summary of progress since `since` (the last update, or the
+/// project's creation): completed issues, scope change, milestones reached.
+fn draft_body(project: &Value, since: DateTime<Utc>) -> String {
+    let issues: Vec<&Value> = project["issues"]["nodes"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .collect();
+    let done = |i: &Value| i["state"]["type"].as_str() == Some("completed");
+    let after = |field: &str, i: &Value| timestamp(&i[field]).is_some_and(|t| t > since);
+
+    let total = issues.len();
+    let completed = issues.iter().filter(|i| done(i)).count();
+    let completed_since: Vec<&Value> = issues
+        .iter()
+        .copied()
+        .filter(|i| done(i) && after("completedAt", i))
+        .collect();
+    let added = issues.iter().filter(|i| after("createdAt", i)).count();
+    let in_progress = issues
+        .iter()
+        .filter(|i| i["state"]["type"].as_str() == Some("started"))
+        .count();
+
+    // A milestone is reached when all its issues are done and the last one
+    // was completed since the previous update
+    let reached: Vec<&str> = project["projectMilestones"]["nodes"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter(|m| {
+            let own: Vec<&Value> = issues
+                .iter()
+                .copied()
+                .filter(|i| i["projectMilestone"]["id"] == m["id"])
+                .collect();
+            !own.is_empty()
+                && own.iter().all(|i| done(i))
+                && own.iter().any(|i| after("completedAt", i))
+        })
+        .filter_map(|m| m["name"].as_str())
+        .collect();
+
+    let pct = if total > 0 {
+        (completed as f64 / total as f64 * 100.0).round()
+    } else {
+        0.0
+    };
+    let mut body = format!(
+        "**Progress:** {}% ({}/{} issues completed)",
+        pct, completed, total
+    );
+    if let Some(target) = project["targetDate"].as_str() {
+        body.push_str(&format!(", target {}", target));
+    }
+    body.push_str(&format!("\n\n**Since {}:**\n", since.format("%Y-%m-%d")));
+    body.push_str(&format!("- Completed {} issue(s)\n", completed_since.len()));
+    for issue in completed_since.iter().take(10) {
+        body.push_str(&format!(
+            "  - {} {}\n",
+            issue["identifier"].as_str().unwrap_or(""),
+            issue["title"].as_str().unwrap_or("")
+        ));
+    }
+    if completed_since.len() > 10 {
+        body.push_str(&format!("  - ...and {} more\n", completed_since.len() - 10));
+    }
+    body.push_str(&format!("- Scope: +{} issue(s) added\n", added));
+    if !reached.is_empty() {
+        body.push_str(&format!("- Milestones reached: {}\n", reached.join(", ")));
+    }
+    body.push_str(&format!("- In progress: {} issue(s)\n", in_progress));
+    body
+}
+
+/// Appended to a printed draft for the author to fill in; never posted empty.
+const RISKS_PLACEHOLDER: &str = "\n**Risks / next steps:**\n- \n";
+
+fn read_stdin() -> String {
+    let lines: Vec<String> = io::stdin().lock().lines().map_while(Result::ok).collect();
+    lines.join("\n")
+}
+
+pub async fn update_status(
+    project: &str,
+    health: Option<Health>,
+    body: Option<String>,
+    draft: bool,
+    use_draft: bool,
+    dry_run: bool,
+    output: &OutputOptions,
+) -> Result<()> {
+    // Generated text is only posted when asked for explicitly
+    if body.is_none() && !draft && !use_draft {
+        anyhow::bail!(
+            "Pass --body TEXT (or - for stdin), --draft to preview the generated update, or --use-draft to post it"
+        );
+    }
+    let client = LinearClient::new()?;
+    let project_id = resolve_project_id(&client, project, &output.cache).await?;
+
+    let body = match body.as_deref() {
+        Some("-") => read_stdin(),
+        Some(text) => text.to_string(),
+        None => {
+            let data = fetch_project(&client, &project_id).await?;
+            let last = fetch_updates(&client, &project_id).await?;
+            let since = last
+                .first()
+                .and_then(|u| timestamp(&u["createdAt"]))
+                .or_else(|| timestamp(&data["createdAt"]))
+                .unwrap_or_else(Utc::now);
+            draft_body(&data, since)
+        }
+    };
+
+    if draft {
+        print!("{}{}", body, RISKS_PLACEHOLDER);
+        return Ok(());
+    }
+    if body.trim().is_empty() {
+        anyhow::bail!("Update body is empty");
+    }
+
+    let mut input = json!({ "projectId": project_id, "body": body });
+    if let Some(h) = health {
+        input["health"] = json!(h.api_value());
+    }
+
+    if dry_run {
+        if output.is_json() || output.has_template() {
+            print_json_owned(json!({ "dry_run": true, "input": input }), output)?;
+        } else {
+            println!("{}", "[DRY RUN] Would post project update:".yellow().bold());
+            if let Some(h) = health {
+                println!("  Health: {}", colored_health(h.api_value()));
+            }
+            println!();
+            println!("{}", body);
+        }
+        return Ok(());
+    }
+
+    let mutation = r#"
+        mutation($input: ProjectUpdateCreateInput!) {
+            projectUpdateCreate(input: $input) {
+                success
+                projectUpdate {
+                    id
+                    url
+                    health
+                    createdAt
+                    project { name }
+                }
+            }
+        }
+    "#;
+    let result = client
+        .mutate(mutation, Some(json!({ "input": input })))
+        .await?;
+
+    if result["data"]["projectUpdateCreate"]["success"].as_bool() == Some(true) {
+        let update = &result["data"]["projectUpdateCreate"]["projectUpdate"];
+        if output.is_json() || output.has_template() {
+            print_json_owned(update.clone(), output)?;
+            return Ok(());
+        }
+        println!(
+            "{} Posted update on {} ({})",
+            "+".green(),
+            update["project"]["name"].as_str().unwrap_or(project).cyan(),
+            colored_health(update["health"].as_str().unwrap_or("-"))
+        );
+        if let Some(url) = update["url"].as_str() {
+            println!("  {}", url.dimmed());
+        }
+    } else {
+        anyhow::bail!("Failed to post project update");
+    }
+    Ok(())
+}
+
+pub async fn list_updates(project: &str, output: &OutputOptions) -> Result<()> {
+    let client = LinearClient::new()?;
+    let project_id = resolve_project_id(&client, project, &output.cache).await?;
+    let updates = fetch_updates(&client, &project_id).await?;
+
+    if output.is_json() || output.has_template() {
+        print_json_owned(Value::Array(updates), output)?;
+        return Ok(());
+    }
+    if updates.is_empty() {
+        println!("No updates posted for this project.");
+        return Ok(());
+    }
+
+    let body_width = display_options().max_width(60);
+    let rows: Vec<UpdateRow> = updates
+        .iter()
+        .map(|u| UpdateRow {
+            date: u["createdAt"]
+                .as_str()
+                .unwrap_or("")
+                .chars()
+                .take(10)
+                .collect(),
+            health: colored_health(u["health"].as_str().unwrap_or("-")),
+            author: u["user"]["name"].as_str().unwrap_or("-").to_string(),
+            body: truncate(
+                u["body"]
+                    .as_str()
+                    .unwrap_or("")
+                    .lines()
+                    .find(|l| !l.trim().is_empty())
+                    .unwrap_or(""),
+                body_width,
+            ),
+        })
+        .collect();
+    println!("{}", Table::new(rows));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project() -> Value {
+        json!({
+            "targetDate": "2026-12-01",
+            "issues": { "nodes": [
+                { "identifier": "P-1", "title": "Schema", "state": { "type": "completed" },
+                  "createdAt": "2026-09-01T00:00:00Z", "completedAt": "2026-09-20T00:00:00Z",
+                  "projectMilestone": { "id": "m1" } },
+                { "identifier": "P-2", "title": "API", "state": { "type": "completed" },
+                  "createdAt": "2026-09-01T00:00:00Z", "completedAt": "2026-10-14T00:00:00Z",
+                  "projectMilestone": { "id": "m1" } },
+                { "identifier": "P-3", "title": "UI", "state": { "type": "started" },
+                  "createdAt": "2026-10-12T00:00:00Z", "projectMilestone": { "id": "m2" } },
+                { "identifier": "P-4", "title": "Docs", "state": { "type": "unstarted" },
+                  "createdAt": "2026-09-01T00:00:00Z", "projectMilestone": null },
+            ]},
+            "projectMilestones": { "nodes": [
+                { "id": "m1", "name": "Alpha" },
+                { "id": "m2", "name": "Beta" },
+            ]},
+        })
+    }
+
+    #[test]
+    fn test_draft_body_since_last_update() {
+        let body = draft_body(&project(), "2026-10-11T00:00:00Z".parse().unwrap());
+        assert!(body.starts_with("**Progress:** 50% (2/4 issues completed), target 2026-12-01"));
+        assert!(body.contains("**Since 2026-10-11:**"));
+        assert!(body.contains("- Completed 1 issue(s)\n  - P-2 API\n"));
+        assert!(body.contains("- Scope: +1 issue(s) added"));
+        assert!(body.contains("- Milestones reached: Alpha"));
+        assert!(body.contains("- In progress: 1 issue(s)"));
+    }
+
+    #[test]
+    fn test_draft_body_milestone_reached_earlier() {
+        let body = draft_body(&project(), "2026-10-15T00:00:00Z".parse().unwrap());
+        assert!(body.contains("- Completed 0 issue(s)"));
+        assert!(!body.contains("Milestones reached"));
+    }
+
+    #[test]
+    fn test_health_api_value() {
+        assert_eq!(Health::AtRisk.api_value(), "atRisk");
+        assert_eq!(
+            Health::from_str("off-track", true).unwrap(),
+            Health::OffTrack
+        );
+    }
+}
@@ -1,25 +1,38 @@
 use anyhow::Result;
 use clap::Subcommand;
-use serde_json::json;
+use colored::Colorize;
+use serde_json::{json, Value};
 use tabled::{Table, Tabled};
 
 use crate::api::LinearClient;
+use crate::dates::is_later;
 use crate::display_options;
 use crate::output::{
     ensure_non_empty, filter_values, print_json_owned, sort_values, OutputOptions,
 };
-use crate::pagination::paginate_nodes;
-use crate::text::truncate;
+use crate::pagination::{paginate_nodes, PaginationOptions};
+use crate::search_index::{IndexedIssue, SearchIndex};
+use crate::text::{snippet, truncate};
 
 #[derive(Subcommand)]
 pub enum SearchCommands {
     /// Search issues by query string
+    #[command(after_help = r#"OFFLINE QUERIES (--offline):
+    linear search issues --offline "login crash"       # All words must match
+    linear search issues --offline '"login page"'      # Phrase query
+    linear search issues --offline "title:login label:bug"
+
+Field prefixes: title:, label:, description:, comment:, id:
+Build the index first with: linear search index --team ENG"#)]
     Issues {
         /// Search query string
         query: String,
         /// Include archived issues
         #[arg(short, long)]
         archived: bool,
+        /// Search the local index instead of the API (archived issues are not indexed)
+        #[arg(long, conflicts_with = "archived")]
+        offline: bool,
     },
     /// Search projects by query string
     Projects {
@@ -29,6 +42,53 @@ pub enum SearchCommands {
         #[arg(short, long)]
         archived: bool,
     },
+    /// Search documents by title and content
+    #[command(alias = "docs")]
+    Documents {
+        /// Search query string
+        query: String,
+        /// Include archived documents
+        #[arg(short, long)]
+        archived: bool,
+    },
+    /// Search issue comments by body text
+    Comments {
+        /// Search query string
+        query: String,
+    },
+    /// Search initiatives by name
+    Initiatives {
+        /// Search query string
+        query: String,
+    },
+    /// Search issues, projects, documents, comments and initiatives at once
+    #[command(after_help = r#"EXAMPLES:
+    linear search all "auth"                # Merged results, newest first
+    linear s all "auth" --output json       # Each result has a "kind" field
+    linear s all "auth" --limit 10          # Cap the merged result count"#)]
+    All {
+        /// Search query string
+        query: String,
+        /// Include archived issues, projects and documents
+        #[arg(short, long)]
+        archived: bool,
+    },
+    /// Build or refresh the local search index used by `search issues --offline`
+    #[command(after_help = r#"EXAMPLES:
+    linear search index --team ENG          # Index (or refresh) ENG issues
+    linear search index                     # Index all teams
+    linear search index -t ENG --full       # Re-fetch everything
+
+Only issues updated since the previous run are fetched. The index lives in
+the cache directory and includes titles, descriptions, labels and comments."#)]
+    Index {
+        /// Team key to index (default: all teams)
+        #[arg(short, long)]
+        team: Option<String>,
+        /// Ignore the stored checkpoint and re-fetch all issues
+        #[arg(long)]
+        full: bool,
+    },
 }
 
 #[derive(Tabled)]
@@ -57,12 +117,98 @@ struct ProjectRow {
     id: String,
 }
 
+#[derive(Tabled)]
+struct DocumentRow {
+    #[tabled(rename = "Title")]
+    title: String,
+    #[tabled(rename = "Project")]
+    project: String,
+    #[tabled(rename = "Match")]
+    snippet: String,
+    #[tabled(rename = "ID")]
+    id: String,
+}
+
+#[derive(Tabled)]
+struct CommentRow {
+    #[tabled(rename = "Issue")]
+    issue: String,
+    #[tabled(rename = "Author")]
+    author: String,
+    #[tabled(rename = "Match")]
+    snippet: String,
+    #[tabled(rename = "ID")]
+    id: String,
+}
+
+#[derive(Tabled)]
+struct InitiativeRow {
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Status")]
+    status: String,
+    #[tabled(rename = "Match")]
+    snippet: String,
+    #[tabled(rename = "ID")]
+    id: String,
+}
+
+#[derive(Tabled)]
+struct ResultRow {
+    #[tabled(rename = "Kind")]
+    kind: String,
+    #[tabled(rename = "Title")]
+    title: String,
+    #[tabled(rename = "Match")]
+    snippet: String,
+    #[tabled(rename = "Updated")]
+    updated: String,
+}
+
+/// Characters of context shown on each side of a match
+const SNIPPET_RADIUS: usize = 30;
+
+/// Snippet for JSON output: matches wrapped in `**` (markdown bold)
+fn json_snippet(text: &str, query: &str) -> Option<String> {
+    snippet(text, query, SNIPPET_RADIUS, |m| format!("**{}**", m))
+}
+
+/// Snippet for table output: matches highlighted in color
+fn table_snippet(text: &str, query: &str) -> String {
+    snippet(text, query, SNIPPET_RADIUS, |m| m.yellow().bold().to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn priority_label(priority: Option<i64>) -> String {
+    match priority {
+        Some(1) => "Urgent".to_string(),
+        Some(2) => "High".to_string(),
+        Some(3) => "Normal".to_string(),
+        Some(4) => "Low".to_string(),
+        _ => "-".to_string(),
+    }
+}
+
 pub async fn handle(cmd: SearchCommands, output: &OutputOptions) -> Result<()> {
     match cmd {
-        SearchCommands::Issues { query, archived } => search_issues(&query, archived, output).await,
+        SearchCommands::Issues {
+            query,
+            offline: true,
+            ..
+        } => search_issues_offline(&query, output),
+        SearchCommands::Issues {
+            query, archived, ..
+        } => search_issues(&query, archived, output).await,
         SearchCommands::Projects { query, archived } => {
             search_projects(&query, archived, output).await
         }
+        SearchCommands::Documents { query, archived } => {
+            search_documents(&query, archived, output).await
+        }
+        SearchCommands::Comments { query } => search_comments(&query, output).await,
+        SearchCommands::Initiatives { query } => search_initiatives(&query, output).await,
+        SearchCommands::All { query, archived } => search_all(&query, archived, output).await,
+        SearchCommands::Index { team, full } => build_index(team, full, output).await,
     }
 }
 
@@ -132,23 +278,12 @@ async fn search_issues(query: &str, include_archived: bool, output: &OutputOptio
     let width = display_options().max_width(50);
     let rows: Vec<IssueRow> = issues
         .iter()
-        .map(|issue| {
-            let priority = match issue["priority"].as_i64() {
-                Some(0) => "-".to_string(),
-                Some(1) => "Urgent".to_string(),
-                Some(2) => "High".to_string(),
-                Some(3) => "Normal".to_string(),
-                Some(4) => "Low".to_string(),
-                _ => "-".to_string(),
-            };
-
-            IssueRow {
-                identifier: issue["identifier"].as_str().unwrap_or("").to_string(),
-                title: truncate(issue["title"].as_str().unwrap_or(""), width),
-                state: issue["state"]["name"].as_str().unwrap_or("-").to_string(),
-                priority,
-                id: issue["id"].as_str().unwrap_or("").to_string(),
-            }
+        .map(|issue| IssueRow {
+            identifier: issue["identifier"].as_str().unwrap_or("").to_string(),
+            title: truncate(issue["title"].as_str().unwrap_or(""), width),
+            state: issue["state"]["name"].as_str().unwrap_or("-").to_string(),
+            priority: priority_label(issue["priority"].as_i64()),
+            id: issue["id"].as_str().unwrap_or("").to_string(),
         })
         .collect();
 
@@ -253,3 +388,633 @@ async fn search_projects(
 
     Ok(())
 }
+
+async fn fetch_documents(
+    client: &LinearClient,
+    query: &str,
+    include_archived: bool,
+    output: &OutputOptions,
+) -> Result<Vec<Value>> {
+    let graphql_query = r#"
+        query($term: String!, $first: Int, $after: String, $last: Int, $before: String, $includeArchived: Boolean) {
+            searchDocuments(term: $term, first: $first, after: $after, last: $last, before: $before, includeArchived: $includeArchived) {
+                nodes {
+                    id
+                    title
+                    content
+                    url
+                    updatedAt
+                    project { name }
+                    creator { name }
+                }
+                pageInfo {
+                    hasNextPage
+                    endCursor
+                    hasPreviousPage
+                    startCursor
+                }
+            }
+        }
+    "#;
+
+    let mut variables = serde_json::Map::new();
+    variables.insert("term".to_string(), json!(query));
+    variables.insert("includeArchived".to_string(), json!(include_archived));
+
+    let pagination = output.pagination.with_default_limit(50);
+    paginate_nodes(
+        client,
+        graphql_query,
+        variables,
+        &["data", "searchDocuments", "nodes"],
+        &["data", "searchDocuments", "pageInfo"],
+        &pagination,
+        50,
+    )
+    .await
+}
+
+async fn fetch_comments(
+    client: &LinearClient,
+    query: &str,
+    output: &OutputOptions,
+) -> Result<Vec<Value>> {
+    let graphql_query = r#"
+        query($first: Int, $after: String, $last: Int, $before: String, $filter: CommentFilter) {
+            comments(first: $first, after: $after, last: $last, before: $before, filter: $filter) {
+                nodes {
+                    id
+                    body
+                    url
+                    updatedAt
+                    user { name }
+                    issue { identifier title }
+                }
+                pageInfo {
+                    hasNextPage
+                    endCursor
+                    hasPreviousPage
+                    startCursor
+                }
+            }
+        }
+    "#;
+
+    let mut variables = serde_json::Map::new();
+    variables.insert(
+        "filter".to_string(),
+        json!({ "body": { "containsIgnoreCase": query } }),
+    );
+
+    let pagination = output.pagination.with_default_limit(50);
+    paginate_nodes(
+        client,
+        graphql_query,
+        variables,
+        &["data", "comments", "nodes"],
+        &["data", "comments", "pageInfo"],
+        &pagination,
+        50,
+    )
+    .await
+}
+
+async fn fetch_initiatives(
+    client: &LinearClient,
+    query: &str,
+    output: &OutputOptions,
+) -> Result<Vec<Value>> {
+    let graphql_query = r#"
+        query($first: Int, $after: String, $last: Int, $before: String, $filter: InitiativeFilter) {
+            initiatives(first: $first, after: $after, last: $last, before: $before, filter: $filter) {
+                nodes {
+                    id
+                    name
+                    description
+                    status
+                    url
+                    updatedAt
+                }
+                pageInfo {
+                    hasNextPage
+                    endCursor
+                    hasPreviousPage
+                    startCursor
+                }
+            }
+        }
+    "#;
+
+    let mut variables = serde_json::Map::new();
+    variables.insert(
+        "filter".to_string(),
+        json!({ "name": { "containsIgnoreCase": query } }),
+    );
+
+    let pagination = output.pagination.with_default_limit(50);
+    paginate_nodes(
+        client,
+        graphql_query,
+        variables,
+        &["data", "initiatives", "nodes"],
+        &["data", "initiatives", "pageInfo"],
+        &pagination,
+        50,
+    )
+    .await
+}
+
+/// Add a `snippet` field built from `text_key` (falling back to `title_key`)
+fn with_snippet(mut node: Value, query: &str, text_key: &str, title_key: &str) -> Value {
+    let text = node[text_key].as_str().unwrap_or("");
+    let found = json_snippet(text, query)
+        .or_else(|| json_snippet(node[title_key].as_str().unwrap_or(""), query));
+    node["snippet"] = json!(found);
+    node
+}
+
+fn print_results_json(items: Vec<Value>, output: &OutputOptions) -> Result<()> {
+    print_json_owned(json!(items), output)
+}
+
+async fn search_documents(
+    query: &str,
+    include_archived: bool,
+    output: &OutputOptions,
+) -> Result<()> {
+    let client = LinearClient::new()?;
+    let mut documents = fetch_documents(&client, query, include_archived, output).await?;
+
+    if output.is_json() || output.has_template() {
+        let items = documents
+            .into_iter()
+            .map(|d| with_snippet(d, query, "content", "title"))
+            .collect();
+        return print_results_json(items, output);
+    }
+
+    filter_values(&mut documents, &output.filters);
+    if let Some(sort_key) = output.json.sort.as_deref() {
+        sort_values(&mut documents, sort_key, output.json.order);
+    }
+
+    ensure_non_empty(&documents, output)?;
+    if documents.is_empty() {
+        println!("No documents found matching: {}", query);
+        return Ok(());
+    }
+
+    let width = display_options().max_width(40);
+    let rows: Vec<DocumentRow> = documents
+        .iter()
+        .map(|d| DocumentRow {
+            title: truncate(d["title"].as_str().unwrap_or(""), width),
+            project: d["project"]["name"].as_str().unwrap_or("-").to_string(),
+            snippet: table_snippet(d["content"].as_str().unwrap_or(""), query),
+            id: d["id"].as_str().unwrap_or("").to_string(),
+        })
+        .collect();
+
+    println!("{}", Table::new(rows));
+    println!("\n{} documents found", documents.len());
+
+    Ok(())
+}
+
+async fn search_comments(query: &str, output: &OutputOptions) -> Result<()> {
+    let client = LinearClient::new()?;
+    let mut comments = fetch_comments(&client, query, output).await?;
+
+    if output.is_json() || output.has_template() {
+        let items = comments
+            .into_iter()
+            .map(|c| with_snippet(c, query, "body", "body"))
+            .collect();
+        return print_results_json(items, output);
+    }
+
+    filter_values(&mut comments, &output.filters);
+    if let Some(sort_key) = output.json.sort.as_deref() {
+        sort_values(&mut comments, sort_key, output.json.order);
+    }
+
+    ensure_non_empty(&comments, output)?;
+    if comments.is_empty() {
+        println!("No comments found matching: {}", query);
+        return Ok(());
+    }
+
+    let rows: Vec<CommentRow> = comments
+        .iter()
+        .map(|c| CommentRow {
+            issue: c["issue"]["identifier"].as_str().unwrap_or("-").to_string(),
+            author: c["user"]["name"].as_str().unwrap_or("-").to_string(),
+            snippet: table_snippet(c["body"].as_str().unwrap_or(""), query),
+            id: c["id"].as_str().unwrap_or("").to_string(),
+        })
+        .collect();
+
+    println!("{}", Table::new(rows));
+    println!("\n{} comments found", comments.len());
+
+    Ok(())
+}
+
+async fn search_initiatives(query: &str, output: &OutputOptions) -> Result<()> {
+    let client = LinearClient::new()?;
+    let mut initiatives = fetch_initiatives(&client, query, output).await?;
+
+    if output.is_json() || output.has_template() {
+        let items = initiatives
+            .into_iter()
+            .map(|i| with_snippet(i, query, "name", "description"))
+            .collect();
+        return print_results_json(items, output);
+    }
+
+    filter_values(&mut initiatives, &output.filters);
+    if let Some(sort_key) = output.json.sort.as_deref() {
+        sort_values(&mut initiatives, sort_key, output.json.order);
+    }
+
+    ensure_non_empty(&initiatives, output)?;
+    if initiatives.is_empty() {
+        println!("No initiatives found matching: {}", query);
+        return Ok(());
+    }
+
+    let rows: Vec<InitiativeRow> = initiatives
+        .iter()
+        .map(|i| InitiativeRow {
+            name: i["name"].as_str().unwrap_or("").to_string(),
+            status: i["status"].as_str().unwrap_or("-").to_string(),
+            snippet: table_snippet(i["name"].as_str().unwrap_or(""), query),
+            id: i["id"].as_str().unwrap_or("").to_string(),
+        })
+        .collect();
+
+    println!("{}", Table::new(rows));
+    println!("\n{} initiatives found", initiatives.len());
+
+    Ok(())
+}
+
+async fn search_all(query: &str, include_archived: bool, output: &OutputOptions) -> Result<()> {
+    let client = LinearClient::new()?;
+
+    let issues_query = r#"
+        query($first: Int, $after: String, $last: Int, $before: String, $includeArchived: Boolean, $filter: IssueFilter) {
+            issues(first: $first, after: $after, last: $last, before: $before, includeArchived: $includeArchived, filter: $filter) {
+                nodes { id identifier title description url updatedAt }
+                pageInfo { hasNextPage endCursor hasPreviousPage startCursor }
+            }
+        }
+    "#;
+    let mut issue_vars = serde_json::Map::new();
+    issue_vars.insert("includeArchived".to_string(), json!(include_archived));
+    issue_vars.insert(
+        "filter".to_string(),
+        json!({
+            "or": [
+                { "title": { "containsIgnoreCase": query } },
+                { "description": { "containsIgnoreCase": query } }
+            ]
+        }),
+    );
+
+    let projects_query = r#"
+        query($first: Int, $after: String, $last: Int, $before: String, $includeArchived: Boolean, $filter: ProjectFilter) {
+            projects(first: $first, after: $after, last: $last, before: $before, includeArchived: $includeArchived, filter: $filter) {
+                nodes { id name description url updatedAt }
+                pageInfo { hasNextPage endCursor hasPreviousPage startCursor }
+            }
+        }
+    "#;
+    let mut project_vars = serde_json::Map::new();
+    project_vars.insert("includeArchived".to_string(), json!(include_archived));
+    project_vars.insert(
+        "filter".to_string(),
+        json!({ "name": { "containsIgnoreCase": query } }),
+    );
+
+    let pagination = output.pagination.with_default_limit(50);
+    let (issues, projects, documents, comments, initiatives) = futures::try_join!(
+        paginate_nodes(
+            &client,
+            issues_query,
+            issue_vars,
+            &["data", "issues", "nodes"],
+            &["data", "issues", "pageInfo"],
+            &pagination,
+            50,
+        ),
+        paginate_nodes(
+            &client,
+            projects_query,
+            project_vars,
+            &["data", "projects", "nodes"],
+            &["data", "projects", "pageInfo"],
+            &pagination,
+            50,
+        ),
+        fetch_documents(&client, query, include_archived, output),
+        fetch_comments(&client, query, output),
+        fetch_initiatives(&client, query, output),
+    )?;
+
+    // Table snippets are highlighted from the source text, keyed by kind and ID
+    let mut table_snippets: std::collections::HashMap<String, String> =
+        std::collections::HashMap::new();
+    let mut result = |kind: &str, node: &Value, title: String, text_key: &str| {
+        let text = node[text_key].as_str().unwrap_or("");
+        let id = node["id"].as_str().unwrap_or("");
+        let highlighted = if json_snippet(text, query).is_some() {
+            table_snippet(text, query)
+        } else {
+            table_snippet(&title, query)
+        };
+        table_snippets.insert(format!("{}:{}", kind, id), highlighted);
+        json!({
+            "kind": kind,
+            "id": id,
+            "title": title,
+            "url": node["url"],
+            "updatedAt": node["updatedAt"],
+            "snippet": json_snippet(text, query).or_else(|| json_snippet(&title, query)),
+        })
+    };
+
+    let mut results: Vec<Value> = Vec::new();
+    for i in &issues {
+        let title = format!(
+            "{} {}",
+            i["identifier"].as_str().unwrap_or(""),
+            i["title"].as_str().unwrap_or("")
+        );
+        results.push(result("issue", i, title, "description"));
+    }
+    for p in &projects {
+        let title = p["name"].as_str().unwrap_or("").to_string();
+        results.push(result("project", p, title, "description"));
+    }
+    for d in &documents {
+        let title = d["title"].as_str().unwrap_or("").to_string();
+        results.push(result("document", d, title, "content"));
+    }
+    for c in &comments {
+        let title = format!(
+            "Comment on {}",
+            c["issue"]["identifier"].as_str().unwrap_or("?")
+        );
+        results.push(result("comment", c, title, "body"));
+    }
+    for i in &initiatives {
+        let title = i["name"].as_str().unwrap_or("").to_string();
+        results.push(result("initiative", i, title, "description"));
+    }
+
+    // Newest first across kinds, then cap to the requested limit
+    sort_values(&mut results, "updatedAt", crate::output::SortOrder::Desc);
+    if let Some(limit) = pagination.limit {
+        results.truncate(limit);
+    }
+
+    if output.is_json() || output.has_template() {
+        return print_results_json(results, output);
+    }
+
+    filter_values(&mut results, &output.filters);
+    if let Some(sort_key) = output.json.sort.as_deref() {
+        sort_values(&mut results, sort_key, output.json.order);
+    }
+
+    ensure_non_empty(&results, output)?;
+    if results.is_empty() {
+        println!("Nothing found matching: {}", query);
+        return Ok(());
+    }
+
+    let width = display_options().max_width(40);
+    let rows: Vec<ResultRow> = results
+        .iter()
+        .map(|r| {
+            let key = format!(
+                "{}:{}",
+                r["kind"].as_str().unwrap_or(""),
+                r["id"].as_str().unwrap_or("")
+            );
+            let snippet = table_snippets
+                .get(&key)
+                .cloned()
+                .unwrap_or_else(|| "-".to_string());
+            ResultRow {
+                kind: r["kind"].as_str().unwrap_or("").to_string(),
+                title: truncate(r["title"].as_str().unwrap_or(""), width),
+                snippet,
+                updated: r["updatedAt"]
+                    .as_str()
+                    .map(|s| s.chars().take(10).collect())
+                    .unwrap_or_default(),
+            }
+        })
+        .collect();
+
+    println!("{}", Table::new(rows));
+    println!("\n{} results found", results.len());
+
+    Ok(())
+}
+
+async fn build_index(team: Option<String>, full: bool, output: &OutputOptions) -> Result<()> {
+    let client = LinearClient::new()?;
+    let mut index = SearchIndex::load()?;
+    let scope = team.clone().unwrap_or_else(|| "*".to_string());
+    if full {
+        index.clear_checkpoint(&scope);
+    }
+
+    let graphql_query = r#"
+        query($first: Int, $after: String, $filter: IssueFilter) {
+            issues(first: $first, after: $after, filter: $filter, includeArchived: true, orderBy: updatedAt) {
+                nodes {
+                    id
+                    identifier
+                    title
+                    description
+                    priority
+                    url
+                    updatedAt
+                    archivedAt
+                    state { name }
+                    team { key }
+                    labels { nodes { name } }
+                    comments(first: 50) { nodes { body } }
+                }
+                pageInfo {
+                    hasNextPage
+                    endCursor
+                }
+            }
+        }
+    "#;
+
+    let mut filter = json!({});
+    if let Some(ref t) = team {
+        filter["team"] = json!({ "key": { "eq": t } });
+    }
+    if let Some(checkpoint) = index.checkpoint(&scope) {
+        filter["updatedAt"] = json!({ "gt": checkpoint });
+    }
+    let mut variables = serde_json::Map::new();
+    variables.insert("filter".to_string(), filter);
+
+    // Comments are nested per issue, so keep pages small
+    let pagination = PaginationOptions {
+        all: true,
+        page_size: Some(50),
+        ..Default::default()
+    };
+    let issues = paginate_nodes(
+        &client,
+        graphql_query,
+        variables,
+        &["data", "issues", "nodes"],
+        &["data", "issues", "pageInfo"],
+        &pagination,
+        50,
+    )
+    .await?;
+
+    let mut removed = 0;
+    let mut latest = index.checkpoint(&scope).map(|s| s.to_string());
+    for issue in &issues {
+        let id = issue["id"].as_str().unwrap_or("");
+        let updated_at = issue["updatedAt"].as_str().unwrap_or("");
+        if is_later(updated_at, latest.as_deref()) {
+            latest = Some(updated_at.to_string());
+        }
+        if issue["archivedAt"].is_string() {
+            index.remove(id);
+            removed += 1;
+            continue;
+        }
+        let names = |path: &str, key: &str| -> Vec<String> {
+            issue[path]["nodes"]
+                .as_array()
+                .map(|a| {
+                    a.iter()
+                        .filter_map(|n| n[key].as_str().map(|s| s.to_string()))
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        index.upsert(IndexedIssue {
+            id: id.to_string(),
+            identifier: issue["identifier"].as_str().unwrap_or("").to_string(),
+            title: issue["title"].as_str().unwrap_or("").to_string(),
+            description: issue["description"].as_str().unwrap_or("").to_string(),
+            labels: names("labels", "name"),
+            comments: names("comments", "body"),
+            state: issue["state"]["name"].as_str().unwrap_or("").to_string(),
+            team: issue["team"]["key"].as_str().unwrap_or("").to_string(),
+            priority: issue["priority"].as_i64().unwrap_or(0),
+            url: issue["url"].as_str().unwrap_or("").to_string(),
+            updated_at: updated_at.to_string(),
+        });
+    }
+
+    index.rebuild();
+    if let Some(latest) = latest {
+        index.set_checkpoint(&scope, &latest);
+    }
+    index.save()?;
+
+    if output.is_json() {
+        print_json_owned(
+            json!({
+                "scope": scope,
+                "fetched": issues.len(),
+                "removed": removed,
+                "indexed": index.len(),
+                "checkpoint": index.checkpoint(&scope),
+                "path": SearchIndex::path()?.display().to_string(),
+            }),
+            output,
+        )?;
+    } else {
+        println!(
+            "Indexed {} updated issues ({} removed); {} issues in local index",
+            issues.len() - removed,
+            removed,
+            index.len()
+        );
+    }
+
+    Ok(())
+}
+
+fn search_issues_offline(query: &str, output: &OutputOptions) -> Result<()> {
+    let index = SearchIndex::load()?;
+    if index.is_empty() {
+        anyhow::bail!("Local search index is empty. Run: linear search index --team <TEAM>");
+    }
+
+    let limit = if output.pagination.all {
+        usize::MAX
+    } else {
+        output.pagination.limit.unwrap_or(50)
+    };
+    let mut issues: Vec<serde_json::Value> = index
+        .search(query)
+        .into_iter()
+        .take(limit)
+        .map(|hit| {
+            json!({
+                "id": hit.issue.id,
+                "identifier": hit.issue.identifier,
+                "title": hit.issue.title,
+                "priority": hit.issue.priority,
+                "state": { "name": hit.issue.state },
+                "team": { "key": hit.issue.team },
+                "labels": hit.issue.labels,
+                "url": hit.issue.url,
+                "updatedAt": hit.issue.updated_at,
+                "score": (hit.score * 1000.0).round() / 1000.0,
+            })
+        })
+        .collect();
+
+    if output.is_json() || output.has_template() {
+        print_json_owned(json!(issues), output)?;
+        return Ok(());
+    }
+
+    filter_values(&mut issues, &output.filters);
+    if let Some(sort_key) = output.json.sort.as_deref() {
+        sort_values(&mut issues, sort_key, output.json.order);
+    }
+
+    ensure_non_empty(&issues, output)?;
+    if issues.is_empty() {
+        println!("No indexed issues found matching: {}", query);
+        return Ok(());
+    }
+
+    let width = display_options().max_width(50);
+    let rows: Vec<IssueRow> = issues
+        .iter()
+        .map(|issue| IssueRow {
+            identifier: issue["identifier"].as_str().unwrap_or("").to_string(),
+            title: truncate(issue["title"].as_str().unwrap_or(""), width),
+            state: issue["state"]["name"].as_str().unwrap_or("-").to_string(),
+            priority: priority_label(issue["priority"].as_i64()),
+            id: issue["id"].as_str().unwrap_or("").to_string(),
+        })
+        .collect();
+
+    let table = Table::new(rows).to_string();
+    println!("{}", table);
+    println!("\n{} issues found (offline)", issues.len());
+
+    Ok(())
+}
@@ -43,9 +43,9 @@ pub enum SyncCommands {
         /// Directory to scan for local projects (default: ~/code)
         #[arg(short, long)]
         directory: Option<String>,
-        /// Team name or ID to create projects in
+        /// Team name or ID to create projects in (default: team from .linear.toml)
         #[arg(short, long)]
-        team: String,
+        team: Option<String>,
         /// Only push specific folders (comma-separated)
         #[arg(short, long)]
         only: Option<String>,
@@ -100,6 +100,7 @@ pub async fn handle(cmd: SyncCommands, output: &OutputOptions) -> Result<()> {
             dry_run,
         } => {
             let dry_run = dry_run || output.dry_run;
+            let team = crate::config::team_or_repo_default(team)?;
             push_command(directory, team, only, dry_run, &output.cache).await
         }
     }
@@ -13,13 +13,17 @@ use crate::pagination::paginate_nodes;
 use crate::text::truncate;
 use crate::types::TimeEntry;
 
+use super::time_report::GroupBy;
+use super::time_timer::RoundMode;
+
 #[derive(Subcommand)]
 pub enum TimeCommands {
     /// Log time spent on an issue
     #[command(after_help = r#"EXAMPLES:
     linear time log LIN-123 2h                 # Log 2 hours
     linear time log LIN-123 30m                # Log 30 minutes
-    linear time log LIN-123 1h30m              # Log 1.5 hours"#)]
+    linear time log LIN-123 1h30m              # Log 1.5 hours
+    linear time log LIN-123 3h --date yesterday # Log on a past day"#)]
     Log {
         /// Issue ID or identifier (e.g., "LIN-123")
         issue: String,
@@ -28,6 +32,9 @@ pub enum TimeCommands {
         /// Optional description of work done
         #[arg(short, long)]
         description: Option<String>,
+        /// Day the work was done (e.g., "yesterday", "2026-10-01", "-2d")
+        #[arg(long, allow_hyphen_values = true)]
+        date: Option<String>,
     },
     /// List time entries
     #[command(after_help = r#"EXAMPLES:
@@ -39,6 +46,91 @@ pub enum TimeCommands {
         #[arg(short, long)]
         issue: Option<String>,
     },
+    /// Update a time entry
+    #[command(after_help = r#"EXAMPLES:
+    linear time update ENTRY_ID --duration 1h45m
+    linear time update ENTRY_ID --description "Code review"
+    linear time update ENTRY_ID --date 2026-10-01"#)]
+    Update {
+        /// Time entry ID
+        id: String,
+        /// New duration (e.g., "2h", "30m", "1h30m")
+        #[arg(long)]
+        duration: Option<String>,
+        /// New description
+        #[arg(short, long)]
+        description: Option<String>,
+        /// New day the work was done
+        #[arg(long, allow_hyphen_values = true)]
+        date: Option<String>,
+        /// Preview without updating
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Timesheet report aggregated by user, project, issue and/or day
+    #[command(after_help = r#"EXAMPLES:
+    linear time report --from 2026-10-01 --to 2026-10-31
+    linear tm report --from -7d --group-by user,day
+    linear tm report --from 2026-10-01 --to 2026-10-31 --group-by user,project,day --csv > oct.csv
+    linear tm report --group-by project --output json
+
+The last --group-by dimension becomes the columns of the matrix; the others
+form the rows. CSV values are decimal hours, JSON values are minutes."#)]
+    Report {
+        /// First day to include
+        #[arg(long, default_value = "-30d", allow_hyphen_values = true)]
+        from: String,
+        /// Last day to include
+        #[arg(long, default_value = "today", allow_hyphen_values = true)]
+        to: String,
+        /// Dimensions to group by, comma separated
+        #[arg(long, value_enum, value_delimiter = ',', default_value = "user")]
+        group_by: Vec<GroupBy>,
+        /// Only include entries by this user (name or email)
+        #[arg(short, long)]
+        user: Option<String>,
+        /// Print CSV instead of a table
+        #[arg(long)]
+        csv: bool,
+    },
+    /// Start a timer on an issue (kept in a local state file)
+    #[command(after_help = r#"EXAMPLES:
+    linear time start LIN-123                  # Start the clock
+    linear tm start LIN-123 -d "Pairing"       # With a description for the entry"#)]
+    Start {
+        /// Issue ID or identifier (e.g., "LIN-123")
+        issue: String,
+        /// Description for the logged entry
+        #[arg(short, long)]
+        description: Option<String>,
+    },
+    /// Stop the running timer and log the elapsed time
+    #[command(after_help = r#"EXAMPLES:
+    linear time stop                           # Log elapsed time
+    linear tm stop --round 15                  # Round up to 15 minutes
+    linear tm stop --round 15 --round-mode nearest
+    linear tm stop --discard                   # Drop the timer without logging
+
+Defaults for --round and --round-mode can be set in the config file:
+    [time]
+    round = 15
+    round_mode = "up""#)]
+    Stop {
+        /// Round to a multiple of this many minutes
+        #[arg(long, value_name = "MINUTES")]
+        round: Option<u32>,
+        /// Rounding direction (default: up)
+        #[arg(long, value_enum)]
+        round_mode: Option<RoundMode>,
+        /// Description for the logged entry (overrides the one given at start)
+        #[arg(short, long)]
+        description: Option<String>,
+        /// Stop without logging time
+        #[arg(long)]
+        discard: bool,
+    },
+    /// Show the running timer
+    Status,
     /// Delete a time entry
     Delete {
         /// Time entry ID
@@ -69,8 +161,36 @@ pub async fn handle(cmd: TimeCommands, output: &OutputOptions) -> Result<()> {
             issue,
             duration,
             description,
-        } => log_time(&issue, &duration, description).await,
+            date,
+        } => log_time(&issue, &duration, description, date, false).await,
         TimeCommands::List { issue } => list_time_entries(issue, output).await,
+        TimeCommands::Update {
+            id,
+            duration,
+            description,
+            date,
+            dry_run,
+        } => {
+            let dry_run = dry_run || output.dry_run;
+            update_time_entry(&id, duration, description, date, dry_run, output).await
+        }
+        TimeCommands::Report {
+            from,
+            to,
+            group_by,
+            user,
+            csv,
+        } => super::time_report::time_report(&from, &to, group_by, user, csv, output).await,
+        TimeCommands::Start { issue, description } => {
+            super::time_timer::start_timer(&issue, description, false).await
+        }
+        TimeCommands::Stop {
+            round,
+            round_mode,
+            description,
+            discard,
+        } => super::time_timer::stop_timer(round, round_mode, description, discard, None).await,
+        TimeCommands::Status => super::time_timer::timer_status(output).await,
         TimeCommands::Delete { id, force } => delete_time_entry(&id, force).await,
     }
 }
@@ -113,7 +233,7 @@ fn parse_duration(duration: &str) -> Result<i32> {
 }
 
 /// Format minutes into human-readable duration
-fn format_duration(minutes: i32) -> String {
+pub(super) fn format_duration(minutes: i32) -> String {
     let hours = minutes / 60;
     let mins = minutes % 60;
     if hours > 0 && mins > 0 {
@@ -125,8 +245,33 @@ fn format_duration(minutes: i32) -> String {
     }
 }
 
-async fn log_time(issue_id: &str, duration: &str, description: Option<String>) -> Result<()> {
+/// Resolve a `--date` value to "YYYY-MM-DD".
+fn parse_spent_at(date: Option<String>) -> Result<Option<String>> {
+    date.map(|d| {
+        crate::dates::parse_due_date(&d).ok_or_else(|| anyhow::anyhow!("Invalid date: {}", d))
+    })
+    .transpose()
+}
+
+/// Print a confirmation. Timers driven by `issues start/stop` report on
+/// stderr so the issue command's own output (e.g. `--id-only`) stays clean.
+pub(super) fn report(line: String, to_stderr: bool) {
+    if to_stderr {
+        eprintln!("{}", line);
+    } else {
+        println!("{}", line);
+    }
+}
+
+pub(super) async fn log_time(
+    issue_id: &str,
+    duration: &str,
+    description: Option<String>,
+    date: Option<String>,
+    to_stderr: bool,
+) -> Result<()> {
     let minutes = parse_duration(duration)?;
+    let spent_at = parse_spent_at(date)?;
     let client = LinearClient::new()?;
 
     // First, resolve the issue to get its UUID
@@ -154,14 +299,8 @@ async fn log_time(issue_id: &str, duration: &str, description: Option<String>) -
     // Create time entry using timeScheduleCreate mutation
     // Note: Linear's API uses timeScheduleCreate for logging time
     let mutation = r#"
-        mutation CreateTimeEntry($issueId: String!, $duration: Int!, $description: String) {
-            timeScheduleCreate(
-                input: {
-                    issueId: $issueId
-                    duration: $duration
-                    description: $description
-                }
-            ) {
+        mutation CreateTimeEntry($input: TimeScheduleCreateInput!) {
+            timeScheduleCreate(input: $input) {
                 success
                 timeSchedule {
                     id
@@ -170,32 +309,36 @@ async fn log_time(issue_id: &str, duration: &str, description: Option<String>) -
         }
     "#;
 
-    let variables = json!({
+    let mut input = json!({
         "issueId": issue_uuid,
         "duration": minutes,
         "description": description
     });
+    if let Some(ref day) = spent_at {
+        input["spentAt"] = json!(day);
+    }
+    let variables = json!({ "input": input });
 
     let result = client.mutate(mutation, Some(variables)).await;
 
     match result {
         Ok(data) => {
             if data["data"]["timeScheduleCreate"]["success"].as_bool() == Some(true) {
-                println!(
-                    "{} Logged {} on {} {}",
-                    "+".green(),
-                    format_duration(minutes).cyan(),
-                    identifier.cyan(),
-                    title.dimmed()
+                report(
+                    format!(
+                        "{} Logged {} on {} {}{}",
+                        "+".green(),
+                        format_duration(minutes).cyan(),
+                        identifier.cyan(),
+                        title.dimmed(),
+                        spent_at.map(|d| format!(" ({})", d)).unwrap_or_default()
+                    ),
+                    to_stderr,
                 );
             } else {
                 // Time tracking might not be enabled or different API
-                println!(
-                    "{} Time tracking may not be available for your Linear workspace.",
-                    "!".yellow()
-                );
-                println!(
-                    "Attempted to log {} on {}",
+                anyhow::bail!(
+                    "Could not log {} on {}. Time tracking may not be available for your Linear workspace.",
                     format_duration(minutes),
                     identifier
                 );
@@ -354,6 +497,78 @@ async fn list_time_entries(issue_filter: Option<String>, output: &OutputOptions)
     Ok(())
 }
 
+async fn update_time_entry(
+    id: &str,
+    duration: Option<String>,
+    description: Option<String>,
+    date: Option<String>,
+    dry_run: bool,
+    output: &OutputOptions,
+) -> Result<()> {
+    let mut input = serde_json::Map::new();
+    if let Some(ref d) = duration {
+        input.insert("duration".to_string(), json!(parse_duration(d)?));
+    }
+    if let Some(d) = description {
+        input.insert("description".to_string(), json!(d));
+    }
+    if let Some(day) = parse_spent_at(date)? {
+        input.insert("spentAt".to_string(), json!(day));
+    }
+    if input.is_empty() {
+        anyhow::bail!("Nothing to update. Use --duration, --description or --date");
+    }
+
+    if dry_run {
+        if output.is_json() || output.has_template() {
+            print_json_owned(json!({ "dry_run": true, "id": id, "input": input }), output)?;
+        } else {
+            println!("{}", "[DRY RUN] Would update time entry:".yellow().bold());
+            println!("  ID: {}", id);
+            for (key, value) in &input {
+                println!("  {}: {}", key, value);
+            }
+        }
+        return Ok(());
+    }
+
+    let client = LinearClient::new()?;
+    let mutation = r#"
+        mutation UpdateTimeEntry($id: String!, $input: TimeScheduleUpdateInput!) {
+            timeScheduleUpdate(id: $id, input: $input) {
+                success
+                timeSchedule {
+                    id
+                    duration
+                    description
+                    spentAt
+                }
+            }
+        }
+    "#;
+
+    let result = client
+        .mutate(mutation, Some(json!({ "id": id, "input": input })))
+        .await?;
+
+    if result["data"]["timeScheduleUpdate"]["success"].as_bool() == Some(true) {
+        let entry = &result["data"]["timeScheduleUpdate"]["timeSchedule"];
+        if output.is_json() || output.has_template() {
+            print_json_owned(entry.clone(), output)?;
+        } else {
+            println!(
+                "{} Time entry updated: {}",
+                "+".green(),
+                format_duration(entry["duration"].as_i64().unwrap_or(0) as i32).cyan()
+            );
+        }
+    } else {
+        anyhow::bail!("Failed to update time entry");
+    }
+
+    Ok(())
+}
+
 async fn delete_time_entry(id: &str, force: bool) -> Result<()> {
     if !force {
         let confirm = dialoguer::Confirm::new()
@@ -418,4 +633,14 @@ mod tests {
         assert_eq!(format_duration(60), "1h");
         assert_eq!(format_duration(45), "45m");
     }
+
+    #[test]
+    fn test_parse_spent_at() {
+        assert_eq!(parse_spent_at(None).unwrap(), None);
+        assert_eq!(
+            parse_spent_at(Some("2026-10-01".to_string())).unwrap(),
+            Some("2026-10-01".to_string())
+        );
+        assert!(parse_spent_at(Some("someday".to_string())).is_err());
+    }
 }
@@ -0,0 +1,489 @@
+use anyhow::Result;
+use chrono::{DateTime, Duration, Local, NaiveDate, Utc};
+use clap::ValueEnum;
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Write;
+use tabled::builder::Builder;
+
+use super::time::format_duration;
+use crate::api::LinearClient;
+use crate::output::{print_json_owned, OutputOptions};
+use crate::pagination::{paginate_nodes, PaginationOptions};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum GroupBy {
+    User,
+    Project,
+    Issue,
+    Day,
+}
+
+impl GroupBy {
+    fn name(self) -> &'static str {
+        match self {
+            GroupBy::User => "user",
+            GroupBy::Project => "project",
+            GroupBy::Issue => "issue",
+            GroupBy::Day => "day",
+        }
+    }
+}
+
+/// A time entry reduced to the fields a report can group by.
+#[derive(Debug)]
+struct Entry {
+    user: String,
+    project: String,
+    issue: String,
+    day: String,
+    minutes: i64,
+}
+
+/// Local calendar day of an entry. `time log --date` stores a bare date
+/// (midnight UTC) which already is the day the user meant; real timestamps
+/// are converted to the local day.
+fn local_day(v: &Value) -> Option<String> {
+    let raw = v["spentAt"].as_str().or_else(|| v["createdAt"].as_str())?;
+    if let Ok(day) = NaiveDate::parse_from_str(raw.get(..10)?, "%Y-%m-%d") {
+        if raw.len() == 10 || raw.get(10..19) == Some("T00:00:00") {
+            return Some(day.to_string());
+        }
+    }
+    let at: DateTime<Utc> = raw.parse().ok()?;
+    Some(at.with_timezone(&Local).date_naive().to_string())
+}
+
+/// Server-side filter for the report. The range is widened by a day each way
+/// so entries whose local day differs from their UTC day are still fetched;
+/// the exact cut is made on local days afterwards. Entries without `spentAt`
+/// are matched on `createdAt`, the day `local_day` falls back to.
+fn schedule_filter(from: &str, to: &str, user: Option<&str>) -> Result<Value> {
+    let day = |s: &str| {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").map_err(|_| anyhow::anyhow!("Invalid date: {}", s))
+    };
+    let range = json!({
+        "gte": (day(from)? - Duration::days(1)).to_string(),
+        "lt": (day(to)? + Duration::days(2)).to_string(),
+    });
+    let mut filter = json!({
+        "or": [
+            { "spentAt": range },
+            { "spentAt": { "null": true }, "createdAt": range },
+        ]
+    });
+    if let Some(u) = user {
+        filter["user"] = json!({
+            "or": [{ "name": { "eqIgnoreCase": u } }, { "email": { "eqIgnoreCase": u } }]
+        });
+    }
+    Ok(filter)
+}
+
+impl Entry {
+    fn from_json(v: &Value) -> Option<Entry> {
+        let day = local_day(v)?;
+        Some(Entry {
+            user: v["user"]["name"].as_str().unwrap_or("-").to_string(),
+            project: v["issue"]["project"]["name"]
+                .as_str()
+                .unwrap_or("No project")
+                .to_string(),
+            issue: v["issue"]["identifier"].as_str().unwrap_or("-").to_string(),
+            day,
+            minutes: v["duration"].as_i64().unwrap_or(0),
+        })
+    }
+
+    fn key(&self, by: GroupBy) -> &str {
+        match by {
+            GroupBy::User => &self.user,
+            GroupBy::Project => &self.project,
+            GroupBy::Issue => &self.issue,
+            GroupBy::Day => &self.day,
+        }
+    }
+}
+
+/// Minutes aggregated by every dimension but the last (rows) and the last
+/// dimension (columns). With a single dimension there are no columns and
+/// each row only has a total.
+#[derive(Debug)]
+struct Matrix {
+    columns: Vec<String>,
+    rows: Vec<(Vec<String>, Vec<i64>)>,
+    column_totals: Vec<i64>,
+}
+
+impl Matrix {
+    fn build(entries: &[Entry], dims: &[GroupBy]) -> Matrix {
+        let (row_dims, col_dim) = if dims.len() > 1 {
+            (&dims[..dims.len() - 1], Some(dims[dims.len() - 1]))
+        } else {
+            (dims, None)
+        };
+        let columns: Vec<String> = match col_dim {
+            Some(d) => entries
+                .iter()
+                .map(|e| e.key(d).to_string())
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect(),
+            None => Vec::new(),
+        };
+        let width = columns.len().max(1);
+
+        let mut rows: BTreeMap<Vec<String>, Vec<i64>> = BTreeMap::new();
+        for e in entries {
+            let key: Vec<String> = row_dims.iter().map(|d| e.key(*d).to_string()).collect();
+            let col = match col_dim {
+                Some(d) => columns.iter().position(|c| c == e.key(d)).unwrap_or(0),
+                None => 0,
+            };
+            rows.entry(key).or_insert_with(|| vec![0; width])[col] += e.minutes;
+        }
+        let mut column_totals = vec![0; width];
+        for cells in rows.values() {
+            for (total, v) in column_totals.iter_mut().zip(cells) {
+                *total += v;
+            }
+        }
+        Matrix {
+            columns,
+            rows: rows.into_iter().collect(),
+            column_totals,
+        }
+    }
+
+    fn total(&self) -> i64 {
+        self.column_totals.iter().sum()
+    }
+}
+
+fn hours(minutes: i64) -> String {
+    format!("{:.2}", minutes as f64 / 60.0)
+}
+
+fn cell(minutes: i64) -> String {
+    if minutes == 0 {
+        "-".to_string()
+    } else {
+        format_duration(minutes as i32)
+    }
+}
+
+fn print_table(matrix: &Matrix, dims: &[GroupBy]) {
+    let row_dims = dims.len().saturating_sub(1).max(1);
+    let mut builder = Builder::default();
+    let mut header: Vec<String> = dims[..row_dims]
+        .iter()
+        .map(|d| capitalize(d.name()))
+        .collect();
+    header.extend(matrix.columns.iter().cloned());
+    header.push("Total".to_string());
+    builder.push_record(header);
+    for (keys, cells) in &matrix.rows {
+        let mut record = keys.clone();
+        if !matrix.columns.is_empty() {
+            record.extend(cells.iter().map(|m| cell(*m)));
+        }
+        record.push(cell(cells.iter().sum()));
+        builder.push_record(record);
+    }
+    let mut totals = vec!["Total".to_string()];
+    totals.extend(std::iter::repeat_n(String::new(), row_dims - 1));
+    if !matrix.columns.is_empty() {
+        totals.extend(matrix.column_totals.iter().map(|m| cell(*m)));
+    }
+    totals.push(cell(matrix.total()));
+    builder.push_record(totals);
+    println!("{}", builder.build());
+}
+
+fn write_csv<W: Write>(out: W, matrix: &Matrix, dims: &[GroupBy]) -> Result<()> {
+    let row_dims = dims.len().saturating_sub(1).max(1);
+    let mut wtr = csv::Writer::from_writer(out);
+    let mut header: Vec<String> = dims[..row_dims]
+        .iter()
+        .map(|d| d.name().to_string())
+        .collect();
+    header.extend(matrix.columns.iter().cloned());
+    header.push("total_hours".to_string());
+    wtr.write_record(&header)?;
+    for (keys, cells) in &matrix.rows {
+        let mut record = keys.clone();
+        if !matrix.columns.is_empty() {
+            record.extend(cells.iter().map(|m| hours(*m)));
+        }
+        record.push(hours(cells.iter().sum()));
+        wtr.write_record(&record)?;
+    }
+    let mut totals = vec!["Total".to_string()];
+    totals.extend(std::iter::repeat_n(String::new(), row_dims - 1));
+    if !matrix.columns.is_empty() {
+        totals.extend(matrix.column_totals.iter().map(|m| hours(*m)));
+    }
+    totals.push(hours(matrix.total()));
+    wtr.write_record(&totals)?;
+    wtr.flush()?;
+    Ok(())
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) => c.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn parse_day(input: &str, flag: &str) -> Result<String> {
+    crate::dates::parse_due_date(input)
+        .ok_or_else(|| anyhow::anyhow!("Invalid {} date: {}", flag, input))
+}
+
+pub async fn time_report(
+    from: &str,
+    to: &str,
+    group_by: Vec<GroupBy>,
+    user: Option<String>,
+    csv: bool,
+    output: &OutputOptions,
+) -> Result<()> {
+    let from = parse_day(from, "--from")?;
+    let to = parse_day(to, "--to")?;
+    if from > to {
+        anyhow::bail!("--from {} is after --to {}", from, to);
+    }
+    let mut dims: Vec<GroupBy> = Vec::new();
+    for d in group_by {
+        if !dims.contains(&d) {
+            dims.push(d);
+        }
+    }
+    if dims.is_empty() {
+        dims.push(GroupBy::User);
+    }
+
+    let client = LinearClient::new()?;
+    let query = r#"
+        query($filter: TimeScheduleFilter, $first: Int, $after: String, $last: Int, $before: String) {
+            timeSchedules(first: $first, after: $after, last: $last, before: $before, filter: $filter) {
+                nodes {
+                    id
+                    duration
+                    spentAt
+                    createdAt
+                    user { name email }
+                    issue { identifier project { name } }
+                }
+                pageInfo {
+                    hasNextPage
+                    endCursor
+                    hasPreviousPage
+                    startCursor
+                }
+            }
+        }
+    "#;
+    let mut vars = serde_json::Map::new();
+    vars.insert(
+        "filter".to_string(),
+        schedule_filter(&from, &to, user.as_deref())?,
+    );
+    let pagination = PaginationOptions {
+        all: true,
+        ..Default::default()
+    };
+    let nodes = paginate_nodes(
+        &client,
+        query,
+        vars,
+        &["data", "timeSchedules", "nodes"],
+        &["data", "timeSchedules", "pageInfo"],
+        &pagination,
+        100,
+    )
+    .await
+    .map_err(|e| {
+        anyhow::anyhow!(
+            "Time tracking API not available: {}. This feature requires Linear's time tracking add-on.",
+            e
+        )
+    })?;
+
+    let user = user.map(|u| u.to_lowercase());
+    let entries: Vec<Entry> = nodes
+        .iter()
+        .filter(|v| match user {
+            Some(ref u) => [&v["user"]["name"], &v["user"]["email"]]
+                .iter()
+                .any(|f| f.as_str().is_some_and(|s| s.to_lowercase() == *u)),
+            None => true,
+        })
+        .filter_map(Entry::from_json)
+        .filter(|e| e.day.as_str() >= from.as_str() && e.day.as_str() <= to.as_str())
+        .collect();
+    let matrix = Matrix::build(&entries, &dims);
+
+    if output.is_json() || output.has_template() {
+        let row_dims = dims.len().saturating_sub(1).max(1);
+        let rows: Vec<Value> = matrix
+            .rows
+            .iter()
+            .map(|(keys, cells)| {
+                let mut row = serde_json::Map::new();
+                for (d, k) in dims[..row_dims].iter().zip(keys) {
+                    row.insert(d.name().to_string(), json!(k));
+                }
+                if !matrix.columns.is_empty() {
+                    let by: serde_json::Map<String, Value> = matrix
+                        .columns
+                        .iter()
+                        .zip(cells)
+                        .filter(|(_, m)| **m > 0)
+                        .map(|(c, m)| (c.clone(), json!(m)))
+                        .collect();
+                    row.insert(dims[row_dims].name().to_string(), Value::Object(by));
+                }
+                row.insert("minutes".to_string(), json!(cells.iter().sum::<i64>()));
+                Value::Object(row)
+            })
+            .collect();
+        print_json_owned(
+            json!({
+                "from": from,
+                "to": to,
+                "group_by": dims.iter().map(|d| d.name()).collect::<Vec<_>>(),
+                "entries": entries.len(),
+                "rows": rows,
+                "total_minutes": matrix.total(),
+            }),
+            output,
+        )?;
+        return Ok(());
+    }
+
+    if csv {
+        return write_csv(std::io::stdout().lock(), &matrix, &dims);
+    }
+
+    if matrix.rows.is_empty() {
+        println!("No time entries between {} and {}.", from, to);
+        return Ok(());
+    }
+    println!("Time from {} to {}", from, to);
+    print_table(&matrix, &dims);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(user: &str, project: &str, day: &str, minutes: i64) -> Entry {
+        Entry {
+            user: user.to_string(),
+            project: project.to_string(),
+            issue: "ENG-1".to_string(),
+            day: day.to_string(),
+            minutes,
+        }
+    }
+
+    #[test]
+    fn test_entry_from_json_prefers_spent_at() {
+        let e = Entry::from_json(&json!({
+            "duration": 90,
+            "spentAt": "2026-10-01T00:00:00.000Z",
+            "createdAt": "2026-10-02T09:00:00.000Z",
+            "user": { "name": "Ann" },
+            "issue": { "identifier": "ENG-1", "project": null },
+        }))
+        .unwrap();
+        assert_eq!(e.day, "2026-10-01");
+        assert_eq!(e.project, "No project");
+        assert_eq!(e.minutes, 90);
+    }
+
+    #[test]
+    fn test_local_day() {
+        // Dates logged with --date keep their day in every timezone
+        assert_eq!(
+            local_day(&json!({ "spentAt": "2026-10-01T00:00:00.000Z" })).as_deref(),
+            Some("2026-10-01")
+        );
+        assert_eq!(
+            local_day(&json!({ "spentAt": "2026-10-01" })).as_deref(),
+            Some("2026-10-01")
+        );
+        let at: DateTime<Utc> = "2026-10-01T23:30:00Z".parse().unwrap();
+        assert_eq!(
+            local_day(&json!({ "createdAt": "2026-10-01T23:30:00.000Z" })),
+            Some(at.with_timezone(&Local).date_naive().to_string())
+        );
+    }
+
+    #[test]
+    fn test_schedule_filter() {
+        let filter = schedule_filter("2026-10-01", "2026-10-31", Some("ann")).unwrap();
+        assert_eq!(filter["or"][0]["spentAt"]["gte"], "2026-09-30");
+        assert_eq!(filter["or"][0]["spentAt"]["lt"], "2026-11-02");
+        // Entries without spentAt are matched on the createdAt fallback
+        assert_eq!(filter["or"][1]["spentAt"]["null"], true);
+        assert_eq!(filter["or"][1]["createdAt"]["gte"], "2026-09-30");
+        assert_eq!(filter["user"]["or"][1]["email"]["eqIgnoreCase"], "ann");
+        assert!(schedule_filter("2026-10-01", "2026-10-31", None).unwrap()["user"].is_null());
+    }
+
+    #[test]
+    fn test_matrix_single_dimension() {
+        let entries = vec![
+            entry("Ann", "Web", "2026-10-01", 60),
+            entry("Bob", "Web", "2026-10-01", 30),
+            entry("Ann", "API", "2026-10-02", 45),
+        ];
+        let m = Matrix::build(&entries, &[GroupBy::User]);
+        assert!(m.columns.is_empty());
+        assert_eq!(m.rows.len(), 2);
+        assert_eq!(m.rows[0], (vec!["Ann".to_string()], vec![105]));
+        assert_eq!(m.total(), 135);
+    }
+
+    #[test]
+    fn test_matrix_rows_and_columns() {
+        let entries = vec![
+            entry("Ann", "Web", "2026-10-01", 60),
+            entry("Ann", "Web", "2026-10-02", 30),
+            entry("Bob", "API", "2026-10-02", 45),
+        ];
+        let m = Matrix::build(&entries, &[GroupBy::User, GroupBy::Project, GroupBy::Day]);
+        assert_eq!(m.columns, vec!["2026-10-01", "2026-10-02"]);
+        assert_eq!(
+            m.rows,
+            vec![
+                (vec!["Ann".to_string(), "Web".to_string()], vec![60, 30]),
+                (vec!["Bob".to_string(), "API".to_string()], vec![0, 45]),
+            ]
+        );
+        assert_eq!(m.column_totals, vec![60, 75]);
+    }
+
+    #[test]
+    fn test_write_csv_in_hours() {
+        let entries = vec![
+            entry("Ann", "Web", "2026-10-01", 90),
+            entry("Bob", "Web", "2026-10-02", 30),
+        ];
+        let m = Matrix::build(&entries, &[GroupBy::User, GroupBy::Day]);
+        let mut buf = Vec::new();
+        write_csv(&mut buf, &m, &[GroupBy::User, GroupBy::Day]).unwrap();
+        assert_eq!(
+            String::from_utf8(buf).unwrap(),
+            "user,2026-10-01,2026-10-02,total_hours\n\
+             Ann,1.50,0.00,1.50\n\
+             Bob,0.00,0.50,0.50\n\
+             Total,1.50,0.50,2.00\n"
+        );
+    }
+}
@@ -0,0 +1,276 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use clap::ValueEnum;
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::fs;
+use std::path::PathBuf;
+
+use super::time::{format_duration, log_time, report};
+use crate::api::LinearClient;
+use crate::output::{print_json_owned, OutputOptions};
+
+/// How `time stop` rounds the elapsed time to a multiple of `--round`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum RoundMode {
+    Up,
+    Nearest,
+    Down,
+}
+
+/// The running timer, kept on disk so it survives the terminal.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+struct Timer {
+    issue: String,
+    #[serde(default)]
+    title: String,
+    started_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+}
+
+fn timer_path() -> Result<PathBuf> {
+    let config_dir = dirs::config_dir()
+        .context("Could not find config directory")?
+        .join("linear-cli");
+
+    fs::create_dir_all(&config_dir)?;
+    Ok(config_dir.join("timer.json"))
+}
+
+fn load_timer() -> Result<Option<Timer>> {
+    let path = timer_path()?;
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = fs::read_to_string(&path)?;
+    let timer = serde_json::from_str(&content)
+        .with_context(|| format!("Invalid timer file: {}", path.display()))?;
+    Ok(Some(timer))
+}
+
+fn save_timer(timer: &Timer) -> Result<()> {
+    fs::write(timer_path()?, serde_json::to_string_pretty(timer)?)?;
+    Ok(())
+}
+
+fn clear_timer() -> Result<()> {
+    let path = timer_path()?;
+    if path.exists() {
+        fs::remove_file(path)?;
+    }
+    Ok(())
+}
+
+/// Whole minutes to log for `seconds` of work, rounded to a multiple of
+/// `round` minutes. Never less than one step, since a zero duration can't
+/// be logged.
+fn billable_minutes(seconds: i64, round: u32, mode: RoundMode) -> i32 {
+    let step = round.max(1) as f64 * 60.0;
+    let seconds = seconds.max(0) as f64;
+    let steps = match mode {
+        RoundMode::Up => (seconds / step).ceil(),
+        RoundMode::Nearest => (seconds / step).round(),
+        RoundMode::Down => (seconds / step).floor(),
+    };
+    ((steps * step / 60.0) as i32).max(round.max(1) as i32)
+}
+
+/// Rounding from the flags, falling back to the `[time]` config section.
+fn rounding(round: Option<u32>, mode: Option<RoundMode>) -> Result<(u32, RoundMode)> {
+    let config = crate::config::load_config()?.time;
+    let mode = match (mode, config.round_mode) {
+        (Some(mode), _) => mode,
+        (None, Some(name)) => RoundMode::from_str(&name, true)
+            .map_err(|_| anyhow::anyhow!("Invalid [time] round_mode: {}", name))?,
+        (None, None) => RoundMode::Up,
+    };
+    Ok((round.or(config.round).unwrap_or(1), mode))
+}
+
+/// Start a timer on an issue. Fails if another timer is running.
+pub async fn start_timer(issue: &str, description: Option<String>, to_stderr: bool) -> Result<()> {
+    if let Some(running) = load_timer()? {
+        anyhow::bail!(
+            "Timer already running on {} (started {}). Run `linear time stop` first.",
+            running.issue,
+            running
+                .started_at
+                .with_timezone(&chrono::Local)
+                .format("%H:%M")
+        );
+    }
+
+    let client = LinearClient::new()?;
+    let query = r#"
+        query($id: String!) {
+            issue(id: $id) {
+                identifier
+                title
+            }
+        }
+    "#;
+    let result = client.query(query, Some(json!({ "id": issue }))).await?;
+    let data = &result["data"]["issue"];
+    if data.is_null() {
+        anyhow::bail!("Issue not found: {}", issue);
+    }
+
+    let timer = Timer {
+        issue: data["identifier"].as_str().unwrap_or(issue).to_string(),
+        title: data["title"].as_str().unwrap_or("").to_string(),
+        started_at: Utc::now(),
+        description,
+    };
+    save_timer(&timer)?;
+    report(
+        format!(
+            "{} Timer started on {} {}",
+            "+".green(),
+            timer.issue.cyan(),
+            timer.title.dimmed()
+        ),
+        to_stderr,
+    );
+    Ok(())
+}
+
+/// Stop the running timer and log the rounded duration. With `only_issue`
+/// (from `issues stop --timer`), a timer running on another issue is left
+/// alone and messages go to stderr.
+pub async fn stop_timer(
+    round: Option<u32>,
+    mode: Option<RoundMode>,
+    description: Option<String>,
+    discard: bool,
+    only_issue: Option<&str>,
+) -> Result<()> {
+    let Some(timer) = load_timer()? else {
+        if only_issue.is_none() {
+            anyhow::bail!("No timer running. Start one with `linear time start ISSUE`.");
+        }
+        return Ok(());
+    };
+    let to_stderr = only_issue.is_some();
+    if let Some(issue) = only_issue {
+        if !timer.issue.eq_ignore_ascii_case(issue) {
+            eprintln!(
+                "{} Timer is running on {}, not {}; left running",
+                "!".yellow(),
+                timer.issue,
+                issue
+            );
+            return Ok(());
+        }
+    }
+
+    let seconds = (Utc::now() - timer.started_at).num_seconds();
+    if discard {
+        clear_timer()?;
+        report(
+            format!(
+                "{} Discarded timer on {} ({} elapsed)",
+                "-".red(),
+                timer.issue.cyan(),
+                format_duration((seconds / 60) as i32)
+            ),
+            to_stderr,
+        );
+        return Ok(());
+    }
+
+    let (round, mode) = rounding(round, mode)?;
+    let minutes = billable_minutes(seconds, round, mode);
+    // log_time fails unless the entry was created, so the timer survives for a retry
+    log_time(
+        &timer.issue,
+        &format!("{}m", minutes),
+        description.or(timer.description),
+        None,
+        to_stderr,
+    )
+    .await?;
+    clear_timer()
+}
+
+pub async fn timer_status(output: &OutputOptions) -> Result<()> {
+    let timer = load_timer()?;
+
+    if output.is_json() || output.has_template() {
+        let value = match timer {
+            Some(t) => json!({
+                "running": true,
+                "issue": t.issue,
+                "title": t.title,
+                "started_at": t.started_at.to_rfc3339(),
+                "elapsed_minutes": (Utc::now() - t.started_at).num_minutes(),
+                "description": t.description,
+            }),
+            None => json!({ "running": false }),
+        };
+        print_json_owned(value, output)?;
+        return Ok(());
+    }
+
+    match timer {
+        Some(t) => {
+            let elapsed = (Utc::now() - t.started_at).num_minutes() as i32;
+            println!(
+                "{} {} {}",
+                "Running:".bold(),
+                t.issue.cyan(),
+                t.title.dimmed()
+            );
+            println!(
+                "Started {} ({} ago)",
+                t.started_at
+                    .with_timezone(&chrono::Local)
+                    .format("%Y-%m-%d %H:%M"),
+                format_duration(elapsed.max(1))
+            );
+        }
+        None => println!("No timer running."),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_billable_minutes_round_up() {
+        assert_eq!(billable_minutes(16 * 60, 15, RoundMode::Up), 30);
+        assert_eq!(billable_minutes(15 * 60, 15, RoundMode::Up), 15);
+        assert_eq!(billable_minutes(30, 15, RoundMode::Up), 15);
+    }
+
+    #[test]
+    fn test_billable_minutes_nearest_and_down() {
+        assert_eq!(billable_minutes(22 * 60, 15, RoundMode::Nearest), 15);
+        assert_eq!(billable_minutes(23 * 60, 15, RoundMode::Nearest), 30);
+        assert_eq!(billable_minutes(29 * 60, 15, RoundMode::Down), 15);
+        // Never below one step, even when rounding down
+        assert_eq!(billable_minutes(5 * 60, 15, RoundMode::Down), 15);
+    }
+
+    #[test]
+    fn test_billable_minutes_no_rounding() {
+        assert_eq!(billable_minutes(90 * 60 + 20, 1, RoundMode::Nearest), 90);
+        assert_eq!(billable_minutes(10, 0, RoundMode::Up), 1);
+    }
+
+    #[test]
+    fn test_timer_roundtrip() {
+        let timer = Timer {
+            issue: "ENG-1".to_string(),
+            title: "Fix login".to_string(),
+            started_at: "2026-10-18T09:00:00Z".parse().unwrap(),
+            description: None,
+        };
+        let json = serde_json::to_string(&timer).unwrap();
+        assert!(!json.contains("description"));
+        assert_eq!(serde_json::from_str::<Timer>(&json).unwrap(), timer);
+    }
+}
@@ -30,6 +30,26 @@ pub enum UserCommands {
         /// User name, email, or ID
         user: String,
     },
+    /// Per-member load: open issues, cycle points vs capacity, due dates
+    #[command(after_help = r#"EXAMPLES:
+    linear users workload --team ENG           # Table, over-capacity rows in red
+    linear u workload -t ENG --capacity 20     # 20 points per person
+    linear u workload -t ENG --output json
+
+Capacity is compared with the estimate points assigned to each member in the
+team's active cycle. Defaults and per-person values can be set in the config:
+    [workload]
+    capacity = 20
+    [workload.people]
+    "alice@example.com" = 10"#)]
+    Workload {
+        /// Team key, name, or ID (defaults to team in .linear.toml)
+        #[arg(short, long)]
+        team: Option<String>,
+        /// Points per person per cycle (overrides [workload] capacity)
+        #[arg(long)]
+        capacity: Option<f64>,
+    },
 }
 
 #[derive(Tabled)]
@@ -47,6 +67,9 @@ pub async fn handle(cmd: UserCommands, output: &OutputOptions) -> Result<()> {
         UserCommands::List { team } => list_users(team, output).await,
         UserCommands::Me => get_me(output).await,
         UserCommands::Get { user } => get_user(&user, output).await,
+        UserCommands::Workload { team, capacity } => {
+            super::users_workload::workload(team, capacity, output).await
+        }
     }
 }
 
@@ -0,0 +1,400 @@
+use anyhow::Result;
+use colored::Colorize;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use tabled::{Table, Tabled};
+
+use crate::api::{resolve_team_id, LinearClient};
+use crate::config::WorkloadConfig;
+use crate::output::{print_json_owned, OutputOptions};
+use crate::pagination::{paginate_nodes, PaginationOptions};
+
+const OPEN_STATES: [&str; 4] = ["triage", "backlog", "unstarted", "started"];
+
+/// One team member's load.
+#[derive(Debug, Default, PartialEq)]
+struct Load {
+    name: String,
+    email: String,
+    backlog: usize,
+    todo: usize,
+    in_progress: usize,
+    cycle_points: f64,
+    cycle_issues: usize,
+    overdue: usize,
+    due_this_week: usize,
+    capacity: Option<f64>,
+}
+
+impl Load {
+    fn open(&self) -> usize {
+        self.backlog + self.todo + self.in_progress
+    }
+
+    fn is_over(&self) -> bool {
+        self.capacity.is_some_and(|c| self.cycle_points > c)
+    }
+
+    fn percent(&self) -> Option<f64> {
+        self.capacity
+            .filter(|c| *c > 0.0)
+            .map(|c| (self.cycle_points / c * 100.0).round())
+    }
+}
+
+#[derive(Tabled)]
+struct LoadRow {
+    #[tabled(rename = "Member")]
+    name: String,
+    #[tabled(rename = "Backlog")]
+    backlog: usize,
+    #[tabled(rename = "Todo")]
+    todo: usize,
+    #[tabled(rename = "In Progress")]
+    in_progress: usize,
+    #[tabled(rename = "Cycle pts")]
+    cycle: String,
+    #[tabled(rename = "Capacity")]
+    capacity: String,
+    #[tabled(rename = "Overdue")]
+    overdue: String,
+    #[tabled(rename = "Due this week")]
+    due_this_week: usize,
+}
+
+/// Capacity for a member: a `[workload.people]` entry matching one of their
+/// names or email, else the `--capacity` flag, else the `[workload] capacity`
+/// default.
+fn capacity_for(config: &WorkloadConfig, flag: Option<f64>, keys: &[&str]) -> Option<f64> {
+    config
+        .people
+        .iter()
+        .find(|(key, _)| {
+            keys.iter()
+                .any(|k| !k.is_empty() && key.eq_ignore_ascii_case(k))
+        })
+        .map(|(_, c)| *c)
+        .or(flag)
+        .or(config.capacity)
+}
+
+/// Tally issues onto members. Issues assigned to someone outside the team
+/// are ignored.
+fn tally(
+    members: &mut BTreeMap<String, Load>,
+    issues: &[Value],
+    cycle_id: Option<&str>,
+    today: &str,
+    end_of_week: &str,
+) {
+    for issue in issues {
+        let Some(load) = issue["assignee"]["id"]
+            .as_str()
+            .and_then(|id| members.get_mut(id))
+        else {
+            continue;
+        };
+        let state = issue["state"]["type"].as_str().unwrap_or("");
+        let open = OPEN_STATES.contains(&state);
+        match state {
+            "triage" | "backlog" => load.backlog += 1,
+            "unstarted" => load.todo += 1,
+            "started" => load.in_progress += 1,
+            _ => {}
+        }
+        if cycle_id.is_some() && issue["cycle"]["id"].as_str() == cycle_id && state != "canceled" {
+            load.cycle_issues += 1;
+            load.cycle_points += issue["estimate"].as_f64().unwrap_or(0.0);
+        }
+        if let Some(due) = issue["dueDate"].as_str().filter(|_| open) {
+            if due < today {
+                load.overdue += 1;
+            } else if due <= end_of_week {
+                load.due_this_week += 1;
+            }
+        }
+    }
+}
+
+fn fmt_points(v: f64) -> String {
+    if v.fract() == 0.0 {
+        format!("{}", v as i64)
+    } else {
+        format!("{:.1}", v)
+    }
+}
+
+pub async fn workload(
+    team: Option<String>,
+    capacity: Option<f64>,
+    output: &OutputOptions,
+) -> Result<()> {
+    let team = crate::config::team_or_repo_default(team)?;
+    let config = crate::config::load_config()?.workload;
+    let client = LinearClient::new()?;
+    let team_id = resolve_team_id(&client, &team, &output.cache).await?;
+
+    let query = r#"
+        query($id: String!) {
+            team(id: $id) {
+                key
+                name
+                activeCycle { id number name }
+                members(first: 250) {
+                    nodes { id name displayName email active }
+                }
+            }
+        }
+    "#;
+    let result = client.query(query, Some(json!({ "id": team_id }))).await?;
+    let data = &result["data"]["team"];
+    if data.is_null() {
+        anyhow::bail!("Team not found: {}", team);
+    }
+    let cycle = &data["activeCycle"];
+    let cycle_id = cycle["id"].as_str();
+
+    let mut members: BTreeMap<String, Load> = BTreeMap::new();
+    for m in data["members"]["nodes"].as_array().into_iter().flatten() {
+        if m["active"].as_bool() == Some(false) {
+            continue;
+        }
+        let name = m["displayName"]
+            .as_str()
+            .or_else(|| m["name"].as_str())
+            .unwrap_or("")
+            .to_string();
+        let email = m["email"].as_str().unwrap_or("").to_string();
+        let keys = [email.as_str(), m["name"].as_str().unwrap_or(""), &name];
+        let cap = capacity_for(&config, capacity, &keys);
+        members.insert(
+            m["id"].as_str().unwrap_or("").to_string(),
+            Load {
+                name,
+                email,
+                capacity: cap,
+                ..Default::default()
+            },
+        );
+    }
+
+    // Open assigned issues, plus everything in the active cycle (for points)
+    let mut scope = vec![json!({ "state": { "type": { "in": OPEN_STATES } } })];
+    if let Some(id) = cycle_id {
+        scope.push(json!({ "cycle": { "id": { "eq": id } } }));
+    }
+    let issues_query = r#"
+        query($filter: IssueFilter, $first: Int, $after: String, $last: Int, $before: String) {
+            issues(first: $first, after: $after, last: $last, before: $before, filter: $filter) {
+                nodes {
+                    estimate
+                    dueDate
+                    state { type }
+                    assignee { id }
+                    cycle { id }
+                }
+                pageInfo { hasNextPage endCursor hasPreviousPage startCursor }
+            }
+        }
+    "#;
+    let mut vars = serde_json::Map::new();
+    vars.insert(
+        "filter".to_string(),
+        json!({
+            "team": { "id": { "eq": team_id } },
+            "assignee": { "null": false },
+            "or": scope,
+        }),
+    );
+    let pagination = PaginationOptions {
+        all: true,
+        ..Default::default()
+    };
+    let issues = paginate_nodes(
+        &client,
+        issues_query,
+        vars,
+        &["data", "issues", "nodes"],
+        &["data", "issues", "pageInfo"],
+        &pagination,
+        250,
+    )
+    .await?;
+
+    let today = chrono::Local::now()
+        .date_naive()
+        .format("%Y-%m-%d")
+        .to_string();
+    let end_of_week = crate::dates::parse_due_date("eow").unwrap_or_else(|| today.clone());
+    tally(&mut members, &issues, cycle_id, &today, &end_of_week);
+
+    let mut loads: Vec<Load> = members.into_values().collect();
+    loads.sort_by(|a, b| {
+        b.cycle_points
+            .total_cmp(&a.cycle_points)
+            .then(b.open().cmp(&a.open()))
+            .then(a.name.cmp(&b.name))
+    });
+
+    if output.is_json() || output.has_template() {
+        print_json_owned(
+            json!({
+                "team": data["key"],
+                "cycle": cycle_id.map(|_| json!({ "number": cycle["number"], "name": cycle["name"] })),
+                "members": loads.iter().map(|l| json!({
+                    "name": l.name,
+                    "email": l.email,
+                    "open": { "backlog": l.backlog, "todo": l.todo, "in_progress": l.in_progress },
+                    "cycle_issues": l.cycle_issues,
+                    "cycle_points": l.cycle_points,
+                    "capacity": l.capacity,
+                    "load_pct": l.percent(),
+                    "over_capacity": l.is_over(),
+                    "overdue": l.overdue,
+                    "due_this_week": l.due_this_week,
+                })).collect::<Vec<_>>(),
+            }),
+            output,
+        )?;
+        return Ok(());
+    }
+
+    match cycle_id {
+        Some(_) => println!(
+            "{} workload, cycle {}",
+            data["name"].as_str().unwrap_or(&team).bold(),
+            cycle["name"]
+                .as_str()
+                .map(String::from)
+                .unwrap_or_else(|| format!("#{}", cycle["number"]))
+        ),
+        None => println!(
+            "{} workload {}",
+            data["name"].as_str().unwrap_or(&team).bold(),
+            "(no active cycle)".dimmed()
+        ),
+    }
+    if loads.is_empty() {
+        println!("No team members found.");
+        return Ok(());
+    }
+
+    let over = loads.iter().filter(|l| l.is_over()).count();
+    let rows: Vec<LoadRow> = loads
+        .iter()
+        .map(|l| {
+            let points = fmt_points(l.cycle_points);
+            LoadRow {
+                name: if l.is_over() {
+                    l.name.red().bold().to_string()
+                } else {
+                    l.name.clone()
+                },
+                backlog: l.backlog,
+                todo: l.todo,
+                in_progress: l.in_progress,
+                cycle: if l.is_over() {
+                    points.red().bold().to_string()
+                } else {
+                    points
+                },
+                capacity: match (l.capacity, l.percent()) {
+                    (Some(c), Some(p)) => format!("{} ({}%)", fmt_points(c), p),
+                    (Some(c), None) => fmt_points(c),
+                    _ => "-".to_string(),
+                },
+                overdue: if l.overdue > 0 {
+                    l.overdue.to_string().red().to_string()
+                } else {
+                    "0".to_string()
+                },
+                due_this_week: l.due_this_week,
+            }
+        })
+        .collect();
+    println!("{}", Table::new(rows));
+    if over > 0 {
+        println!("{} {} member(s) over capacity", "!".red(), over);
+    } else if loads.iter().all(|l| l.capacity.is_none()) {
+        println!(
+            "{}",
+            "Set --capacity or [workload] capacity in the config to highlight overload.".dimmed()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capacity_for_prefers_person_override() {
+        let mut config = WorkloadConfig {
+            capacity: Some(20.0),
+            ..Default::default()
+        };
+        config.people.insert("Alice@Example.com".to_string(), 10.0);
+        config.people.insert("carl".to_string(), 8.0);
+        assert_eq!(
+            capacity_for(&config, Some(15.0), &["alice@example.com", "Alice"]),
+            Some(10.0)
+        );
+        assert_eq!(
+            capacity_for(
+                &config,
+                Some(15.0),
+                &["carl@example.com", "Carl Jones", "carl"]
+            ),
+            Some(8.0)
+        );
+        assert_eq!(
+            capacity_for(&config, Some(15.0), &["bob@example.com"]),
+            Some(15.0)
+        );
+        assert_eq!(
+            capacity_for(&config, None, &["bob@example.com"]),
+            Some(20.0)
+        );
+        assert_eq!(
+            capacity_for(&WorkloadConfig::default(), None, &["", "Bob"]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_tally() {
+        let mut members = BTreeMap::new();
+        members.insert(
+            "u1".to_string(),
+            Load {
+                name: "Ann".to_string(),
+                capacity: Some(5.0),
+                ..Default::default()
+            },
+        );
+        let issues = vec![
+            json!({ "assignee": { "id": "u1" }, "state": { "type": "started" }, "estimate": 3, "cycle": { "id": "c1" }, "dueDate": "2026-10-10" }),
+            json!({ "assignee": { "id": "u1" }, "state": { "type": "completed" }, "estimate": 3, "cycle": { "id": "c1" }, "dueDate": "2026-10-10" }),
+            json!({ "assignee": { "id": "u1" }, "state": { "type": "backlog" }, "dueDate": "2026-10-20" }),
+            json!({ "assignee": { "id": "u1" }, "state": { "type": "canceled" }, "estimate": 8, "cycle": { "id": "c1" } }),
+            json!({ "assignee": { "id": "other" }, "state": { "type": "started" }, "estimate": 5 }),
+        ];
+        tally(
+            &mut members,
+            &issues,
+            Some("c1"),
+            "2026-10-18",
+            "2026-10-25",
+        );
+        let ann = &members["u1"];
+        assert_eq!((ann.backlog, ann.todo, ann.in_progress), (1, 0, 1));
+        assert_eq!(ann.cycle_points, 6.0);
+        assert_eq!(ann.cycle_issues, 2);
+        // Completed issues past their due date aren't overdue
+        assert_eq!(ann.overdue, 1);
+        assert_eq!(ann.due_this_week, 1);
+        assert!(ann.is_over());
+        assert_eq!(ann.percent(), Some(120.0));
+    }
+}
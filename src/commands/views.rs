@@ -5,11 +5,13 @@ use serde_json::json;
 use tabled::{Table, Tabled};
 
 use crate::api::{resolve_team_id, resolve_view_id, LinearClient};
+use crate::config::{self, LocalView};
 use crate::display_options;
 use crate::output::{
     ensure_non_empty, filter_values, print_json, print_json_owned, sort_values, OutputOptions,
 };
 use crate::pagination::paginate_nodes;
+use crate::priority::priority_to_string;
 use crate::text::truncate;
 use crate::types::CustomView;
 
@@ -20,7 +22,8 @@ pub enum ViewCommands {
     #[command(after_help = r#"EXAMPLES:
     linear views list                       # List all custom views
     linear v list --shared                  # List shared views only
-    linear v list --team ENG                # Filter by team"#)]
+    linear v list --team ENG                # Filter by team
+    linear v list --local                   # Local views from config.toml"#)]
     List {
         /// Filter by team name or ID
         #[arg(short, long)]
@@ -28,6 +31,9 @@ pub enum ViewCommands {
         /// Show only shared views
         #[arg(long)]
         shared: bool,
+        /// Show local views from the config file instead
+        #[arg(long, conflicts_with = "shared")]
+        local: bool,
     },
     /// Get custom view details
     #[command(after_help = r#"EXAMPLES:
@@ -84,6 +90,56 @@ pub enum ViewCommands {
         #[arg(long)]
         filter_json: Option<String>,
     },
+    /// Run a saved view (local or Linear) with its ordering and grouping
+    #[command(after_help = r#"EXAMPLES:
+    linear views run "Bug Triage"           # Run a Linear view
+    linear v run my-bugs                    # Run a local view from config.toml
+    linear v run my-bugs --group-by assignee
+    linear v run "Roadmap" --output json    # Project views list projects
+
+LOCAL VIEWS (config.toml):
+    [views.my-bugs]
+    team = "ENG"
+    labels = ["bug"]
+    state_type = ["unstarted", "started"]
+    assignee = "me"
+    order_by = "priority"
+    group_by = "state"
+
+    Fields: model (issue|project), description, team, state, state_type,
+    assignee (name|me|none), labels, priority, project, order_by, group_by,
+    and filter (raw IssueFilter merged over the generated one)."#)]
+    Run {
+        /// View name (local views are checked first) or ID
+        name: String,
+        /// Override grouping: state, priority, assignee, project, label, cycle, none
+        #[arg(short, long)]
+        group_by: Option<String>,
+        /// Override ordering: priority, title, state, dueDate, createdAt, updatedAt
+        #[arg(long)]
+        order_by: Option<String>,
+        /// Include archived issues/projects
+        #[arg(long)]
+        archived: bool,
+    },
+    /// Promote a local view to a Linear custom view
+    #[command(after_help = r#"EXAMPLES:
+    linear views publish my-bugs            # Create a personal Linear view
+    linear v publish my-bugs --shared       # Share it with the workspace
+    linear v publish my-bugs --keep         # Keep the local copy"#)]
+    Publish {
+        /// Local view name
+        name: String,
+        /// Make the view shared (visible to all workspace members)
+        #[arg(long)]
+        shared: bool,
+        /// Team name or ID to scope the view to (defaults to the view's team)
+        #[arg(short, long)]
+        team: Option<String>,
+        /// Keep the local view in config.toml after publishing
+        #[arg(long)]
+        keep: bool,
+    },
     /// Delete a custom view
     #[command(after_help = r#"EXAMPLES:
     linear views delete "My View"           # Delete with confirmation
@@ -115,7 +171,17 @@ struct ViewRow {
 
 pub async fn handle(cmd: ViewCommands, output: &OutputOptions) -> Result<()> {
     match cmd {
-        ViewCommands::List { team, shared } => list_views(team, shared, output).await,
+        ViewCommands::List {
+            team,
+            shared,
+            local,
+        } => {
+            if local {
+                list_local_views(output)
+            } else {
+                list_views(team, shared, output).await
+            }
+        }
         ViewCommands::Get { name_or_id } => get_view(&name_or_id, output).await,
         ViewCommands::Create {
             name,
@@ -139,6 +205,18 @@ pub async fn handle(cmd: ViewCommands, output: &OutputOptions) -> Result<()> {
             name_or_id,
             force,
         } => delete_view(&name_or_id, force, output).await,
+        ViewCommands::Run {
+            name,
+            group_by,
+            order_by,
+            archived,
+        } => run_view(&name, group_by, order_by, archived, output).await,
+        ViewCommands::Publish {
+            name,
+            shared,
+            team,
+            keep,
+        } => publish_view(&name, shared, team, keep, output).await,
     }
 }
 
@@ -588,6 +666,621 @@ async fn delete_view(name_or_id: &str, force: bool, output: &OutputOptions) -> R
     Ok(())
 }
 
+#[derive(Tabled)]
+struct LocalViewRow {
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Model")]
+    model: String,
+    #[tabled(rename = "Team")]
+    team: String,
+    #[tabled(rename = "Description")]
+    description: String,
+}
+
+#[derive(Tabled)]
+struct ViewIssueRow {
+    #[tabled(rename = "ID")]
+    identifier: String,
+    #[tabled(rename = "Title")]
+    title: String,
+    #[tabled(rename = "State")]
+    state: String,
+    #[tabled(rename = "Priority")]
+    priority: String,
+    #[tabled(rename = "Assignee")]
+    assignee: String,
+}
+
+#[derive(Tabled)]
+struct ViewProjectRow {
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Status")]
+    state: String,
+    #[tabled(rename = "Target")]
+    target: String,
+    #[tabled(rename = "ID")]
+    id: String,
+}
+
+fn list_local_views(output: &OutputOptions) -> Result<()> {
+    let config = config::load_config()?;
+
+    if output.is_json() || output.has_template() {
+        let views: Vec<serde_json::Value> = config
+            .views
+            .iter()
+            .map(|(name, view)| {
+                let mut value = serde_json::to_value(view).unwrap_or_else(|_| json!({}));
+                value["name"] = json!(name);
+                value
+            })
+            .collect();
+        print_json_owned(json!(views), output)?;
+        return Ok(());
+    }
+
+    if config.views.is_empty() {
+        println!("No local views. Add [views.NAME] sections to {}", config::config_file_path()?.display());
+        return Ok(());
+    }
+
+    let width = display_options().max_width(40);
+    let rows: Vec<LocalViewRow> = config
+        .views
+        .iter()
+        .map(|(name, view)| LocalViewRow {
+            name: truncate(name, width),
+            model: if is_project_view(view) { "project" } else { "issue" }.to_string(),
+            team: view.team.clone().unwrap_or_else(|| "-".to_string()),
+            description: truncate(view.description.as_deref().unwrap_or("-"), width),
+        })
+        .collect();
+
+    println!("{}", Table::new(rows));
+    println!("\n{} local views", config.views.len());
+
+    Ok(())
+}
+
+fn is_project_view(view: &LocalView) -> bool {
+    matches!(view.model.as_deref(), Some("project") | Some("projects"))
+}
+
+/// Merge a raw filter object over a generated one (top-level keys win).
+fn merge_filter(mut filter: serde_json::Value, raw: Option<&serde_json::Value>) -> serde_json::Value {
+    if let (Some(target), Some(serde_json::Value::Object(extra))) = (filter.as_object_mut(), raw) {
+        for (key, value) in extra {
+            target.insert(key.clone(), value.clone());
+        }
+    }
+    filter
+}
+
+/// Translate a local view into a Linear IssueFilter.
+fn local_issue_filter(view: &LocalView) -> serde_json::Value {
+    let mut filter = json!({});
+
+    if let Some(ref team) = view.team {
+        filter["team"] = json!({ "key": { "eqIgnoreCase": team } });
+    }
+
+    let mut state = json!({});
+    if !view.state.is_empty() {
+        state["name"] = json!({ "in": view.state });
+    }
+    if !view.state_type.is_empty() {
+        state["type"] = json!({ "in": view.state_type });
+    }
+    if state.as_object().map(|o| !o.is_empty()).unwrap_or(false) {
+        filter["state"] = state;
+    }
+
+    if let Some(ref assignee) = view.assignee {
+        filter["assignee"] = match assignee.to_lowercase().as_str() {
+            "me" => json!({ "isMe": { "eq": true } }),
+            "none" | "unassigned" => json!({ "null": true }),
+            _ if assignee.contains('@') => json!({ "email": { "eqIgnoreCase": assignee } }),
+            _ => json!({ "name": { "eqIgnoreCase": assignee } }),
+        };
+    }
+
+    if !view.labels.is_empty() {
+        filter["labels"] = json!({ "some": { "name": { "in": view.labels } } });
+    }
+
+    if !view.priority.is_empty() {
+        filter["priority"] = json!({ "in": view.priority });
+    }
+
+    if let Some(ref project) = view.project {
+        filter["project"] = json!({ "name": { "eqIgnoreCase": project } });
+    }
+
+    merge_filter(filter, view.filter.as_ref())
+}
+
+/// Translate a local project view into a Linear ProjectFilter.
+fn local_project_filter(view: &LocalView) -> serde_json::Value {
+    let mut filter = json!({});
+
+    if let Some(ref team) = view.team {
+        filter["accessibleTeams"] = json!({ "some": { "key": { "eqIgnoreCase": team } } });
+    }
+    if !view.state.is_empty() {
+        filter["status"] = json!({ "name": { "in": view.state } });
+    }
+
+    merge_filter(filter, view.filter.as_ref())
+}
+
+/// Normalize a grouping name (ours or Linear's view preference value).
+/// Returns `Ok(None)` for "no grouping".
+fn normalize_grouping(value: &str) -> Result<Option<&'static str>> {
+    match value {
+        "state" | "status" | "workflowState" => Ok(Some("state")),
+        "priority" => Ok(Some("priority")),
+        "assignee" => Ok(Some("assignee")),
+        "project" => Ok(Some("project")),
+        "label" | "labels" => Ok(Some("label")),
+        "cycle" => Ok(Some("cycle")),
+        "none" | "noGrouping" => Ok(None),
+        other => anyhow::bail!(
+            "Unknown grouping: '{}'. Use state, priority, assignee, project, label, cycle, or none.",
+            other
+        ),
+    }
+}
+
+/// Normalize an ordering name (ours or Linear's view preference value).
+/// Returns `Ok(None)` for server order.
+fn normalize_ordering(value: &str) -> Result<Option<&'static str>> {
+    match value {
+        "priority" => Ok(Some("priority")),
+        "title" | "name" => Ok(Some("title")),
+        "state" | "status" | "workflowState" => Ok(Some("state")),
+        "dueDate" | "due" | "targetDate" => Ok(Some("dueDate")),
+        "createdAt" | "created" => Ok(Some("createdAt")),
+        "updatedAt" | "updated" => Ok(Some("updatedAt")),
+        "manual" | "none" => Ok(None),
+        other => anyhow::bail!(
+            "Unknown ordering: '{}'. Use priority, title, state, dueDate, createdAt, or updatedAt.",
+            other
+        ),
+    }
+}
+
+fn sort_by_ordering(items: &mut [serde_json::Value], ordering: &str) {
+    fn text(item: &serde_json::Value, keys: &[&str]) -> String {
+        keys.iter()
+            .find_map(|k| {
+                let v = item.pointer(k)?;
+                v.as_str().map(|s| s.to_lowercase())
+            })
+            .unwrap_or_default()
+    }
+
+    match ordering {
+        // No priority (0) sorts after Low (4)
+        "priority" => items.sort_by_key(|i| match i["priority"].as_i64().unwrap_or(0) {
+            0 => 5,
+            p => p,
+        }),
+        "title" => items.sort_by_key(|i| text(i, &["/title", "/name"])),
+        "state" => items.sort_by_key(|i| text(i, &["/state/name", "/state"])),
+        // Missing dates last
+        "dueDate" => items.sort_by_key(|i| {
+            let date = text(i, &["/dueDate", "/targetDate"]);
+            (date.is_empty(), date)
+        }),
+        "createdAt" | "updatedAt" => {
+            items.sort_by_key(|i| std::cmp::Reverse(text(i, &[&format!("/{}", ordering)])))
+        }
+        _ => {}
+    }
+}
+
+fn group_keys(item: &serde_json::Value, grouping: &str) -> Vec<String> {
+    let name = |field: &str, fallback: &str| {
+        item[field]["name"].as_str().unwrap_or(fallback).to_string()
+    };
+    match grouping {
+        "state" => vec![match &item["state"] {
+            serde_json::Value::String(s) => s.clone(),
+            state => state["name"].as_str().unwrap_or("Unknown").to_string(),
+        }],
+        "priority" => vec![priority_to_string(item["priority"].as_i64())],
+        "assignee" => vec![name("assignee", "Unassigned")],
+        "project" => vec![name("project", "No Project")],
+        "cycle" => vec![match (&item["cycle"]["name"], item["cycle"]["number"].as_i64()) {
+            (serde_json::Value::String(n), _) => n.clone(),
+            (_, Some(n)) => format!("Cycle {}", n),
+            _ => "No Cycle".to_string(),
+        }],
+        "label" => {
+            let labels: Vec<String> = item["labels"]["nodes"]
+                .as_array()
+                .map(|nodes| {
+                    nodes
+                        .iter()
+                        .filter_map(|l| l["name"].as_str().map(String::from))
+                        .collect()
+                })
+                .unwrap_or_default();
+            if labels.is_empty() {
+                vec!["No Label".to_string()]
+            } else {
+                labels
+            }
+        }
+        _ => vec![String::new()],
+    }
+}
+
+/// A view resolved from either the config file or Linear, ready to execute.
+struct ResolvedView {
+    name: String,
+    project: bool,
+    filter: serde_json::Value,
+    ordering: Option<String>,
+    grouping: Option<String>,
+}
+
+async fn resolve_runnable_view(
+    client: &LinearClient,
+    name: &str,
+    output: &OutputOptions,
+) -> Result<ResolvedView> {
+    let config = config::load_config()?;
+    if let Some(view) = config.views.get(name) {
+        let project = is_project_view(view);
+        if !project && !matches!(view.model.as_deref(), None | Some("issue") | Some("issues")) {
+            anyhow::bail!(
+                "Local view '{}' has unknown model '{}'. Use issue or project.",
+                name,
+                view.model.as_deref().unwrap_or("")
+            );
+        }
+        return Ok(ResolvedView {
+            name: name.to_string(),
+            project,
+            filter: if project {
+                local_project_filter(view)
+            } else {
+                local_issue_filter(view)
+            },
+            ordering: view.order_by.clone(),
+            grouping: view.group_by.clone(),
+        });
+    }
+
+    let view_id = resolve_view_id(client, name, &output.cache).await?;
+    let query = r#"
+        query($id: String!) {
+            customView(id: $id) {
+                id name modelName filterData projectFilterData
+            }
+        }
+    "#;
+    let result = client.query(query, Some(json!({ "id": view_id }))).await?;
+    let view = &result["data"]["customView"];
+    if view.is_null() {
+        anyhow::bail!("Custom view not found: {}", name);
+    }
+
+    let project = view["modelName"].as_str() == Some("Project")
+        || (view["filterData"].is_null() && !view["projectFilterData"].is_null());
+    let filter = if project {
+        view["projectFilterData"].clone()
+    } else {
+        view["filterData"].clone()
+    };
+
+    // View preferences are optional; fall back to the defaults when they can't be read.
+    let prefs_query = r#"
+        query($id: String!) {
+            customView(id: $id) {
+                userViewPreferences { preferences { viewOrdering issueGrouping } }
+                organizationViewPreferences { preferences { viewOrdering issueGrouping } }
+            }
+        }
+    "#;
+    let (mut ordering, mut grouping) = (None, None);
+    match client.query(prefs_query, Some(json!({ "id": view_id }))).await {
+        Ok(prefs) => {
+            let cv = &prefs["data"]["customView"];
+            for scope in ["organizationViewPreferences", "userViewPreferences"] {
+                let p = &cv[scope]["preferences"];
+                if let Some(o) = p["viewOrdering"].as_str() {
+                    ordering = Some(o.to_string());
+                }
+                if let Some(g) = p["issueGrouping"].as_str() {
+                    grouping = Some(g.to_string());
+                }
+            }
+        }
+        Err(e) => eprintln!(
+            "{} Couldn't read view preferences ({}); using default ordering and grouping",
+            "!".yellow(),
+            e
+        ),
+    }
+
+    Ok(ResolvedView {
+        name: view["name"].as_str().unwrap_or(name).to_string(),
+        project,
+        filter: if filter.is_null() { json!({}) } else { filter },
+        ordering,
+        grouping,
+    })
+}
+
+async fn run_view(
+    name: &str,
+    group_by: Option<String>,
+    order_by: Option<String>,
+    include_archived: bool,
+    output: &OutputOptions,
+) -> Result<()> {
+    let client = LinearClient::new()?;
+    let view = resolve_runnable_view(&client, name, output).await?;
+
+    // Explicit flags are validated strictly; unknown values stored on the view are ignored.
+    let ordering = match order_by {
+        Some(ref o) => normalize_ordering(o)?,
+        None => view.ordering.as_deref().and_then(|o| normalize_ordering(o).ok().flatten()),
+    };
+    let grouping = match group_by {
+        Some(ref g) => normalize_grouping(g)?,
+        None => view.grouping.as_deref().and_then(|g| normalize_grouping(g).ok().flatten()),
+    };
+    if view.project && grouping.is_some_and(|g| g != "state") {
+        anyhow::bail!("Project views can only be grouped by state.");
+    }
+
+    let (query, root) = if view.project {
+        (
+            r#"
+            query($filter: ProjectFilter, $includeArchived: Boolean, $first: Int, $after: String, $last: Int, $before: String) {
+                projects(first: $first, after: $after, last: $last, before: $before, includeArchived: $includeArchived, filter: $filter) {
+                    nodes {
+                        id name state url startDate targetDate createdAt updatedAt
+                    }
+                    pageInfo { hasNextPage endCursor hasPreviousPage startCursor }
+                }
+            }
+        "#,
+            "projects",
+        )
+    } else {
+        (
+            r#"
+            query($filter: IssueFilter, $includeArchived: Boolean, $first: Int, $after: String, $last: Int, $before: String) {
+                issues(first: $first, after: $after, last: $last, before: $before, includeArchived: $includeArchived, filter: $filter) {
+                    nodes {
+                        id identifier title priority dueDate url createdAt updatedAt
+                        state { name type }
+                        assignee { name }
+                        project { name }
+                        cycle { number name }
+                        labels { nodes { name } }
+                    }
+                    pageInfo { hasNextPage endCursor hasPreviousPage startCursor }
+                }
+            }
+        "#,
+            "issues",
+        )
+    };
+
+    let mut vars = serde_json::Map::new();
+    vars.insert("includeArchived".to_string(), json!(include_archived));
+    if view.filter.as_object().map(|o| !o.is_empty()).unwrap_or(false) {
+        vars.insert("filter".to_string(), view.filter.clone());
+    }
+
+    // The view's ordering is applied here, not by the API, so fetch every
+    // page and sort before taking the limit
+    let mut pagination = output.pagination.with_default_limit(50);
+    let limit = pagination.limit.filter(|_| !pagination.all);
+    if ordering.is_some() {
+        pagination.all = true;
+    }
+    let mut items = paginate_nodes(
+        &client,
+        query,
+        vars,
+        &["data", root, "nodes"],
+        &["data", root, "pageInfo"],
+        &pagination,
+        if ordering.is_some() { 250 } else { 50 },
+    )
+    .await?;
+
+    if let Some(ordering) = ordering {
+        sort_by_ordering(&mut items, ordering);
+        if let Some(limit) = limit {
+            items.truncate(limit);
+        }
+    }
+
+    if output.is_json() || output.has_template() {
+        print_json_owned(json!(items), output)?;
+        return Ok(());
+    }
+
+    filter_values(&mut items, &output.filters);
+    if let Some(sort_key) = output.json.sort.as_deref() {
+        sort_values(&mut items, sort_key, output.json.order);
+    }
+
+    ensure_non_empty(&items, output)?;
+    let noun = if view.project { "projects" } else { "issues" };
+    println!("{}", view.name.bold());
+    if items.is_empty() {
+        println!("No {} found.", noun);
+        return Ok(());
+    }
+
+    let width = display_options().max_width(50);
+
+    if let Some(grouping) = grouping {
+        // Build groups preserving (sorted) insertion order
+        let mut groups: Vec<(String, Vec<&serde_json::Value>)> = Vec::new();
+        let mut seen: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
+        for item in &items {
+            for key in group_keys(item, grouping) {
+                if let Some(&idx) = seen.get(&key) {
+                    groups[idx].1.push(item);
+                } else {
+                    seen.insert(key.clone(), groups.len());
+                    groups.push((key, vec![item]));
+                }
+            }
+        }
+
+        for (group_name, group_items) in &groups {
+            println!("\n{} ({})", group_name.cyan().bold(), group_items.len());
+            println!("{}", "-".repeat(50));
+            for item in group_items {
+                let id = item["identifier"].as_str().unwrap_or("");
+                let title = item["title"].as_str().or(item["name"].as_str()).unwrap_or("");
+                println!("  {} {}", id.cyan(), truncate(title, width));
+            }
+        }
+        println!("\n{} {} in {} groups", items.len(), noun, groups.len());
+        return Ok(());
+    }
+
+    let table = if view.project {
+        let rows: Vec<ViewProjectRow> = items
+            .iter()
+            .map(|p| ViewProjectRow {
+                name: truncate(p["name"].as_str().unwrap_or(""), width),
+                state: p["state"].as_str().unwrap_or("-").to_string(),
+                target: p["targetDate"].as_str().unwrap_or("-").to_string(),
+                id: p["id"].as_str().unwrap_or("").to_string(),
+            })
+            .collect();
+        Table::new(rows).to_string()
+    } else {
+        let rows: Vec<ViewIssueRow> = items
+            .iter()
+            .map(|i| ViewIssueRow {
+                identifier: i["identifier"].as_str().unwrap_or("").to_string(),
+                title: truncate(i["title"].as_str().unwrap_or(""), width),
+                state: i["state"]["name"].as_str().unwrap_or("-").to_string(),
+                priority: priority_to_string(i["priority"].as_i64()),
+                assignee: i["assignee"]["name"].as_str().unwrap_or("-").to_string(),
+            })
+            .collect();
+        Table::new(rows).to_string()
+    };
+    println!("{}", table);
+    println!("\n{} {}", items.len(), noun);
+
+    Ok(())
+}
+
+async fn publish_view(
+    name: &str,
+    shared: bool,
+    team: Option<String>,
+    keep: bool,
+    output: &OutputOptions,
+) -> Result<()> {
+    let mut config = config::load_config()?;
+    let view = config
+        .views
+        .get(name)
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("Local view not found: {}. See: linear views list --local", name))?;
+
+    let client = LinearClient::new()?;
+
+    let mut input = json!({
+        "name": name,
+        "shared": shared,
+    });
+    if let Some(ref desc) = view.description {
+        input["description"] = json!(desc);
+    }
+    if let Some(ref t) = team.or_else(|| view.team.clone()) {
+        let team_id = resolve_team_id(&client, t, &output.cache).await?;
+        input["teamId"] = json!(team_id);
+    }
+    if is_project_view(&view) {
+        input["projectFilterData"] = local_project_filter(&view);
+    } else {
+        input["filterData"] = local_issue_filter(&view);
+    }
+
+    if output.dry_run {
+        if output.is_json() || output.has_template() {
+            print_json_owned(
+                json!({
+                    "dry_run": true,
+                    "would_create": { "input": input },
+                    "would_remove_local": !keep,
+                }),
+                output,
+            )?;
+        } else {
+            println!("{}", "[DRY RUN] Would publish local view:".yellow().bold());
+            println!("  Name: {}", name);
+            println!("  Shared: {}", if shared { "Yes" } else { "No" });
+        }
+        return Ok(());
+    }
+
+    let mutation = r#"
+        mutation($input: CustomViewCreateInput!) {
+            customViewCreate(input: $input) {
+                success
+                customView { id name shared }
+            }
+        }
+    "#;
+
+    let result = client
+        .mutate(mutation, Some(json!({ "input": input })))
+        .await?;
+
+    if result["data"]["customViewCreate"]["success"].as_bool() != Some(true) {
+        anyhow::bail!("Failed to publish view '{}'", name);
+    }
+    let created = &result["data"]["customViewCreate"]["customView"];
+
+    if !keep {
+        config.views.remove(name);
+        config::save_config(&config)?;
+    }
+
+    if output.is_json() || output.has_template() {
+        print_json(created, output)?;
+        return Ok(());
+    }
+
+    println!(
+        "{} Published view: {}",
+        "+".green(),
+        created["name"].as_str().unwrap_or(name)
+    );
+    println!("  ID: {}", created["id"].as_str().unwrap_or(""));
+    if !keep {
+        println!("  Removed local view from config.toml");
+    }
+    if view.order_by.is_some() || view.group_by.is_some() {
+        println!(
+            "  {} Ordering and grouping are not published; set them in Linear.",
+            "Note:".yellow()
+        );
+    }
+
+    Ok(())
+}
+
 /// Fetch the filterData for a custom view (used by issues list --view).
 pub async fn fetch_view_filter(
     client: &LinearClient,
@@ -649,3 +1342,103 @@ pub async fn fetch_view_project_filter(
 
     Ok(filter.clone())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_local_issue_filter() {
+        let view = LocalView {
+            team: Some("ENG".to_string()),
+            state_type: vec!["started".to_string()],
+            assignee: Some("me".to_string()),
+            labels: vec!["bug".to_string()],
+            priority: vec![1, 2],
+            filter: Some(json!({ "estimate": { "gte": 3 } })),
+            ..Default::default()
+        };
+        let filter = local_issue_filter(&view);
+        assert_eq!(filter["team"], json!({ "key": { "eqIgnoreCase": "ENG" } }));
+        assert_eq!(filter["state"], json!({ "type": { "in": ["started"] } }));
+        assert_eq!(filter["assignee"], json!({ "isMe": { "eq": true } }));
+        assert_eq!(filter["labels"], json!({ "some": { "name": { "in": ["bug"] } } }));
+        assert_eq!(filter["priority"], json!({ "in": [1, 2] }));
+        assert_eq!(filter["estimate"], json!({ "gte": 3 }));
+    }
+
+    #[test]
+    fn test_local_filter_raw_overrides_generated() {
+        let view = LocalView {
+            assignee: Some("none".to_string()),
+            filter: Some(json!({ "assignee": { "name": { "eq": "Alice" } } })),
+            ..Default::default()
+        };
+        let filter = local_issue_filter(&view);
+        assert_eq!(filter["assignee"], json!({ "name": { "eq": "Alice" } }));
+        assert_eq!(local_issue_filter(&LocalView::default()), json!({}));
+    }
+
+    #[test]
+    fn test_local_project_filter() {
+        let view = LocalView {
+            model: Some("project".to_string()),
+            team: Some("ENG".to_string()),
+            state: vec!["In Progress".to_string()],
+            ..Default::default()
+        };
+        assert!(is_project_view(&view));
+        let filter = local_project_filter(&view);
+        assert_eq!(
+            filter["accessibleTeams"],
+            json!({ "some": { "key": { "eqIgnoreCase": "ENG" } } })
+        );
+        assert_eq!(filter["status"], json!({ "name": { "in": ["In Progress"] } }));
+    }
+
+    #[test]
+    fn test_normalize_view_preferences() {
+        assert_eq!(normalize_grouping("workflowState").unwrap(), Some("state"));
+        assert_eq!(normalize_grouping("noGrouping").unwrap(), None);
+        assert!(normalize_grouping("bogus").is_err());
+        assert_eq!(normalize_ordering("manual").unwrap(), None);
+        assert_eq!(normalize_ordering("targetDate").unwrap(), Some("dueDate"));
+        assert!(normalize_ordering("bogus").is_err());
+    }
+
+    #[test]
+    fn test_sort_by_priority_puts_none_last() {
+        let mut items = vec![
+            json!({ "identifier": "A-1", "priority": 0 }),
+            json!({ "identifier": "A-2", "priority": 3 }),
+            json!({ "identifier": "A-3", "priority": 1 }),
+        ];
+        sort_by_ordering(&mut items, "priority");
+        let ids: Vec<&str> = items.iter().map(|i| i["identifier"].as_str().unwrap()).collect();
+        assert_eq!(ids, vec!["A-3", "A-2", "A-1"]);
+    }
+
+    #[test]
+    fn test_sort_by_due_date_missing_last() {
+        let mut items = vec![
+            json!({ "identifier": "A-1" }),
+            json!({ "identifier": "A-2", "dueDate": "2024-03-01" }),
+            json!({ "identifier": "A-3", "dueDate": "2024-01-01" }),
+        ];
+        sort_by_ordering(&mut items, "dueDate");
+        let ids: Vec<&str> = items.iter().map(|i| i["identifier"].as_str().unwrap()).collect();
+        assert_eq!(ids, vec!["A-3", "A-2", "A-1"]);
+    }
+
+    #[test]
+    fn test_group_keys_labels_and_cycle() {
+        let issue = json!({
+            "labels": { "nodes": [{ "name": "bug" }, { "name": "ui" }] },
+            "cycle": { "number": 7, "name": null },
+        });
+        assert_eq!(group_keys(&issue, "label"), vec!["bug", "ui"]);
+        assert_eq!(group_keys(&issue, "cycle"), vec!["Cycle 7"]);
+        assert_eq!(group_keys(&json!({}), "label"), vec!["No Label"]);
+        assert_eq!(group_keys(&json!({ "state": "started" }), "state"), vec!["started"]);
+    }
+}
@@ -1,8 +1,8 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
 
 #[cfg(unix)]
@@ -34,6 +34,286 @@ pub struct Config {
     // Legacy field for backward compatibility
     #[serde(skip_serializing_if = "Option::is_none")]
     pub api_key: Option<String>,
+    /// Local-only saved views, keyed by name (see `views run` / `views publish`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub views: BTreeMap<String, LocalView>,
+    /// Git integration settings
+    #[serde(default, skip_serializing_if = "GitConfig::is_empty")]
+    pub git: GitConfig,
+    /// Time tracking settings
+    #[serde(default, skip_serializing_if = "TimeConfig::is_empty")]
+    pub time: TimeConfig,
+    /// Capacity settings for `users workload`
+    #[serde(default, skip_serializing_if = "WorkloadConfig::is_empty")]
+    pub workload: WorkloadConfig,
+    /// `[[sla]]` rules for `metrics aging`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sla: Vec<SlaRule>,
+}
+
+/// `[time]` section of the config file.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct TimeConfig {
+    /// `time stop` rounds to a multiple of this many minutes (e.g. 15)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub round: Option<u32>,
+    /// "up" (default), "nearest" or "down"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub round_mode: Option<String>,
+}
+
+impl TimeConfig {
+    pub fn is_empty(&self) -> bool {
+        self == &TimeConfig::default()
+    }
+}
+
+/// `[workload]` section of the config file.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct WorkloadConfig {
+    /// Estimate points per person per cycle
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capacity: Option<f64>,
+    /// Per-person capacity, keyed by email or name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub people: BTreeMap<String, f64>,
+}
+
+impl WorkloadConfig {
+    pub fn is_empty(&self) -> bool {
+        self == &WorkloadConfig::default()
+    }
+}
+
+/// One `[[sla]]` entry. An open issue matching the priority and label
+/// breaches when it has spent longer than `max` in `state`, or, without a
+/// state, when it is older than `max`.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct SlaRule {
+    /// Shown in reports; defaults to a description of the rule
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// urgent, high, medium, low, none (default: any)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<String>,
+    /// Issue label (default: any)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// Workflow state name or type
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    /// Limit such as "4h", "2d" or "1w"
+    pub max: String,
+}
+
+/// `[git]` section of the config file.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct GitConfig {
+    /// Magic word -> target state (name or state type) for `git sync-states`.
+    /// Extends the built-in words; an empty value disables a word.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub magic_words: BTreeMap<String, String>,
+    /// Per-team overrides of `magic_words`, keyed by team key
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub team_magic_words: BTreeMap<String, BTreeMap<String, String>>,
+    /// PR title template for `git pr` (`{{field}}` placeholders)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pr_title_template: Option<String>,
+    /// PR body template for `git pr` (`{{field}}` placeholders)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pr_body_template: Option<String>,
+    /// Remote host -> forge (github, gitlab, gitea, forgejo) for self-hosted remotes
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub forges: BTreeMap<String, String>,
+    /// Where `git worktree` puts issue worktrees; relative to the repo root,
+    /// `{{repo}}` is the repo directory name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub worktree_dir: Option<String>,
+    /// Branch naming for issue branches
+    #[serde(default, skip_serializing_if = "BranchConfig::is_empty")]
+    pub branch: BranchConfig,
+    /// CODEOWNERS owner (`@handle`, `@org/team`) -> Linear user email or name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub owners: BTreeMap<String, String>,
+}
+
+/// `[git.branch]`: how issue branches are named. When nothing is set, Linear's
+/// own `branchName` is used.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct BranchConfig {
+    /// e.g. `{{user}}/{{identifier}}-{{slug}}` or `feature/{{IDENTIFIER}}`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+    /// Maximum slug length (default 50)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug_length: Option<usize>,
+    /// Slug case: "lower" (default) or "preserve"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub case: Option<String>,
+    /// Issue label -> branch prefix, e.g. `bug = "fix/"`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub label_prefixes: BTreeMap<String, String>,
+}
+
+impl BranchConfig {
+    pub fn is_empty(&self) -> bool {
+        self == &BranchConfig::default()
+    }
+}
+
+impl GitConfig {
+    pub fn is_empty(&self) -> bool {
+        self == &GitConfig::default()
+    }
+
+    /// Overlay `other` (e.g. a repo's `[git]` section): its maps extend ours,
+    /// its set options replace ours.
+    pub fn layer(&mut self, other: GitConfig) {
+        self.magic_words.extend(other.magic_words);
+        for (team, words) in other.team_magic_words {
+            self.team_magic_words.entry(team).or_default().extend(words);
+        }
+        self.forges.extend(other.forges);
+        self.owners.extend(other.owners);
+        if other.pr_title_template.is_some() {
+            self.pr_title_template = other.pr_title_template;
+        }
+        if other.pr_body_template.is_some() {
+            self.pr_body_template = other.pr_body_template;
+        }
+        if other.worktree_dir.is_some() {
+            self.worktree_dir = other.worktree_dir;
+        }
+        let branch = other.branch;
+        if branch.template.is_some() {
+            self.branch.template = branch.template;
+        }
+        if branch.slug_length.is_some() {
+            self.branch.slug_length = branch.slug_length;
+        }
+        if branch.case.is_some() {
+            self.branch.case = branch.case;
+        }
+        self.branch.label_prefixes.extend(branch.label_prefixes);
+    }
+}
+
+/// Name of the per-repository config file.
+pub const REPO_CONFIG_FILE: &str = ".linear.toml";
+
+/// Per-repository defaults from a `.linear.toml` in the current directory or
+/// a parent, layered over the global config.
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+pub struct RepoConfig {
+    /// Default team for `issues create` and `sync push`
+    #[serde(default)]
+    pub team: Option<String>,
+    /// Default project for `issues create`
+    #[serde(default)]
+    pub project: Option<String>,
+    /// Labels added to issues created from this repo
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// Branch name template for issue branches (shorthand for `[git.branch] template`)
+    #[serde(default)]
+    pub branch_template: Option<String>,
+    /// Base branch for `git pr`
+    #[serde(default)]
+    pub pr_base: Option<String>,
+    /// Workflow action (start, stop, close) -> state name
+    #[serde(default)]
+    pub states: BTreeMap<String, String>,
+    /// Overrides for the global `[git]` section
+    #[serde(default)]
+    pub git: GitConfig,
+}
+
+/// Find `.linear.toml` in `start` or its nearest parent that has one.
+fn find_repo_config_from(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .map(|dir| dir.join(REPO_CONFIG_FILE))
+        .find(|path| path.is_file())
+}
+
+/// The repo config for the current directory, with the path it came from.
+pub fn find_repo_config() -> Result<Option<(PathBuf, RepoConfig)>> {
+    let cwd = std::env::current_dir()?;
+    let Some(path) = find_repo_config_from(&cwd) else {
+        return Ok(None);
+    };
+    let content = fs::read_to_string(&path)?;
+    let repo: RepoConfig = toml::from_str(&content)
+        .map_err(|e| anyhow::anyhow!("Invalid {}: {}", path.display(), e))?;
+    Ok(Some((path, repo)))
+}
+
+/// The repo config for the current directory (empty when there is none).
+pub fn load_repo_config() -> Result<RepoConfig> {
+    Ok(find_repo_config()?.map(|(_, repo)| repo).unwrap_or_default())
+}
+
+/// `--team` if given, else the repo's default team.
+pub fn team_or_repo_default(team: Option<String>) -> Result<String> {
+    match team.or(load_repo_config()?.team) {
+        Some(t) => Ok(t),
+        None => anyhow::bail!("--team is required (or set team in .linear.toml)"),
+    }
+}
+
+/// Effective `[git]` settings: global config layered with the repo's.
+pub fn git_config() -> Result<GitConfig> {
+    let mut git = load_config()?.git;
+    let repo = load_repo_config()?;
+    git.layer(repo.git);
+    if repo.branch_template.is_some() {
+        git.branch.template = repo.branch_template;
+    }
+    Ok(git)
+}
+
+/// A saved view that lives only in the config file.
+///
+/// The fields form a small filter DSL that is translated into a Linear
+/// `IssueFilter` (or `ProjectFilter` when `model = "project"`). Anything the
+/// DSL can't express can be passed verbatim through `filter`.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct LocalView {
+    /// "issue" (default) or "project"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Team key, e.g. "ENG"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<String>,
+    /// Workflow state (or project status) names
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub state: Vec<String>,
+    /// Workflow state types: triage, backlog, unstarted, started, completed, canceled
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub state_type: Vec<String>,
+    /// Assignee name, "me", or "none"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
+    /// Label names (matches issues with any of them)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+    /// Priorities 0-4 (0 = none, 1 = urgent)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub priority: Vec<i64>,
+    /// Project name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    /// Client-side ordering: priority, title, state, dueDate, createdAt, updatedAt
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_by: Option<String>,
+    /// Grouping: state, priority, assignee, project, label, cycle
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group_by: Option<String>,
+    /// Raw filter merged over the generated one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<serde_json::Value>,
 }
 
 fn config_path() -> Result<PathBuf> {
@@ -263,6 +543,26 @@ pub fn show_config() -> Result<()> {
         println!("No workspace configured. Run: linear workspace add <name>");
     }
 
+    if let Some((path, repo)) = find_repo_config()? {
+        println!();
+        println!("Repo config: {}", path.display());
+        let settings = [
+            ("Team", repo.team),
+            ("Project", repo.project),
+            ("Labels", Some(repo.labels.join(", ")).filter(|l| !l.is_empty())),
+            ("Branch template", repo.branch_template),
+            ("PR base", repo.pr_base),
+        ];
+        for (name, value) in settings {
+            if let Some(value) = value {
+                println!("{:<16} {}", format!("{}:", name), value);
+            }
+        }
+        for (action, state) in &repo.states {
+            println!("{:<16} {}", format!("State ({}):", action), state);
+        }
+    }
+
     Ok(())
 }
 
@@ -513,10 +813,16 @@ mod tests {
             current: Some("default".to_string()),
             workspaces: HashMap::new(),
             api_key: None,
+            views: BTreeMap::new(),
+            git: GitConfig::default(),
+            time: TimeConfig::default(),
+            workload: WorkloadConfig::default(),
+            sla: Vec::new(),
         };
 
         let toml_str = toml::to_string_pretty(&config).unwrap();
         assert!(!toml_str.contains("api_key"));
+        assert!(!toml_str.contains("[time]"));
     }
 
     #[test]
@@ -600,6 +906,27 @@ mod tests {
         assert!(config.workspaces["oauth-ws"].oauth.is_some());
     }
 
+    #[test]
+    fn test_sla_rules_parse() {
+        let toml_str = r#"
+            [[sla]]
+            priority = "urgent"
+            state = "triage"
+            max = "4h"
+
+            [[sla]]
+            name = "Bugs fixed in a week"
+            label = "Bug"
+            max = "7d"
+        "#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.sla.len(), 2);
+        assert_eq!(config.sla[0].state.as_deref(), Some("triage"));
+        assert_eq!(config.sla[1].label.as_deref(), Some("Bug"));
+        assert!(config.sla[1].state.is_none());
+    }
+
     #[test]
     fn test_oauth_config_roundtrip_toml() {
         let mut config = Config::default();
@@ -709,4 +1036,115 @@ mod tests {
         assert!(ws.oauth.is_some());
         assert_eq!(ws.oauth.as_ref().unwrap().access_token, "oauth_tok");
     }
+
+    #[test]
+    fn test_config_local_views_parse() {
+        let toml_str = r#"
+            current = "default"
+
+            [workspaces.default]
+            api_key = "lin_api_key1"
+
+            [views.my-bugs]
+            team = "ENG"
+            labels = ["bug"]
+            state_type = ["unstarted", "started"]
+            assignee = "me"
+            order_by = "priority"
+            group_by = "state"
+
+            [views.my-bugs.filter]
+            estimate = { gte = 3 }
+        "#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        let view = &config.views["my-bugs"];
+        assert_eq!(view.team.as_deref(), Some("ENG"));
+        assert_eq!(view.labels, vec!["bug"]);
+        assert_eq!(view.state_type, vec!["unstarted", "started"]);
+        assert_eq!(view.group_by.as_deref(), Some("state"));
+        assert_eq!(
+            view.filter.as_ref().unwrap()["estimate"]["gte"],
+            serde_json::json!(3)
+        );
+
+        let toml_str = toml::to_string_pretty(&config).unwrap();
+        let parsed: Config = toml::from_str(&toml_str).unwrap();
+        assert_eq!(parsed.views["my-bugs"], *view);
+    }
+
+    #[test]
+    fn test_config_views_not_serialized_when_empty() {
+        let config = Config::default();
+        let toml_str = toml::to_string_pretty(&config).unwrap();
+        assert!(!toml_str.contains("views"));
+    }
+
+    #[test]
+    fn test_config_git_magic_words_parse() {
+        let toml_str = r#"
+            [git.magic_words]
+            fixes = "Done"
+            refs = "In Progress"
+
+            [git.team_magic_words.ENG]
+            fixes = "Deployed"
+        "#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.git.magic_words["fixes"], "Done");
+        assert_eq!(config.git.team_magic_words["ENG"]["fixes"], "Deployed");
+        assert!(!config.git.is_empty());
+        assert!(Config::default().git.is_empty());
+    }
+
+    #[test]
+    fn test_repo_config_parse_and_layer() {
+        let toml_str = r#"
+            team = "ENG"
+            project = "Payments"
+            labels = ["backend"]
+            pr_base = "develop"
+
+            [states]
+            start = "In Review"
+
+            [git]
+            pr_title_template = "{{identifier}}: {{title}}"
+
+            [git.magic_words]
+            fixes = "Deployed"
+        "#;
+        let repo: RepoConfig = toml::from_str(toml_str).unwrap();
+        assert_eq!(repo.team.as_deref(), Some("ENG"));
+        assert_eq!(repo.labels, vec!["backend"]);
+        assert_eq!(repo.states["start"], "In Review");
+
+        let mut git = GitConfig {
+            pr_body_template: Some("Linear: {{url}}".to_string()),
+            ..Default::default()
+        };
+        git.magic_words.insert("fixes".into(), "Done".into());
+        git.magic_words.insert("refs".into(), "started".into());
+        git.layer(repo.git);
+        assert_eq!(git.magic_words["fixes"], "Deployed");
+        assert_eq!(git.magic_words["refs"], "started");
+        assert_eq!(git.pr_title_template.as_deref(), Some("{{identifier}}: {{title}}"));
+        assert_eq!(git.pr_body_template.as_deref(), Some("Linear: {{url}}"));
+    }
+
+    #[test]
+    fn test_find_repo_config_walks_up() {
+        let tmp = std::env::temp_dir().join(format!("linear-repo-cfg-{}", std::process::id()));
+        let nested = tmp.join("services/api/src");
+        fs::create_dir_all(&nested).unwrap();
+        assert_eq!(find_repo_config_from(&nested), None);
+        fs::write(tmp.join(REPO_CONFIG_FILE), "team = \"ENG\"").unwrap();
+        fs::write(tmp.join("services/api").join(REPO_CONFIG_FILE), "team = \"API\"").unwrap();
+        assert_eq!(
+            find_repo_config_from(&nested),
+            Some(tmp.join("services/api").join(REPO_CONFIG_FILE))
+        );
+        fs::remove_dir_all(&tmp).unwrap();
+    }
 }
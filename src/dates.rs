@@ -132,6 +132,18 @@ fn add_months(from: NaiveDate, months: i32) -> NaiveDate {
     NaiveDate::from_ymd_opt(new_year, new_month, from.day().min(28)).unwrap_or(from)
 }
 
+/// Whether `candidate` is a later RFC 3339 timestamp than `current`.
+pub fn is_later(candidate: &str, current: Option<&str>) -> bool {
+    let Some(current) = current else { return true };
+    match (
+        chrono::DateTime::parse_from_rfc3339(candidate),
+        chrono::DateTime::parse_from_rfc3339(current),
+    ) {
+        (Ok(a), Ok(b)) => a > b,
+        _ => candidate > current,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -239,4 +251,17 @@ mod tests {
         assert_eq!(parse_due_date("TODAY"), Some(today.clone()));
         assert_eq!(parse_due_date("Today"), Some(today));
     }
+
+    #[test]
+    fn test_is_later() {
+        assert!(is_later("2026-10-01T10:00:00.000Z", None));
+        assert!(is_later(
+            "2026-10-01T10:00:00.001Z",
+            Some("2026-10-01T10:00:00.000Z")
+        ));
+        assert!(!is_later(
+            "2026-09-30T23:59:59.999Z",
+            Some("2026-10-01T00:00:00.000Z")
+        ));
+    }
 }
@@ -8,6 +8,7 @@ pub enum ErrorKind {
     NotFound,    // exit code 2
     Auth,        // exit code 3
     RateLimited, // exit code 4
+    CheckFailed, // exit code 5
 }
 
 impl ErrorKind {
@@ -17,6 +18,7 @@ impl ErrorKind {
             ErrorKind::NotFound => 2,
             ErrorKind::Auth => 3,
             ErrorKind::RateLimited => 4,
+            ErrorKind::CheckFailed => 5,
         }
     }
 
@@ -59,6 +61,11 @@ impl CliError {
         Self::new(ErrorKind::RateLimited, message)
     }
 
+    /// A `--check` run found problems (the command itself succeeded)
+    pub fn check_failed(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::CheckFailed, message)
+    }
+
     pub fn code(&self) -> u8 {
         self.kind.exit_code()
     }
@@ -183,6 +190,7 @@ mod tests {
         assert_eq!(ErrorKind::NotFound.exit_code(), 2);
         assert_eq!(ErrorKind::Auth.exit_code(), 3);
         assert_eq!(ErrorKind::RateLimited.exit_code(), 4);
+        assert_eq!(ErrorKind::CheckFailed.exit_code(), 5);
     }
 
     #[test]
@@ -191,6 +199,7 @@ mod tests {
         assert!(!ErrorKind::NotFound.is_retryable());
         assert!(!ErrorKind::Auth.is_retryable());
         assert!(ErrorKind::RateLimited.is_retryable());
+        assert!(!ErrorKind::CheckFailed.is_retryable());
     }
 
     #[test]
@@ -1,5 +1,7 @@
 mod api;
 mod cache;
+mod charts;
+mod codeowners;
 mod commands;
 mod config;
 mod dates;
@@ -13,6 +15,7 @@ mod output;
 mod pagination;
 mod priority;
 mod retry;
+mod search_index;
 mod text;
 #[allow(dead_code)]
 mod types;
@@ -22,12 +25,11 @@ use anyhow::Result;
 use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
 use clap_complete::{generate, Shell};
 use commands::{
-    auth, bulk, comments, cycles, doctor, documents, export, favorites, git, history, initiatives,
+    auth, bulk, comments, context, cycles, doctor, documents, export, favorites, git, history, initiatives,
     interactive, issues, labels, metrics, notifications, projects, relations, roadmaps, search,
     statuses, sync, teams, templates, time, triage, uploads, users, views, watch, webhooks,
 };
 use error::CliError;
-use output::print_json_owned;
 use output::{parse_filters, JsonOutputOptions, OutputOptions, SortOrder};
 use pagination::PaginationOptions;
 use std::io::IsTerminal;
@@ -297,7 +299,8 @@ enum Commands {
     linear projects list                    # List all projects
     linear p list --archived                # Include archived projects
     linear p get PROJECT_ID                 # View project details
-    linear p create "Q1 Roadmap" -t ENG     # Create a project"#)]
+    linear p create "Q1 Roadmap" -t ENG     # Create a project
+    linear p update-status PROJ --health at-risk --body -  # Post a status update"#)]
     Projects {
         #[command(subcommand)]
         action: projects::ProjectCommands,
@@ -339,7 +342,8 @@ enum Commands {
     #[command(after_help = r#"EXAMPLES:
     linear users list                       # List all users
     linear u list --team ENG                # List team members
-    linear u me                             # View your profile"#)]
+    linear u me                             # View your profile
+    linear u workload -t ENG                # Per-member load vs capacity"#)]
     Users {
         #[command(subcommand)]
         action: users::UserCommands,
@@ -350,7 +354,10 @@ enum Commands {
     linear cycles list -t ENG               # List team cycles
     linear c current -t ENG                 # Show current cycle
     linear c create -t ENG --name "Sprint 5" # Create a cycle
-    linear c update ID --name "Sprint 5b"   # Update cycle name"#)]
+    linear c update ID --name "Sprint 5b"   # Update cycle name
+    linear c close -t ENG                   # Roll unfinished issues to next cycle
+    linear c plan -t ENG --capacity 30      # Propose backlog for next cycle
+    linear c report 12 -t ENG               # Markdown retrospective"#)]
     Cycles {
         #[command(subcommand)]
         action: cycles::CycleCommands,
@@ -375,11 +382,15 @@ enum Commands {
         #[command(subcommand)]
         action: documents::DocumentCommands,
     },
-    /// Search across Linear - find issues and projects
+    /// Search across Linear - issues, projects, documents, comments, initiatives
     #[command(alias = "s")]
     #[command(after_help = r#"EXAMPLES:
     linear search issues "auth bug"         # Search issues
-    linear s projects "backend"             # Search projects"#)]
+    linear s projects "backend"             # Search projects
+    linear s documents "spec"               # Search documents
+    linear s all "auth"                     # Search everything
+    linear s index --team ENG               # Build local search index
+    linear s issues --offline "title:login" # Search the local index"#)]
     Search {
         #[command(subcommand)]
         action: search::SearchCommands,
@@ -408,8 +419,14 @@ enum Commands {
     #[command(after_help = r#"EXAMPLES:
     linear git checkout LIN-123             # Checkout issue branch
     linear g branch LIN-123                 # Show branch name
-    linear g pr LIN-123                     # Create GitHub PR
-    linear g pr LIN-123 --draft             # Create draft PR"#)]
+    linear g pr LIN-123                     # Create PR (gh/glab/tea/fj)
+    linear g pr LIN-123 --draft             # Create draft PR
+    linear g hooks install                  # Add issue refs to commit messages
+    linear g changelog v1.2.0..HEAD         # Release notes from referenced issues
+    linear g sync-states --range A..B       # Apply "fixes LIN-123" transitions
+    linear g stack LIN-101                  # Stacked branches for sub-issues
+    linear g worktree LIN-123               # Issue branch in its own worktree
+    linear g suggest-assignee src/foo.rs    # Assignee from CODEOWNERS"#)]
     Git {
         #[command(subcommand)]
         action: git::GitCommands,
@@ -460,7 +477,9 @@ enum Commands {
     #[command(alias = "tm")]
     #[command(after_help = r#"EXAMPLES:
     linear time log LIN-123 2h              # Log 2 hours on issue
-    linear tm list --issue LIN-123          # List time entries"#)]
+    linear tm list --issue LIN-123          # List time entries
+    linear tm report --group-by user,day    # Timesheet matrix with totals
+    linear tm start LIN-123                 # Start a timer; `tm stop` logs it"#)]
     Time {
         #[command(subcommand)]
         action: time::TimeCommands,
@@ -487,17 +506,24 @@ Use arrow keys to navigate, Enter to select, q to quit."#)]
         #[arg(short, long)]
         team: Option<String>,
     },
-    /// Detect current Linear issue from git branch - for AI agents
+    /// Detect current Linear issue from the repo - for AI agents
     #[command(alias = "ctx")]
     #[command(after_help = r#"EXAMPLES:
-    linear context                          # Show current issue from branch
+    linear context                          # Show current issue ID
     linear ctx --output json                # Get as JSON for parsing
-
-Detects issue ID from branch names like:
-  - lin-123-fix-bug
-  - feature/LIN-456-new-feature
-  - scw-789-some-task"#)]
-    Context,
+    linear ctx --full --output json         # Description, sub-issues, relations,
+                                            # comments and linked PRs
+
+Looks for the issue, in order:
+  1. A .linear file in this directory or a parent (up to the repo root)
+     containing LIN-123 or issue = "LIN-123"
+  2. The git branch or jj bookmark, e.g. lin-123-fix-bug, feature/LIN-456-x
+  3. Recent commit messages / jj descriptions (LIN-123: ..., Linear-Issue: trailers)"#)]
+    Context {
+        /// Include description, sub-issues, relations, comments and linked PRs
+        #[arg(long)]
+        full: bool,
+    },
     /// Manage favorites - quick access to issues/projects
     #[command(alias = "fav")]
     #[command(after_help = r#"EXAMPLES:
@@ -543,9 +569,13 @@ Detects issue ID from branch names like:
     /// View metrics - velocity, burndown, progress
     #[command(alias = "mt")]
     #[command(after_help = r#"EXAMPLES:
-    linear metrics cycle CYCLE_ID           # Cycle metrics
+    linear metrics cycle CYCLE_ID           # Cycle metrics + burndown chart
+    linear mt cycle 12 -t ENG --svg out.svg # Export burndown as SVG
     linear mt project PROJECT_ID            # Project progress
-    linear mt velocity TEAM --cycles 5      # Team velocity"#)]
+    linear mt velocity TEAM --cycles 5      # Team velocity
+    linear mt flow -t ENG --since -90d      # Lead/cycle time, throughput, WIP
+    linear mt forecast --project Payments   # Monte Carlo completion dates
+    linear mt aging -t ENG --check          # SLA breaches, exit 5 if any"#)]
     Metrics {
         #[command(subcommand)]
         action: metrics::MetricsCommands,
@@ -567,7 +597,9 @@ Detects issue ID from branch names like:
     #[command(after_help = r#"EXAMPLES:
     linear export csv --team ENG            # Export team issues to CSV
     linear exp csv -f issues.csv            # Export to file
-    linear exp markdown --team ENG          # Export as Markdown"#)]
+    linear exp markdown --team ENG          # Export as Markdown
+    linear exp ndjson --since-last-run s.json # Incremental NDJSON export
+    linear exp sqlite team.db --team ENG    # Normalized SQLite database"#)]
     Export {
         #[command(subcommand)]
         action: export::ExportCommands,
@@ -588,6 +620,8 @@ Detects issue ID from branch names like:
     linear v list --shared                  # List shared views only
     linear v get "My View"                  # View details
     linear v create "Bug Triage" --shared   # Create a shared view
+    linear v run "Bug Triage"               # Run a view with its ordering/grouping
+    linear v publish my-bugs                # Promote a local config.toml view
     linear v delete VIEW_ID --force         # Delete a view"#)]
     Views {
         #[command(subcommand)]
@@ -878,7 +912,8 @@ async fn async_main() -> Result<()> {
     Ok(())
 }
 
-/// Categorize error for exit codes: 1=general error, 2=not found, 3=auth error
+/// Categorize error for exit codes: 1=general error, 2=not found, 3=auth error,
+/// 5=`--check` failed
 fn categorize_error(e: &anyhow::Error) -> u8 {
     if let Some(cli_error) = e.downcast_ref::<CliError>() {
         return cli_error.code();
@@ -954,7 +989,7 @@ async fn run_command(
         Commands::Search { action } => search::handle(action, output).await?,
         Commands::Sync { action } => sync::handle(action, output).await?,
         Commands::Statuses { action } => statuses::handle(action, output).await?,
-        Commands::Git { action } => git::handle(action).await?,
+        Commands::Git { action } => git::handle(action, output).await?,
         Commands::Bulk { action } => bulk::handle(action, output).await?,
         Commands::Cache { action } => commands::cache::handle(action).await?,
         Commands::Notifications { action } => notifications::handle(action, output).await?,
@@ -962,7 +997,9 @@ async fn run_command(
         Commands::Time { action } => time::handle(action, output).await?,
         Commands::Uploads { action } => uploads::handle(action).await?,
         Commands::Interactive { team } => interactive::run(team).await?,
-        Commands::Context => handle_context(output, agent_opts, retry).await?,
+        Commands::Context { full } => {
+            context::handle(full, output, agent_opts.id_only, retry).await?
+        }
         Commands::Favorites { action } => favorites::handle(action, output).await?,
         Commands::Roadmaps { action } => {
             let pagination = PaginationOptions::default();
@@ -1109,99 +1146,3 @@ impl Drop for PagerGuard {
         let _ = self.child.wait();
     }
 }
-
-/// Handle the context command - detect current Linear issue from git branch
-async fn handle_context(
-    output: &OutputOptions,
-    agent_opts: AgentOptions,
-    retry: u32,
-) -> Result<()> {
-    // Get current git branch
-    let branch_output = std::process::Command::new("git")
-        .args(["rev-parse", "--abbrev-ref", "HEAD"])
-        .output();
-
-    let branch = match branch_output {
-        Ok(output) if output.status.success() => {
-            String::from_utf8_lossy(&output.stdout).trim().to_string()
-        }
-        _ => {
-            anyhow::bail!("Not in a git repository or git not available");
-        }
-    };
-
-    // Extract issue ID from branch name using regex
-    static ISSUE_RE: OnceLock<regex::Regex> = OnceLock::new();
-    let re = ISSUE_RE.get_or_init(|| regex::Regex::new(r"(?i)([a-z]+-\d+)").unwrap());
-
-    let issue_id = re
-        .find(&branch)
-        .map(|m| m.as_str().to_uppercase())
-        .ok_or_else(|| anyhow::anyhow!("No Linear issue ID found in branch: {}", branch))?;
-
-    if output.is_json() || output.has_template() {
-        if agent_opts.id_only {
-            print_json_owned(serde_json::json!(issue_id), output)?;
-            return Ok(());
-        }
-        // Fetch issue details for JSON output
-        let client = api::LinearClient::new_with_retry(retry)?;
-        let query = r#"
-            query($id: String!) {
-                issue(id: $id) {
-                    id
-                    identifier
-                    title
-                    state { name }
-                    assignee { name }
-                    priority
-                    url
-                }
-            }
-        "#;
-
-        let result = client
-            .query(query, Some(serde_json::json!({ "id": issue_id })))
-            .await;
-
-        match result {
-            Ok(data) => {
-                let issue = &data["data"]["issue"];
-                if issue.is_null() {
-                    print_json_owned(
-                        serde_json::json!({
-                            "branch": branch,
-                            "issue_id": issue_id,
-                            "found": false,
-                        }),
-                        output,
-                    )?;
-                } else {
-                    print_json_owned(
-                        serde_json::json!({
-                            "branch": branch,
-                            "issue_id": issue_id,
-                            "found": true,
-                            "issue": issue,
-                        }),
-                        output,
-                    )?;
-                }
-            }
-            Err(_) => {
-                print_json_owned(
-                    serde_json::json!({
-                        "branch": branch,
-                        "issue_id": issue_id,
-                        "found": false,
-                    }),
-                    output,
-                )?;
-            }
-        }
-    } else {
-        println!("{}", issue_id);
-    }
-
-    Ok(())
-}
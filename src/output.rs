@@ -286,7 +286,7 @@ pub fn ensure_non_empty(values: &[Value], output: &OutputOptions) -> Result<()>
     Ok(())
 }
 
-fn render_template(template: &str, value: &Value) -> String {
+pub fn render_template(template: &str, value: &Value) -> String {
     template_regex()
         .replace_all(template, |caps: &regex::Captures| {
             let path = caps.get(1).map(|m| m.as_str()).unwrap_or("");
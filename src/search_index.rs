@@ -0,0 +1,508 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::PathBuf;
+
+use crate::cache;
+
+const INDEX_FILENAME: &str = "search_index.json";
+const INDEX_VERSION: u32 = 1;
+
+/// Indexed issue fields, usable as `field:` prefixes in offline queries
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Field {
+    Identifier,
+    Title,
+    Label,
+    Description,
+    Comment,
+}
+
+impl Field {
+    const ALL: [Field; 5] = [
+        Field::Identifier,
+        Field::Title,
+        Field::Label,
+        Field::Description,
+        Field::Comment,
+    ];
+
+    /// Ranking weight: matches in titles count more than matches in comments
+    fn weight(self) -> f64 {
+        match self {
+            Field::Identifier => 4.0,
+            Field::Title => 3.0,
+            Field::Label => 2.0,
+            Field::Description => 1.0,
+            Field::Comment => 0.5,
+        }
+    }
+
+    fn parse(prefix: &str) -> Option<Field> {
+        match prefix.to_lowercase().as_str() {
+            "id" | "identifier" => Some(Field::Identifier),
+            "title" => Some(Field::Title),
+            "label" | "labels" => Some(Field::Label),
+            "description" | "desc" => Some(Field::Description),
+            "comment" | "comments" => Some(Field::Comment),
+            _ => None,
+        }
+    }
+
+    fn code(self) -> u8 {
+        self as u8
+    }
+
+    fn from_code(code: u8) -> Option<Field> {
+        Field::ALL.get(code as usize).copied()
+    }
+}
+
+/// Issue as stored in the local index
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexedIssue {
+    pub id: String,
+    pub identifier: String,
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    #[serde(default)]
+    pub comments: Vec<String>,
+    #[serde(default)]
+    pub state: String,
+    #[serde(default)]
+    pub team: String,
+    #[serde(default)]
+    pub priority: i64,
+    #[serde(default)]
+    pub url: String,
+    #[serde(default)]
+    pub updated_at: String,
+}
+
+/// (document index, field code, token position)
+type Posting = (u32, u8, u32);
+
+/// Inverted index over issue titles, descriptions, labels and comments.
+///
+/// Documents are refreshed incrementally (by `updatedAt` checkpoints per team
+/// scope); postings are not stored and are rebuilt locally on load and after
+/// each refresh.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SearchIndex {
+    version: u32,
+    /// Highest `updatedAt` indexed per scope (team key, or `*` for all teams)
+    #[serde(default)]
+    checkpoints: BTreeMap<String, String>,
+    #[serde(default)]
+    docs: Vec<IndexedIssue>,
+    /// Issue ID -> position in `docs`
+    #[serde(skip)]
+    positions: HashMap<String, usize>,
+    #[serde(skip)]
+    postings: HashMap<String, Vec<Posting>>,
+}
+
+/// A ranked offline search result
+#[derive(Debug)]
+pub struct SearchHit<'a> {
+    pub issue: &'a IndexedIssue,
+    pub score: f64,
+}
+
+#[derive(Debug, PartialEq)]
+struct Clause {
+    field: Option<Field>,
+    terms: Vec<String>,
+}
+
+impl SearchIndex {
+    pub fn path() -> Result<PathBuf> {
+        Ok(cache::cache_dir_path()?.join(INDEX_FILENAME))
+    }
+
+    /// Load the index from the cache directory (empty if missing or outdated).
+    /// A corrupt index is reported and replaced by an empty one, so the next
+    /// `search index` run rebuilds it from scratch.
+    pub fn load() -> Result<Self> {
+        let path = Self::path()?;
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read search index {}", path.display()))?;
+        let mut index: SearchIndex = match serde_json::from_str(&content) {
+            Ok(index) => index,
+            Err(e) => {
+                eprintln!(
+                    "Warning: Search index {} is corrupt ({}); it will be rebuilt",
+                    path.display(),
+                    e
+                );
+                return Ok(Self::default());
+            }
+        };
+        if index.version != INDEX_VERSION {
+            return Ok(Self::default());
+        }
+        index.positions = index
+            .docs
+            .iter()
+            .enumerate()
+            .map(|(i, d)| (d.id.clone(), i))
+            .collect();
+        index.rebuild();
+        Ok(index)
+    }
+
+    /// Save the index using an atomic temp-file rename
+    pub fn save(&mut self) -> Result<()> {
+        self.version = INDEX_VERSION;
+        let path = Self::path()?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let temp_path = path.with_extension("tmp");
+        fs::write(&temp_path, serde_json::to_string(self)?)?;
+        fs::rename(&temp_path, &path).context("Failed to update search index")?;
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.docs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.docs.is_empty()
+    }
+
+    pub fn checkpoint(&self, scope: &str) -> Option<&str> {
+        self.checkpoints.get(scope).map(|s| s.as_str())
+    }
+
+    pub fn set_checkpoint(&mut self, scope: &str, updated_at: &str) {
+        self.checkpoints
+            .insert(scope.to_string(), updated_at.to_string());
+    }
+
+    pub fn clear_checkpoint(&mut self, scope: &str) {
+        self.checkpoints.remove(scope);
+    }
+
+    /// Insert or replace an issue (call `rebuild` afterwards)
+    pub fn upsert(&mut self, issue: IndexedIssue) {
+        match self.positions.get(&issue.id) {
+            Some(&i) => self.docs[i] = issue,
+            None => {
+                self.positions.insert(issue.id.clone(), self.docs.len());
+                self.docs.push(issue);
+            }
+        }
+    }
+
+    /// Remove an issue by ID (call `rebuild` afterwards)
+    pub fn remove(&mut self, id: &str) {
+        let Some(i) = self.positions.remove(id) else {
+            return;
+        };
+        self.docs.swap_remove(i);
+        if let Some(moved) = self.docs.get(i) {
+            self.positions.insert(moved.id.clone(), i);
+        }
+    }
+
+    /// Recompute postings from the stored documents
+    pub fn rebuild(&mut self) {
+        let mut postings: HashMap<String, Vec<Posting>> = HashMap::new();
+        for (doc_idx, doc) in self.docs.iter().enumerate() {
+            let doc_idx = doc_idx as u32;
+            let fields: [(Field, Vec<&str>); 5] = [
+                (Field::Identifier, vec![doc.identifier.as_str()]),
+                (Field::Title, vec![doc.title.as_str()]),
+                (Field::Label, doc.labels.iter().map(|s| s.as_str()).collect()),
+                (Field::Description, vec![doc.description.as_str()]),
+                (Field::Comment, doc.comments.iter().map(|s| s.as_str()).collect()),
+            ];
+            for (field, texts) in fields {
+                let mut pos = 0u32;
+                for text in texts {
+                    for token in tokenize(text) {
+                        postings
+                            .entry(token)
+                            .or_default()
+                            .push((doc_idx, field.code(), pos));
+                        pos += 1;
+                    }
+                    // Gap between separate labels/comments so phrases don't span them
+                    pos += 1;
+                }
+            }
+        }
+        self.postings = postings;
+    }
+
+    /// Run a query: bare words and `field:word` clauses are ANDed, `"quoted text"`
+    /// matches a phrase. Results are ranked by field-weighted TF-IDF.
+    pub fn search(&self, query: &str) -> Vec<SearchHit<'_>> {
+        let clauses = parse_query(query);
+        if clauses.is_empty() {
+            return Vec::new();
+        }
+
+        let mut scores: Option<HashMap<u32, f64>> = None;
+        for clause in &clauses {
+            let clause_scores = self.match_clause(clause);
+            scores = Some(match scores {
+                None => clause_scores,
+                Some(prev) => prev
+                    .into_iter()
+                    .filter_map(|(doc, score)| clause_scores.get(&doc).map(|s| (doc, score + s)))
+                    .collect(),
+            });
+        }
+
+        let mut hits: Vec<SearchHit> = scores
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|(doc, score)| {
+                self.docs
+                    .get(doc as usize)
+                    .map(|issue| SearchHit { issue, score })
+            })
+            .collect();
+        hits.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.issue.identifier.cmp(&b.issue.identifier))
+        });
+        hits
+    }
+
+    fn match_clause(&self, clause: &Clause) -> HashMap<u32, f64> {
+        let mut scores: HashMap<u32, f64> = HashMap::new();
+        let lists: Option<Vec<&Vec<Posting>>> =
+            clause.terms.iter().map(|t| self.postings.get(t)).collect();
+        let Some(lists) = lists else { return scores };
+        let Some(first) = lists.first() else {
+            return scores;
+        };
+
+        let total_docs = self.docs.len().max(1) as f64;
+        let idf: f64 = lists
+            .iter()
+            .map(|list| {
+                let df = list.iter().map(|p| p.0).collect::<HashSet<_>>().len() as f64;
+                (1.0 + total_docs / df.max(1.0)).ln()
+            })
+            .sum();
+
+        let rest: Vec<HashSet<Posting>> = lists[1..]
+            .iter()
+            .map(|list| list.iter().copied().collect())
+            .collect();
+
+        for &(doc, code, pos) in first.iter() {
+            let Some(field) = Field::from_code(code) else {
+                continue;
+            };
+            if clause.field.is_some_and(|f| f != field) {
+                continue;
+            }
+            let is_phrase = rest
+                .iter()
+                .enumerate()
+                .all(|(i, set)| set.contains(&(doc, code, pos + i as u32 + 1)));
+            if is_phrase {
+                *scores.entry(doc).or_default() += field.weight() * idf;
+            }
+        }
+        scores
+    }
+}
+
+/// Lowercase alphanumeric tokens
+pub fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(|t| t.to_lowercase())
+        .collect()
+}
+
+/// Split a query into clauses, honoring `field:` prefixes and double quotes.
+fn parse_query(query: &str) -> Vec<Clause> {
+    let mut clauses = Vec::new();
+    let mut chars = query.chars().peekable();
+
+    while chars.peek().is_some() {
+        while chars.peek().is_some_and(|c| c.is_whitespace()) {
+            chars.next();
+        }
+        let mut word = String::new();
+        let mut quoted = String::new();
+        let mut has_quote = false;
+        while let Some(&c) = chars.peek() {
+            if c == '"' {
+                chars.next();
+                has_quote = true;
+                for c in chars.by_ref() {
+                    if c == '"' {
+                        break;
+                    }
+                    quoted.push(c);
+                }
+                break;
+            }
+            if c.is_whitespace() {
+                break;
+            }
+            word.push(c);
+            chars.next();
+        }
+
+        let (field, text) = match word.split_once(':') {
+            Some((prefix, rest)) => match Field::parse(prefix) {
+                Some(field) => (Some(field), rest.to_string()),
+                None => (None, word.clone()),
+            },
+            None => (None, word.clone()),
+        };
+        let text = if has_quote {
+            format!("{} {}", text, quoted)
+        } else {
+            text
+        };
+        let terms = tokenize(&text);
+        if !terms.is_empty() {
+            clauses.push(Clause { field, terms });
+        }
+    }
+
+    clauses
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(id: &str, identifier: &str, title: &str, labels: &[&str]) -> IndexedIssue {
+        IndexedIssue {
+            id: id.to_string(),
+            identifier: identifier.to_string(),
+            title: title.to_string(),
+            description: String::new(),
+            labels: labels.iter().map(|s| s.to_string()).collect(),
+            comments: Vec::new(),
+            state: "Todo".to_string(),
+            team: "ENG".to_string(),
+            priority: 0,
+            url: String::new(),
+            updated_at: String::new(),
+        }
+    }
+
+    fn sample_index() -> SearchIndex {
+        let mut index = SearchIndex::default();
+        index.upsert(issue("1", "ENG-1", "Login page crashes on Safari", &["bug"]));
+        index.upsert(issue("2", "ENG-2", "Redesign page for login", &["feature"]));
+        let mut with_comment = issue("3", "ENG-3", "Improve docs", &[]);
+        with_comment.comments = vec!["The login page crashes here too".to_string()];
+        index.upsert(with_comment);
+        index.rebuild();
+        index
+    }
+
+    fn identifiers(hits: &[SearchHit]) -> Vec<String> {
+        hits.iter().map(|h| h.issue.identifier.clone()).collect()
+    }
+
+    #[test]
+    fn test_tokenize() {
+        assert_eq!(tokenize("Fix ENG-123: Login!"), vec!["fix", "eng", "123", "login"]);
+    }
+
+    #[test]
+    fn test_parse_query_fields_and_phrases() {
+        let clauses = parse_query(r#"title:login "page crashes" label:bug"#);
+        assert_eq!(clauses.len(), 3);
+        assert_eq!(clauses[0].field, Some(Field::Title));
+        assert_eq!(clauses[1].terms, vec!["page", "crashes"]);
+        assert_eq!(clauses[1].field, None);
+        assert_eq!(clauses[2].field, Some(Field::Label));
+    }
+
+    #[test]
+    fn test_parse_query_quoted_field() {
+        let clauses = parse_query(r#"title:"login page""#);
+        assert_eq!(clauses.len(), 1);
+        assert_eq!(clauses[0].field, Some(Field::Title));
+        assert_eq!(clauses[0].terms, vec!["login", "page"]);
+    }
+
+    #[test]
+    fn test_search_ranks_title_over_comment() {
+        let index = sample_index();
+        let hits = index.search("crashes");
+        assert_eq!(identifiers(&hits), vec!["ENG-1", "ENG-3"]);
+    }
+
+    #[test]
+    fn test_search_phrase() {
+        let index = sample_index();
+        let hits = index.search(r#""login page""#);
+        assert_eq!(identifiers(&hits), vec!["ENG-1", "ENG-3"]);
+    }
+
+    #[test]
+    fn test_search_field_prefix() {
+        let index = sample_index();
+        assert_eq!(identifiers(&index.search("label:bug")), vec!["ENG-1"]);
+        assert_eq!(
+            identifiers(&index.search("title:login")),
+            vec!["ENG-1", "ENG-2"]
+        );
+    }
+
+    #[test]
+    fn test_search_terms_are_anded() {
+        let index = sample_index();
+        assert_eq!(identifiers(&index.search("login safari")), vec!["ENG-1"]);
+        assert!(index.search("login nonexistent").is_empty());
+    }
+
+    #[test]
+    fn test_upsert_and_remove() {
+        let mut index = sample_index();
+        index.upsert(issue("2", "ENG-2", "Renamed entirely", &[]));
+        index.remove("3");
+        index.rebuild();
+        assert_eq!(index.len(), 2);
+        assert_eq!(identifiers(&index.search("login")), vec!["ENG-1"]);
+    }
+
+    #[test]
+    fn test_upsert_after_remove_replaces_moved_doc() {
+        let mut index = sample_index();
+        index.remove("1");
+        index.upsert(issue("3", "ENG-3", "Docs for safari", &[]));
+        index.rebuild();
+        assert_eq!(index.len(), 2);
+        assert_eq!(identifiers(&index.search("safari")), vec!["ENG-3"]);
+    }
+
+    #[test]
+    fn test_postings_not_serialized() {
+        let mut index = sample_index();
+        index.version = INDEX_VERSION;
+        let json = serde_json::to_string(&index).unwrap();
+        assert!(!json.contains("postings"));
+
+        let mut loaded: SearchIndex = serde_json::from_str(&json).unwrap();
+        assert!(loaded.search("login").is_empty());
+        loaded.rebuild();
+        assert_eq!(identifiers(&loaded.search("safari")), vec!["ENG-1"]);
+    }
+}
@@ -83,6 +83,53 @@ pub fn strip_markdown(input: &str) -> String {
     result.trim().to_string()
 }
 
+/// Extract an excerpt of `text` around the first case-insensitive match of `query`
+/// (or, failing that, of any word in it), with `radius` characters of context on
+/// each side. The matched text is passed through `highlight`.
+pub fn snippet(
+    text: &str,
+    query: &str,
+    radius: usize,
+    highlight: impl Fn(&str) -> String,
+) -> Option<String> {
+    let chars: Vec<char> = text.split_whitespace().collect::<Vec<_>>().join(" ").chars().collect();
+    let lower: Vec<char> = chars.iter().map(|c| c.to_lowercase().next().unwrap_or(*c)).collect();
+
+    let mut needles: Vec<String> = vec![query.trim().to_string()];
+    needles.extend(
+        query
+            .split_whitespace()
+            .filter(|w| w.chars().count() >= 2)
+            .map(|w| w.trim_matches('"').to_string()),
+    );
+
+    let (start, len) = needles.iter().find_map(|needle| {
+        let needle: Vec<char> = needle.chars().flat_map(|c| c.to_lowercase()).collect();
+        if needle.is_empty() || needle.len() > lower.len() {
+            return None;
+        }
+        lower
+            .windows(needle.len())
+            .position(|w| w == needle.as_slice())
+            .map(|pos| (pos, needle.len()))
+    })?;
+
+    let from = start.saturating_sub(radius);
+    let to = (start + len + radius).min(chars.len());
+    let before: String = chars[from..start].iter().collect();
+    let matched: String = chars[start..start + len].iter().collect();
+    let after: String = chars[start + len..to].iter().collect();
+
+    Some(format!(
+        "{}{}{}{}{}",
+        if from > 0 { "..." } else { "" },
+        before,
+        highlight(&matched),
+        after,
+        if to < chars.len() { "..." } else { "" }
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -197,4 +244,23 @@ mod tests {
     fn test_strip_markdown_collapses_blank_lines() {
         assert_eq!(strip_markdown("a\n\n\n\nb"), "a\n\nb");
     }
+
+    #[test]
+    fn test_snippet_highlights_match() {
+        let text = "The login page crashes when the session expires";
+        let result = snippet(text, "CRASHES", 10, |m| format!("[{}]", m)).unwrap();
+        assert_eq!(result, "...ogin page [crashes] when the ...");
+    }
+
+    #[test]
+    fn test_snippet_falls_back_to_words() {
+        let text = "Token refresh\nfails silently";
+        let result = snippet(text, "silent token", 100, |m| format!("[{}]", m)).unwrap();
+        assert_eq!(result, "Token refresh fails [silent]ly");
+    }
+
+    #[test]
+    fn test_snippet_no_match() {
+        assert!(snippet("hello world", "absent", 10, |m| m.to_string()).is_none());
+    }
 }
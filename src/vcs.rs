@@ -1,5 +1,8 @@
 use anyhow::Result;
 use std::process::Command;
+use std::sync::OnceLock;
+
+use crate::config::BranchConfig;
 
 pub fn run_git_command(args: &[&str]) -> Result<String> {
     let output = Command::new("git").args(args).output()?;
@@ -12,10 +15,16 @@ pub fn run_git_command(args: &[&str]) -> Result<String> {
     }
 }
 
-pub fn generate_branch_name(identifier: &str, title: &str) -> String {
-    // Convert title to kebab-case for branch name
-    let slug: String = title
-        .to_lowercase()
+const DEFAULT_SLUG_LENGTH: usize = 50;
+
+/// Kebab-case `text`, cut to at most `max_len` bytes on a char boundary.
+fn slugify(text: &str, max_len: usize, lowercase: bool) -> String {
+    let text = if lowercase {
+        text.to_lowercase()
+    } else {
+        text.to_string()
+    };
+    let slug = text
         .chars()
         .map(|c| if c.is_alphanumeric() { c } else { '-' })
         .collect::<String>()
@@ -24,12 +33,145 @@ pub fn generate_branch_name(identifier: &str, title: &str) -> String {
         .collect::<Vec<_>>()
         .join("-");
 
-    // Truncate if too long
-    let slug = if slug.len() > 50 {
-        slug[..50].trim_end_matches('-').to_string()
+    if slug.len() > max_len {
+        let mut end = max_len;
+        while !slug.is_char_boundary(end) {
+            end -= 1;
+        }
+        slug[..end].trim_end_matches('-').to_string()
     } else {
         slug
+    }
+}
+
+pub fn generate_branch_name(identifier: &str, title: &str) -> String {
+    format!(
+        "{}/{}",
+        identifier.to_lowercase(),
+        slugify(title, DEFAULT_SLUG_LENGTH, true)
+    )
+}
+
+/// What a branch name is built from.
+pub struct BranchInfo<'a> {
+    pub identifier: &'a str,
+    pub title: &'a str,
+    /// Linear's suggested `branchName` (may be empty)
+    pub linear_branch: &'a str,
+    /// Current user's display name, for `{{user}}`
+    pub user: Option<&'a str>,
+    pub labels: &'a [String],
+}
+
+/// Branch name for an issue. With no `[git.branch]` settings this is Linear's
+/// `branchName` (or `identifier/slug`); otherwise the template, default
+/// `{{identifier}}/{{slug}}`, is rendered and prefixed by the first matching
+/// label prefix.
+pub fn issue_branch_name(config: &BranchConfig, info: &BranchInfo) -> String {
+    if config.is_empty() {
+        return match info.linear_branch {
+            "" => generate_branch_name(info.identifier, info.title),
+            b => b.to_string(),
+        };
+    }
+
+    let template = config
+        .template
+        .as_deref()
+        .unwrap_or("{{identifier}}/{{slug}}");
+    let lowercase = !config
+        .case
+        .as_deref()
+        .is_some_and(|c| c.eq_ignore_ascii_case("preserve"));
+    let slug = slugify(
+        info.title,
+        config.slug_length.unwrap_or(DEFAULT_SLUG_LENGTH),
+        lowercase,
+    );
+    let user = slugify(info.user.unwrap_or(""), DEFAULT_SLUG_LENGTH, true);
+    let prefix = info
+        .labels
+        .iter()
+        .find_map(|label| {
+            config
+                .label_prefixes
+                .iter()
+                .find(|(l, _)| l.eq_ignore_ascii_case(label))
+                .map(|(_, p)| p.as_str())
+        })
+        .unwrap_or("");
+
+    let name = template
+        .replace("{{IDENTIFIER}}", &info.identifier.to_uppercase())
+        .replace("{{identifier}}", &info.identifier.to_lowercase())
+        .replace("{{slug}}", &slug)
+        .replace("{{user}}", &user);
+    let name = if template.contains("{{prefix}}") {
+        name.replace("{{prefix}}", prefix)
+    } else {
+        format!("{}{}", prefix, name)
     };
+    // Empty placeholders can leave "//", "-/" or stray separators behind
+    let mut cleaned = String::with_capacity(name.len());
+    for c in name.chars() {
+        let prev = cleaned.chars().last();
+        match (prev, c) {
+            (None, '/' | '-') => continue,
+            (Some('/'), '/' | '-') | (Some('-'), '-') => continue,
+            (Some('-'), '/') => {
+                cleaned.pop();
+                cleaned.push('/');
+                continue;
+            }
+            _ => cleaned.push(c),
+        }
+    }
+    cleaned.trim_end_matches(['/', '-']).to_string()
+}
+
+/// Regex that pulls the identifier out of branches made from `template`.
+fn template_regex(template: &str) -> Option<regex::Regex> {
+    if !template.to_lowercase().contains("{{identifier}}") {
+        return None;
+    }
+    let placeholder = regex::Regex::new(r"\{\{(identifier|IDENTIFIER|slug|user|prefix)\}\}").ok()?;
+    let mut pattern = String::from("(?i)^(?:.*/)?");
+    let mut last = 0;
+    let mut captured = false;
+    // The label prefix may sit in front of the template
+    let template = template.trim_start_matches("{{prefix}}");
+    for m in placeholder.find_iter(template) {
+        pattern.push_str(&regex::escape(&template[last..m.start()]));
+        pattern.push_str(match m.as_str() {
+            "{{identifier}}" | "{{IDENTIFIER}}" if !captured => {
+                captured = true;
+                r"([a-z][a-z0-9]*-\d+)"
+            }
+            "{{identifier}}" | "{{IDENTIFIER}}" => r"[a-z][a-z0-9]*-\d+",
+            "{{slug}}" => ".*?",
+            _ => "[^/]*?",
+        });
+        last = m.end();
+    }
+    pattern.push_str(&regex::escape(&template[last..]));
+    pattern.push('$');
+    regex::Regex::new(&pattern).ok()
+}
+
+/// Like [`issue_id_from_branch`], but tries the configured branch template
+/// first so that e.g. `{{slug}}-{{identifier}}` isn't fooled by "utf-8".
+pub fn issue_id_from_branch_with(branch: &str, template: Option<&str>) -> Option<String> {
+    template
+        .and_then(template_regex)
+        .and_then(|re| re.captures(branch))
+        .and_then(|caps| caps.get(1))
+        .map(|m| m.as_str().to_uppercase())
+        .or_else(|| issue_id_from_branch(branch))
+}
 
-    format!("{}/{}", identifier.to_lowercase(), slug)
+/// Extract a Linear issue identifier (e.g. "LIN-123") from a branch name.
+pub fn issue_id_from_branch(branch: &str) -> Option<String> {
+    static ISSUE_RE: OnceLock<regex::Regex> = OnceLock::new();
+    let re = ISSUE_RE.get_or_init(|| regex::Regex::new(r"(?i)([a-z]+-\d+)").unwrap());
+    re.find(branch).map(|m| m.as_str().to_uppercase())
 }
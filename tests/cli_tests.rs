@@ -48,6 +48,31 @@ fn test_projects_members_help() {
     );
 }
 
+#[test]
+fn test_projects_update_status_help() {
+    let (code, stdout, _stderr) = run_cli(&["projects", "update-status", "--help"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("--health"));
+    assert!(stdout.contains("at-risk"));
+    assert!(stdout.contains("--body"));
+    assert!(stdout.contains("--draft"));
+    assert!(stdout.contains("--use-draft"));
+}
+
+#[test]
+fn test_projects_update_status_requires_body_or_draft() {
+    let (code, _stdout, stderr) = run_cli(&["projects", "update-status", "PROJ"]);
+    assert_ne!(code, 0);
+    assert!(stderr.contains("--use-draft"));
+}
+
+#[test]
+fn test_projects_updates_help() {
+    let (code, stdout, _stderr) = run_cli(&["projects", "updates", "--help"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("newest first"));
+}
+
 #[test]
 fn test_issues_help() {
     let (code, stdout, _stderr) = run_cli(&["issues", "--help"]);
@@ -101,6 +126,35 @@ fn test_search_help() {
     assert_eq!(code, 0);
     assert!(stdout.contains("issues"));
     assert!(stdout.contains("projects"));
+    assert!(stdout.contains("index"));
+    assert!(stdout.contains("documents"));
+    assert!(stdout.contains("comments"));
+    assert!(stdout.contains("initiatives"));
+    assert!(stdout.contains("all"));
+}
+
+#[test]
+fn test_search_all_help() {
+    let (code, stdout, _stderr) = run_cli(&["search", "all", "--help"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("--archived"));
+    assert!(stdout.contains("kind"));
+}
+
+#[test]
+fn test_search_issues_offline_help() {
+    let (code, stdout, _stderr) = run_cli(&["search", "issues", "--help"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("--offline"));
+    assert!(stdout.contains("title:"));
+}
+
+#[test]
+fn test_search_index_help() {
+    let (code, stdout, _stderr) = run_cli(&["search", "index", "--help"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("--team"));
+    assert!(stdout.contains("--full"));
 }
 
 #[test]
@@ -111,6 +165,79 @@ fn test_git_help() {
     assert!(stdout.contains("branch"));
 }
 
+#[test]
+fn test_git_hooks_install_help() {
+    let (code, stdout, _stderr) = run_cli(&["git", "hooks", "install", "--help"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("--require-reference"));
+    assert!(stdout.contains("--force"));
+    assert!(stdout.contains("Linear-Issue:"));
+}
+
+#[test]
+fn test_git_changelog_help() {
+    let (code, stdout, _stderr) = run_cli(&["git", "changelog", "--help"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("--group-by"));
+    assert!(stdout.contains("--version"));
+    assert!(stdout.contains("--mark-released"));
+    assert!(stdout.contains("--comment"));
+    assert!(stdout.contains("--file"));
+}
+
+#[test]
+fn test_git_sync_states_help() {
+    let (code, stdout, _stderr) = run_cli(&["git", "sync-states", "--help"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("--range"));
+    assert!(stdout.contains("--map"));
+    assert!(stdout.contains("git.magic_words"));
+}
+
+#[test]
+fn test_git_stack_help() {
+    let (code, stdout, _stderr) = run_cli(&["git", "stack", "--help"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("status"));
+    assert!(stdout.contains("--flat"));
+    assert!(stdout.contains("--base"));
+}
+
+#[test]
+fn test_git_checkout_help_branch_templates() {
+    let (code, stdout, _stderr) = run_cli(&["git", "checkout", "--help"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("[git.branch]"));
+    assert!(stdout.contains("{{slug}}"));
+}
+
+#[test]
+fn test_git_pr_help() {
+    let (code, stdout, _stderr) = run_cli(&["git", "pr", "--help"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("--forge"));
+    assert!(stdout.contains("--body-template"));
+    assert!(stdout.contains("acceptance_criteria"));
+    assert!(stdout.contains("git.forges"));
+}
+
+#[test]
+fn test_git_worktree_help() {
+    let (code, stdout, _stderr) = run_cli(&["git", "worktree", "--help"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("prune"));
+    assert!(stdout.contains("--dir"));
+    assert!(stdout.contains("worktree_dir"));
+}
+
+#[test]
+fn test_git_suggest_assignee_help() {
+    let (code, stdout, _stderr) = run_cli(&["git", "suggest-assignee", "--help"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("CODEOWNERS"));
+    assert!(stdout.contains("[git.owners]"));
+}
+
 #[test]
 fn test_sync_help() {
     let (code, stdout, _stderr) = run_cli(&["sync", "--help"]);
@@ -252,6 +379,23 @@ fn test_export_help() {
     assert!(stdout.contains("csv"));
 }
 
+#[test]
+fn test_export_ndjson_help() {
+    let (code, stdout, _stderr) = run_cli(&["export", "ndjson", "--help"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("--since-last-run"));
+    assert!(stdout.contains("STATEFILE"));
+}
+
+#[test]
+fn test_export_sqlite_help() {
+    let (code, stdout, _stderr) = run_cli(&["export", "sqlite", "--help"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("DATABASE"));
+    assert!(stdout.contains("--full"));
+    assert!(stdout.contains("issue_labels"));
+}
+
 #[test]
 fn test_uploads_help() {
     let (code, stdout, _stderr) = run_cli(&["uploads", "--help"]);
@@ -278,6 +422,9 @@ fn test_issues_create_help() {
     assert!(stdout.contains("--priority"));
     assert!(stdout.contains("--description"));
     assert!(stdout.contains("--dry-run"));
+    assert!(stdout.contains("--project"));
+    assert!(stdout.contains(".linear.toml"));
+    assert!(stdout.contains("--auto-assign-from-paths"));
 }
 
 #[test]
@@ -350,6 +497,44 @@ fn test_time_help() {
     assert_eq!(code, 0);
     assert!(stdout.contains("log"));
     assert!(stdout.contains("list"));
+    assert!(stdout.contains("update"));
+    assert!(stdout.contains("report"));
+}
+
+#[test]
+fn test_time_report_help() {
+    let (code, stdout, _stderr) = run_cli(&["time", "report", "--help"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("--from"));
+    assert!(stdout.contains("--to"));
+    assert!(stdout.contains("--group-by"));
+    assert!(stdout.contains("--csv"));
+}
+
+#[test]
+fn test_time_stop_help() {
+    let (code, stdout, _stderr) = run_cli(&["time", "stop", "--help"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("--round"));
+    assert!(stdout.contains("--round-mode"));
+    assert!(stdout.contains("--discard"));
+}
+
+#[test]
+fn test_issues_start_stop_timer_flag() {
+    let (code, stdout, _stderr) = run_cli(&["issues", "start", "--help"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("--timer"));
+    let (code, stdout, _stderr) = run_cli(&["issues", "stop", "--help"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("--timer"));
+}
+
+#[test]
+fn test_time_log_date_flag() {
+    let (code, stdout, _stderr) = run_cli(&["time", "log", "--help"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("--date"));
 }
 
 #[test]
@@ -399,6 +584,8 @@ fn test_context_help() {
     let (code, stdout, _stderr) = run_cli(&["context", "--help"]);
     assert_eq!(code, 0);
     assert!(stdout.contains("context") || stdout.contains("issue") || stdout.contains("branch"));
+    assert!(stdout.contains("--full"));
+    assert!(stdout.contains(".linear"));
 }
 
 // --- Alias tests for commands without coverage ---
@@ -729,6 +916,25 @@ fn test_views_update_help() {
     assert!(stdout.contains("--filter-json"));
 }
 
+#[test]
+fn test_views_run_help() {
+    let (code, stdout, _stderr) = run_cli(&["views", "run", "--help"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("--group-by"));
+    assert!(stdout.contains("--order-by"));
+    assert!(stdout.contains("--archived"));
+    assert!(stdout.contains("LOCAL VIEWS"));
+}
+
+#[test]
+fn test_views_publish_help() {
+    let (code, stdout, _stderr) = run_cli(&["views", "publish", "--help"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("--shared"));
+    assert!(stdout.contains("--team"));
+    assert!(stdout.contains("--keep"));
+}
+
 #[test]
 fn test_views_delete_help() {
     let (code, stdout, _stderr) = run_cli(&["views", "delete", "--help"]);
@@ -873,6 +1079,15 @@ fn test_users_help() {
     assert!(stdout.contains("get"), "users should have get");
 }
 
+#[test]
+fn test_users_workload_help() {
+    let (code, stdout, _stderr) = run_cli(&["users", "workload", "--help"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("--team"));
+    assert!(stdout.contains("--capacity"));
+    assert!(stdout.contains("[workload.people]"));
+}
+
 #[test]
 fn test_users_get_help() {
     let (code, stdout, _stderr) = run_cli(&["users", "get", "--help"]);
@@ -1181,6 +1396,91 @@ fn test_help_shows_milestones() {
     );
 }
 
+#[test]
+fn test_metrics_cycle_help() {
+    let (code, stdout, _stderr) = run_cli(&["metrics", "cycle", "--help"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("--chart"));
+    assert!(stdout.contains("burnup"));
+    assert!(stdout.contains("--svg"));
+    assert!(stdout.contains("--issues"));
+}
+
+#[test]
+fn test_metrics_cycle_svg_stdout_rejects_json() {
+    let (code, _stdout, stderr) =
+        run_cli(&["metrics", "cycle", "1", "--svg", "-", "--output", "json"]);
+    assert_ne!(code, 0);
+    assert!(stderr.contains("--svg -"));
+}
+
+#[test]
+fn test_cycles_report_publish_rejects_json() {
+    let (code, _stdout, stderr) = run_cli(&[
+        "cycles", "report", "12", "--publish", "Rituals", "--output", "json",
+    ]);
+    assert_ne!(code, 0);
+    assert!(stderr.contains("--publish"));
+}
+
+#[test]
+fn test_metrics_flow_help() {
+    let (code, stdout, _stderr) = run_cli(&["metrics", "flow", "--help"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("--since"));
+    assert!(stdout.contains("--by"));
+    assert!(stdout.contains("assignee"));
+    assert!(stdout.contains("cycle time"));
+}
+
+#[test]
+fn test_metrics_forecast_help() {
+    let (code, stdout, _stderr) = run_cli(&["metrics", "forecast", "--help"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("--project"));
+    assert!(stdout.contains("--runs"));
+    assert!(stdout.contains("--points"));
+    assert!(stdout.contains("Milestones"));
+}
+
+#[test]
+fn test_metrics_aging_help() {
+    let (code, stdout, _stderr) = run_cli(&["metrics", "aging", "--help"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("--breaches"));
+    assert!(stdout.contains("--check"));
+    assert!(stdout.contains("[[sla]]"));
+}
+
+#[test]
+fn test_cycles_close_help() {
+    let (code, stdout, _stderr) = run_cli(&["cycles", "close", "--help"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("--state"));
+    assert!(stdout.contains("--interactive"));
+    assert!(stdout.contains("--comment"));
+    assert!(stdout.contains("--dry-run"));
+}
+
+#[test]
+fn test_cycles_plan_help() {
+    let (code, stdout, _stderr) = run_cli(&["cycles", "plan", "--help"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("--capacity"));
+    assert!(stdout.contains("--apply"));
+    assert!(stdout.contains("velocity"));
+}
+
+#[test]
+fn test_cycles_report_help() {
+    let (code, stdout, _stderr) = run_cli(&["cycles", "report", "--help"]);
+    assert_eq!(code, 0);
+    assert!(stdout.contains("--html"));
+    assert!(stdout.contains("--file"));
+    assert!(stdout.contains("--bug-label"));
+    assert!(stdout.contains("--publish"));
+}
+
 // === Pager support tests ===
 
 #[test]